@@ -4,6 +4,7 @@ use aurora_engine_types::{U256, types::Wei};
 use clap::Subcommand;
 
 use crate::cli::advanced::Config;
+use crate::error::Error;
 use crate::{client::AuroraClient, utils};
 
 #[derive(Subcommand)]
@@ -60,23 +61,21 @@ pub async fn execute_command(
         },
         Command::Write { subcommand } => match subcommand {
             WriteCommand::Deploy { input_data_hex } => {
-                let secret_key_hex = config.get_evm_secret_key()?;
-                let secret_key = utils::secret_key_from_hex(secret_key_hex)?;
+                let signer = config.get_evm_signer()?;
                 let input = utils::hex_to_vec(&input_data_hex)?;
                 client
-                    .send_and_wait_transaction(&secret_key, None, Wei::zero(), input)
+                    .send_and_wait_transaction(signer.as_ref(), None, Wei::zero(), input)
                     .await?;
             }
             WriteCommand::Transfer {
                 target_addr_hex,
                 amount,
             } => {
-                let secret_key_hex = config.get_evm_secret_key()?;
-                let secret_key = utils::secret_key_from_hex(secret_key_hex)?;
-                let target = utils::hex_to_address(&target_addr_hex)?;
-                let amount = Wei::new(U256::from_dec_str(&amount).unwrap());
+                let signer = config.get_evm_signer()?;
+                let target = crate::cli::parse_address(&target_addr_hex)?;
+                let amount = Wei::new(parse_amount(&amount)?);
                 client
-                    .send_and_wait_transaction(&secret_key, Some(target), amount, Vec::new())
+                    .send_and_wait_transaction(signer.as_ref(), Some(target), amount, Vec::new())
                     .await?;
             }
             WriteCommand::Call {
@@ -84,18 +83,24 @@ pub async fn execute_command(
                 amount,
                 input_data_hex,
             } => {
-                let secret_key_hex = config.get_evm_secret_key()?;
-                let secret_key = utils::secret_key_from_hex(secret_key_hex)?;
-                let target = utils::hex_to_address(&target_addr_hex)?;
+                let signer = config.get_evm_signer()?;
+                let target = crate::cli::parse_address(&target_addr_hex)?;
                 let amount = amount
-                    .as_ref()
-                    .map_or_else(Wei::zero, |a| Wei::new(U256::from_dec_str(a).unwrap()));
+                    .as_deref()
+                    .map(parse_amount)
+                    .transpose()?
+                    .map_or_else(Wei::zero, Wei::new);
                 let input = utils::hex_to_vec(&input_data_hex)?;
                 client
-                    .send_and_wait_transaction(&secret_key, Some(target), amount, input)
+                    .send_and_wait_transaction(signer.as_ref(), Some(target), amount, input)
                     .await?;
             }
         },
     }
     Ok(())
 }
+
+fn parse_amount(amount: &str) -> anyhow::Result<U256> {
+    U256::from_dec_str(amount)
+        .map_err(|e| Error::Usage(format!("Invalid amount: {amount}, {e:?}")).into())
+}
@@ -1,6 +1,10 @@
+use aurora_sdk_rs::aurora::signer::EvmSigner;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 
+use crate::cli::parse_address;
+use crate::utils::{EvmSignerOptions, KEYSTORE_PASSWORD_ENV};
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Config {
     pub network: Network,
@@ -8,6 +12,15 @@ pub struct Config {
     pub aurora_api_key: Option<String>,
     pub near_key_path: Option<String>,
     pub evm_secret_key: Option<String>,
+    /// Path to the encrypted JSON keystore file with the EVM secret key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evm_keystore_path: Option<String>,
+    /// URL of the remote signer of EVM transactions: `http(s)://` or `unix://`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evm_remote_signer: Option<String>,
+    /// EVM address of the account managed by the remote signer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evm_signer_address: Option<String>,
 }
 
 impl Config {
@@ -23,10 +36,19 @@ impl Config {
         Ok(())
     }
 
-    pub fn get_evm_secret_key(&self) -> anyhow::Result<&str> {
-        self.evm_secret_key.as_deref().ok_or_else(|| {
-            anyhow::anyhow!("evm_secret_key must be given in config to use this feature")
-        })
+    pub fn get_evm_signer(&self) -> anyhow::Result<Box<dyn EvmSigner>> {
+        EvmSignerOptions {
+            secret_key: self.evm_secret_key.clone(),
+            keystore: self.evm_keystore_path.clone(),
+            keystore_password: std::env::var(KEYSTORE_PASSWORD_ENV).ok(),
+            remote_signer: self.evm_remote_signer.clone(),
+            signer_address: self
+                .evm_signer_address
+                .as_deref()
+                .map(parse_address)
+                .transpose()?,
+        }
+        .into_signer()
     }
 }
 
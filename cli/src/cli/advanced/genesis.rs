@@ -0,0 +1,345 @@
+//! Pre-deployment of Aurora EVM and other accounts in the nearcore genesis file, so a fresh
+//! localnet boots with a ready engine and doesn't need the deploy and init transactions.
+use aurora_engine_types::storage::{KeyPrefix, address_to_key, bytes_to_key, storage_to_key};
+use aurora_engine_types::types::{Address, u256_to_arr};
+use aurora_engine_types::{H256, U256};
+use near_crypto::{KeyFile, KeyType, PublicKey, SecretKey};
+use near_primitives::account::{AccessKey, Account, AccountContract};
+use near_primitives::hash::hash;
+use near_primitives::state_record::StateRecord;
+use near_primitives::types::{AccountId, Balance};
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+use crate::cli::parse_address;
+use crate::error::Error;
+use crate::utils;
+
+/// Storage key of the engine state, under the `Config` prefix.
+const STATE_KEY: &[u8] = b"STATE";
+/// Index of the `V2` variant of the versioned engine state, the one written by `new`.
+const STATE_VERSION: u8 = 1;
+
+/// Balance of the engine account in the genesis: 1e9 NEAR.
+const ENGINE_BALANCE: Balance = Balance::from_near(1_000_000_000);
+
+/// Engine code and the parameters of `new` to initialize the engine state with.
+pub struct Engine {
+    pub code: Vec<u8>,
+    pub chain_id: u64,
+    pub owner_id: aurora_engine_types::account_id::AccountId,
+    pub upgrade_delay_blocks: u64,
+}
+
+/// Engine state in the borsh layout of the engine's `EngineState`.
+#[derive(borsh::BorshSerialize)]
+struct EngineState {
+    chain_id: [u8; 32],
+    owner_id: aurora_engine_types::account_id::AccountId,
+    upgrade_delay_blocks: u64,
+    is_paused: bool,
+    key_manager: Option<aurora_engine_types::account_id::AccountId>,
+}
+
+/// Additional accounts read from the JSON file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Accounts {
+    pub near_accounts: Vec<NearAccount>,
+    /// EVM accounts and contracts, which require the engine to be pre-deployed.
+    pub evm_accounts: Vec<EvmAccount>,
+}
+
+/// NEAR account with a full access key. A new key is generated and written next to the genesis
+/// file if the public key isn't set.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NearAccount {
+    pub account_id: AccountId,
+    /// Balance in NEAR.
+    pub balance: f64,
+    pub public_key: Option<PublicKey>,
+}
+
+/// EVM account with its balance in wei as a decimal string, nonce, code and storage in hex.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EvmAccount {
+    pub address: String,
+    #[serde(default)]
+    pub balance: Option<String>,
+    #[serde(default)]
+    pub nonce: u64,
+    #[serde(default)]
+    pub code: Option<String>,
+    #[serde(default)]
+    pub storage: BTreeMap<String, String>,
+}
+
+impl Accounts {
+    pub fn from_file<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            Error::Usage(format!(
+                "Couldn't read accounts file {}: {e}",
+                path.display()
+            ))
+        })?;
+
+        serde_json::from_str(&content).map_err(|e| {
+            Error::Usage(format!("Invalid accounts file {}: {e}", path.display())).into()
+        })
+    }
+}
+
+/// Add the engine account with a new full access key to the genesis file and write the key next
+/// to it. The engine is deployed and initialized if its code is given, and the accounts are added.
+pub fn add_records(
+    path: &str,
+    engine_id: &str,
+    engine: Option<Engine>,
+    accounts: Accounts,
+) -> anyhow::Result<()> {
+    if engine.is_none() && !accounts.evm_accounts.is_empty() {
+        return Err(Error::Usage(
+            "EVM accounts could be added with the engine WASM only".to_string(),
+        )
+        .into());
+    }
+
+    let mut genesis = near_chain_configs::Genesis::from_file(
+        path,
+        near_chain_configs::GenesisValidationMode::UnsafeFast,
+    )?;
+    let engine_id: AccountId = engine_id.parse()?;
+    let records = &mut genesis.force_read_records().0;
+    let existing = records
+        .iter()
+        .filter_map(|record| match record {
+            StateRecord::Account { account_id, .. } => Some(account_id.clone()),
+            _ => None,
+        })
+        .collect::<HashSet<_>>();
+    if existing.contains(&engine_id) {
+        println!("Aurora account already present");
+        return Ok(());
+    }
+    if let Some(account) = accounts
+        .near_accounts
+        .iter()
+        .find(|account| account.account_id == engine_id || existing.contains(&account.account_id))
+    {
+        return Err(Error::Usage(format!(
+            "Account {} is already present in the genesis",
+            account.account_id
+        ))
+        .into());
+    }
+
+    let dir = Path::new(path).parent().unwrap_or_else(|| Path::new("."));
+    let mut total = ENGINE_BALANCE;
+    let mut new_records = Vec::new();
+
+    let public_key = new_key(&engine_id, &dir.join("aurora_key.json"))?;
+    let contract = engine.as_ref().map_or(AccountContract::None, |engine| {
+        AccountContract::Local(hash(&engine.code))
+    });
+    new_records.push(StateRecord::Account {
+        account_id: engine_id.clone(),
+        account: Account::new(ENGINE_BALANCE, Balance::ZERO, contract, 0),
+    });
+    new_records.push(StateRecord::AccessKey {
+        account_id: engine_id.clone(),
+        public_key,
+        access_key: AccessKey::full_access(),
+    });
+    if let Some(engine) = engine {
+        new_records.extend(engine_records(&engine_id, engine)?);
+        for account in &accounts.evm_accounts {
+            new_records.extend(evm_records(&engine_id, account)?);
+        }
+    }
+
+    for account in accounts.near_accounts {
+        let amount = Balance::from_yoctonear(utils::near_to_yocto(account.balance));
+        let public_key = match account.public_key {
+            Some(public_key) => public_key,
+            None => new_key(
+                &account.account_id,
+                &dir.join(format!("{}_key.json", account.account_id)),
+            )?,
+        };
+        total = total.saturating_add(amount);
+        new_records.push(StateRecord::Account {
+            account_id: account.account_id.clone(),
+            account: Account::new(amount, Balance::ZERO, AccountContract::None, 0),
+        });
+        new_records.push(StateRecord::AccessKey {
+            account_id: account.account_id,
+            public_key,
+            access_key: AccessKey::full_access(),
+        });
+    }
+
+    records.extend(new_records);
+    genesis.config.total_supply = genesis.config.total_supply.saturating_add(total);
+    genesis.to_file(path);
+    println!("Aurora account added to {path}");
+
+    Ok(())
+}
+
+/// Generate a new key for the account and write it to the key file.
+fn new_key(account_id: &AccountId, path: &Path) -> anyhow::Result<PublicKey> {
+    let secret_key = SecretKey::from_random(KeyType::ED25519);
+    let public_key = secret_key.public_key();
+    KeyFile {
+        account_id: account_id.clone(),
+        public_key: public_key.clone(),
+        secret_key,
+    }
+    .write_to_file(path)?;
+    println!("Access key of {account_id} written to {}", path.display());
+
+    Ok(public_key)
+}
+
+fn engine_records(engine_id: &AccountId, engine: Engine) -> anyhow::Result<Vec<StateRecord>> {
+    let state = EngineState {
+        chain_id: u256_to_arr(&U256::from(engine.chain_id)),
+        owner_id: engine.owner_id,
+        upgrade_delay_blocks: engine.upgrade_delay_blocks,
+        is_paused: false,
+        key_manager: None,
+    };
+    let state = [vec![STATE_VERSION], borsh::to_vec(&state)?].concat();
+
+    Ok(vec![
+        StateRecord::Contract {
+            account_id: engine_id.clone(),
+            code: engine.code,
+        },
+        data(engine_id, bytes_to_key(KeyPrefix::Config, STATE_KEY), state),
+    ])
+}
+
+fn evm_records(engine_id: &AccountId, account: &EvmAccount) -> anyhow::Result<Vec<StateRecord>> {
+    let address = parse_address(&account.address)?;
+    let mut records = Vec::new();
+
+    if let Some(balance) = &account.balance {
+        let balance = U256::from_dec_str(balance)
+            .map_err(|e| Error::Usage(format!("Invalid balance of {}: {e}", account.address)))?;
+        records.push(evm_data(
+            engine_id,
+            KeyPrefix::Balance,
+            address,
+            u256_to_arr(&balance),
+        ));
+    }
+    if account.nonce > 0 {
+        let nonce = u256_to_arr(&U256::from(account.nonce));
+        records.push(evm_data(engine_id, KeyPrefix::Nonce, address, nonce));
+    }
+    if let Some(code) = &account.code {
+        let code = utils::hex_to_vec(code)?;
+        records.push(evm_data(engine_id, KeyPrefix::Code, address, code));
+    }
+    for (key, value) in &account.storage {
+        let key = H256(utils::hex_to_arr(key)?);
+        let value = utils::hex_to_arr::<32>(value)?;
+        let key = storage_to_key(&address, &key, 0);
+        records.push(data(engine_id, key.as_ref().to_vec(), value.to_vec()));
+    }
+
+    Ok(records)
+}
+
+fn evm_data<V: Into<Vec<u8>>>(
+    engine_id: &AccountId,
+    prefix: KeyPrefix,
+    address: Address,
+    value: V,
+) -> StateRecord {
+    data(
+        engine_id,
+        address_to_key(prefix, &address).to_vec(),
+        value.into(),
+    )
+}
+
+fn data(engine_id: &AccountId, key: Vec<u8>, value: Vec<u8>) -> StateRecord {
+    StateRecord::Data {
+        account_id: engine_id.clone(),
+        data_key: key.into(),
+        value: value.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_engine_state_record() {
+        let engine_id: AccountId = "aurora.node0".parse().unwrap();
+        let records = engine_records(
+            &engine_id,
+            Engine {
+                code: b"engine".to_vec(),
+                chain_id: 1_313_161_556,
+                owner_id: "owner.node0".parse().unwrap(),
+                upgrade_delay_blocks: 2,
+            },
+        )
+        .unwrap();
+
+        let StateRecord::Data {
+            data_key, value, ..
+        } = &records[1]
+        else {
+            panic!("expected data record, got {:?}", records[1]);
+        };
+        assert_eq!(data_key.as_slice(), b"\x07\x00STATE");
+        assert_eq!(value[0], STATE_VERSION);
+        assert_eq!(&value[29..33], 1_313_161_556_u32.to_be_bytes());
+        assert_eq!(&value[33..37], 11_u32.to_le_bytes());
+        assert_eq!(&value[37..48], b"owner.node0");
+        assert_eq!(&value[48..], [2, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_evm_records() {
+        let engine_id: AccountId = "aurora.node0".parse().unwrap();
+        let account: EvmAccount = serde_json::from_value(serde_json::json!({
+            "address": "0x1B16948F011686AE74BB2Ba0477aDAd2aB7A7F0A",
+            "balance": "1000",
+            "code": "0x6001",
+            "storage": {
+                "0x0000000000000000000000000000000000000000000000000000000000000001":
+                    "0x0000000000000000000000000000000000000000000000000000000000000002"
+            }
+        }))
+        .unwrap();
+        let records = evm_records(&engine_id, &account).unwrap();
+        let data = records
+            .iter()
+            .map(|record| match record {
+                StateRecord::Data {
+                    data_key, value, ..
+                } => (data_key.to_vec(), value.to_vec()),
+                _ => panic!("expected data record, got {record:?}"),
+            })
+            .collect::<Vec<_>>();
+        let address = utils::hex_to_vec(&account.address).unwrap();
+
+        assert_eq!(data.len(), 3);
+        assert_eq!(data[0].0, [&[7, 2], address.as_slice()].concat());
+        assert_eq!(data[0].1, u256_to_arr(&U256::from(1000)));
+        assert_eq!(data[1].0, [&[7, 3], address.as_slice()].concat());
+        assert_eq!(data[1].1, [0x60, 0x01]);
+        assert_eq!(data[2].0.len(), 54);
+        assert_eq!(data[2].1[31], 2);
+    }
+}
@@ -5,6 +5,7 @@ use crate::cli::advanced::config::{Config, Network};
 pub mod aurora;
 pub mod config;
 pub mod erc20;
+pub mod genesis;
 pub mod near;
 pub mod process_tx_data;
 pub mod solidity;
@@ -16,6 +17,12 @@ pub struct Cli {
     /// Path to config file
     #[clap(short, long)]
     pub config_path: Option<String>,
+    /// Log RPC calls with their latency, or with params and responses if repeated (-vv)
+    #[clap(short, long, action = clap::ArgAction::Count)]
+    pub verbose: u8,
+    /// Log RPC requests and responses to stderr, or append them to the file
+    #[clap(long, value_name = "FILE", num_args = 0..=1, require_equals = true, default_missing_value = "-")]
+    pub trace_rpc: Option<std::path::PathBuf>,
     #[clap(subcommand)]
     pub command: Command,
 }
@@ -41,6 +48,8 @@ pub enum Command {
 }
 
 pub async fn run(args: Cli) -> anyhow::Result<()> {
+    crate::utils::trace::init(args.verbose, args.trace_rpc.as_deref())?;
+
     let config_path = args.config_path.as_deref().unwrap_or("default-config.json");
     let config = Config::from_file(config_path)?;
     let network = &config.network;
@@ -61,7 +70,7 @@ pub async fn run(args: Cli) -> anyhow::Result<()> {
                 &format!("{aurora_endpoint}{api_key}"),
                 near_endpoint,
                 &config.engine_account_id,
-            );
+            )?;
             aurora::execute_command(subcommand, &client, &config).await?;
         }
         Command::Near { subcommand } => {
@@ -69,13 +78,13 @@ pub async fn run(args: Cli) -> anyhow::Result<()> {
                 near_endpoint,
                 &config.engine_account_id,
                 config.near_key_path.clone(),
-            );
+            )?;
             near::execute_command(subcommand, &client, &config, config_path).await?;
         }
         Command::ProcessTxData {
             action,
             input_files_list_path,
-        } => process_tx_data::execute_command(action, input_files_list_path).await,
+        } => process_tx_data::execute_command(action, input_files_list_path).await?,
     }
 
     Ok(())
@@ -10,29 +10,31 @@ use aurora_engine_types::{
     parameters::{CrossContractCallArgs, PromiseArgs, PromiseCreateArgs},
     types::{Address, NearGas, Wei, Yocto},
 };
+use aurora_sdk_rs::aurora::signer::EvmSigner;
 use clap::Subcommand;
-use near_primitives::account::AccountContract;
-use near_primitives::types::Balance;
-use near_primitives::{
-    account::{AccessKey, Account},
-    state_record::StateRecord,
-    views::FinalExecutionOutcomeView,
-};
-use std::{path::Path, str::FromStr};
+use near_primitives::views::FinalExecutionOutcomeView;
+use std::str::FromStr;
 
-use crate::cli::advanced::{Config, Network};
+use crate::cli::advanced::{Config, Network, genesis};
+use crate::cli::block::BlockArgs;
+use crate::error::Error;
 use crate::utils;
+use crate::utils::pausable::PausedFlags;
+use crate::utils::wasm::{self, Contract};
 
 /// Chain ID for Aurora localnet, per the documentation on
 /// <https://doc.aurora.dev/getting-started/network-endpoints>
 #[allow(clippy::unreadable_literal)]
 const AURORA_LOCAL_NET_CHAIN_ID: u64 = 1313161556;
 
-use crate::{client::NearClient, utils::secret_key_from_hex};
+use crate::client::NearClient;
 
 #[derive(Subcommand)]
 pub enum Command {
+    /// Read the state of the engine at the final block or the given one
     Read {
+        #[clap(flatten)]
+        block: BlockArgs,
         #[clap(subcommand)]
         subcommand: ReadCommand,
     },
@@ -230,16 +232,37 @@ pub enum WriteCommand {
     }, // storage_deposit
     // set_paused_flags
     SetPausedFlags {
+        /// Comma-separated paused features: deposit, withdraw, or all, none, or the mask
         paused_mask: String,
     },
 }
 
 #[derive(Subcommand)]
 pub enum InitCommand {
-    /// Add aurora account to the nearcore genesis file
+    /// Add aurora account to the nearcore genesis file.
+    /// Optionally pre-deploy and initialize the engine, and add NEAR and EVM accounts.
     Genesis {
         #[clap(short, long)]
         path: String,
+        /// Path to the Wasm artifact for the engine contract, deployed and initialized in the
+        /// genesis if given.
+        #[clap(short, long)]
+        wasm_path: Option<String>,
+        /// Unique identifier for the chain. The default value is 1313161556 (Aurora localnet).
+        #[clap(short, long, requires = "wasm_path")]
+        chain_id: Option<u64>,
+        /// Near account ID for the owner of the Engine contract.
+        /// The default value is the Engine Account ID itself.
+        #[clap(short, long, requires = "wasm_path")]
+        owner_id: Option<String>,
+        /// How many blocks after staging upgrade can deploy it.
+        /// Default value is 0 (i.e. no delay in upgrading).
+        #[clap(short, long, requires = "wasm_path")]
+        upgrade_delay_blocks: Option<u64>,
+        /// Path to the JSON file with `near_accounts` and `evm_accounts` to add to the genesis.
+        /// EVM accounts require the engine Wasm.
+        #[clap(short, long)]
+        accounts_path: Option<String>,
     },
     /// Modify CLI config to use local nearcore as RPC.
     /// Optionally change the CLI access key to the one for the aurora account.
@@ -259,187 +282,192 @@ pub async fn execute_command(
     config_path: &str,
 ) -> anyhow::Result<()> {
     match command {
-        Command::Read { subcommand } => match subcommand {
-            ReadCommand::GetReceiptResult { receipt_id_b58 } => {
-                let tx_hash = bs58::decode(receipt_id_b58.as_str()).into_vec()?;
-                let outcome = client
-                    .get_receipt_outcome(
-                        tx_hash
-                            .as_slice()
-                            .try_into()
-                            .map_err(|e| anyhow::anyhow!("{e}"))?,
-                    )
-                    .await?;
-                println!("{outcome:?}");
-            }
-            ReadCommand::EngineCall {
-                sender_addr_hex,
-                target_addr_hex,
-                amount,
-                input_data_hex,
-            } => {
-                let (sender, target, amount) =
-                    parse_read_call_args(sender_addr_hex, &target_addr_hex, amount.as_deref())?;
-                let input = utils::hex_to_vec(&input_data_hex)?;
-                let result = client
-                    .view_contract_call(sender, target, amount, input, None)
-                    .await?;
-                println!("{result:?}");
-            }
-            ReadCommand::EngineErc20 {
-                erc20,
-                target_addr_hex,
-                amount,
-                sender_addr_hex,
-            } => {
-                let (sender, target, amount) =
-                    parse_read_call_args(sender_addr_hex, &target_addr_hex, amount.as_deref())?;
-                let input = erc20.abi_encode()?;
-                let result = client
-                    .view_contract_call(sender, target, amount, input, None)
-                    .await?;
-                println!("{result:?}");
-            }
-            ReadCommand::Solidity {
-                contract_call,
-                target_addr_hex,
-                amount,
-                sender_addr_hex,
-            } => {
-                let (sender, target, amount) =
-                    parse_read_call_args(sender_addr_hex, &target_addr_hex, amount.as_deref())?;
-                let input = contract_call.abi_encode()?;
-                let result = client
-                    .view_contract_call(sender, target, amount, input, None)
-                    .await?;
-                if let TransactionStatus::Succeed(bytes) = result {
-                    let parsed_output = contract_call.abi_decode(&bytes)?;
-                    println!("{parsed_output:?}");
-                } else {
+        Command::Read { block, subcommand } => {
+            let client = &client.clone().with_block_reference(block.block_reference());
+
+            match subcommand {
+                ReadCommand::GetReceiptResult { receipt_id_b58 } => {
+                    let tx_hash = bs58::decode(receipt_id_b58.as_str()).into_vec()?;
+                    let outcome = client
+                        .get_receipt_outcome(
+                            tx_hash
+                                .as_slice()
+                                .try_into()
+                                .map_err(|e| anyhow::anyhow!("{e}"))?,
+                        )
+                        .await?;
+                    println!("{outcome:?}");
+                }
+                ReadCommand::EngineCall {
+                    sender_addr_hex,
+                    target_addr_hex,
+                    amount,
+                    input_data_hex,
+                } => {
+                    let (sender, target, amount) =
+                        parse_read_call_args(sender_addr_hex, &target_addr_hex, amount.as_deref())?;
+                    let input = utils::hex_to_vec(&input_data_hex)?;
+                    let result = client
+                        .view_contract_call(sender, target, amount, input)
+                        .await?;
                     println!("{result:?}");
                 }
-            }
-            ReadCommand::EngineXccDryRun {
-                target_near_account,
-                sender_address_hex,
-                method_name,
-                json_args,
-                json_args_stdin,
-                deposit_yocto,
-                attached_gas,
-            } => {
-                let promise = PromiseArgs::Create(parse_xcc_args(
-                    &target_near_account,
+                ReadCommand::EngineErc20 {
+                    erc20,
+                    target_addr_hex,
+                    amount,
+                    sender_addr_hex,
+                } => {
+                    let (sender, target, amount) =
+                        parse_read_call_args(sender_addr_hex, &target_addr_hex, amount.as_deref())?;
+                    let input = erc20.abi_encode()?;
+                    let result = client
+                        .view_contract_call(sender, target, amount, input)
+                        .await?;
+                    println!("{result:?}");
+                }
+                ReadCommand::Solidity {
+                    contract_call,
+                    target_addr_hex,
+                    amount,
+                    sender_addr_hex,
+                } => {
+                    let (sender, target, amount) =
+                        parse_read_call_args(sender_addr_hex, &target_addr_hex, amount.as_deref())?;
+                    let input = contract_call.abi_encode()?;
+                    let result = client
+                        .view_contract_call(sender, target, amount, input)
+                        .await?;
+                    if let TransactionStatus::Succeed(bytes) = result {
+                        let parsed_output = contract_call.abi_decode(&bytes)?;
+                        println!("{parsed_output:?}");
+                    } else {
+                        println!("{result:?}");
+                    }
+                }
+                ReadCommand::EngineXccDryRun {
+                    target_near_account,
+                    sender_address_hex,
                     method_name,
                     json_args,
                     json_args_stdin,
                     deposit_yocto,
                     attached_gas,
-                ));
-                let precompile_args = CrossContractCallArgs::Eager(promise);
-                let sender = utils::hex_to_address(&sender_address_hex)?;
-                let result = client
-                    .view_contract_call(
-                        sender,
-                        aurora_engine_precompiles::xcc::cross_contract_call::ADDRESS,
-                        Wei::zero(),
-                        borsh::to_vec(&precompile_args).unwrap(),
-                        None,
-                    )
-                    .await?;
-                println!("{result:?}");
-            }
-            ReadCommand::GetBridgedNep141 { erc_20_address_hex } => {
-                let erc20 = utils::hex_to_address(&erc_20_address_hex)?;
-                match client.get_nep141_from_erc20(erc20).await {
-                    Ok(nep_141_account) => println!("{nep_141_account}"),
-                    Err(e) => {
-                        let error_msg = format!("{e:?}");
-                        if error_msg.contains("ERC20_NOT_FOUND") {
-                            println!("No NEP-141 account associated with {erc_20_address_hex}");
-                        } else {
-                            panic!("{error_msg}");
+                } => {
+                    let promise = PromiseArgs::Create(parse_xcc_args(
+                        &target_near_account,
+                        method_name,
+                        json_args,
+                        json_args_stdin,
+                        deposit_yocto,
+                        attached_gas,
+                    )?);
+                    let precompile_args = CrossContractCallArgs::Eager(promise);
+                    let sender = crate::cli::parse_address(&sender_address_hex)?;
+                    let result = client
+                        .view_contract_call(
+                            sender,
+                            aurora_engine_precompiles::xcc::cross_contract_call::ADDRESS,
+                            Wei::zero(),
+                            borsh::to_vec(&precompile_args).unwrap(),
+                        )
+                        .await?;
+                    println!("{result:?}");
+                }
+                ReadCommand::GetBridgedNep141 { erc_20_address_hex } => {
+                    let erc20 = crate::cli::parse_address(&erc_20_address_hex)?;
+                    match client.get_nep141_from_erc20(erc20).await {
+                        Ok(nep_141_account) => println!("{nep_141_account}"),
+                        Err(e) => {
+                            if format!("{e:?}").contains("ERC20_NOT_FOUND") {
+                                println!("No NEP-141 account associated with {erc_20_address_hex}");
+                            } else {
+                                return Err(e);
+                            }
                         }
                     }
                 }
+                ReadCommand::GetAuroraErc20 { nep_141_account } => {
+                    let address = client
+                        .get_erc20_from_nep141(&nep_141_account)
+                        .await?
+                        .encode();
+                    println!("{address}");
+                }
+                ReadCommand::GetEngineBridgeProver => {
+                    let bridge_prover = client.get_bridge_prover().await?;
+                    println!("{bridge_prover}");
+                }
+                ReadCommand::GetChainId => {
+                    let chain_id = {
+                        let result = client.view_call("get_chain_id", vec![]).await?;
+                        U256::from_big_endian(&result.result).low_u64()
+                    };
+                    println!("{chain_id}");
+                }
+                ReadCommand::GetUpgradeIndex => {
+                    let upgrade_index = {
+                        let result = client.view_call("get_upgrade_index", vec![]).await?;
+                        U256::from_big_endian(&result.result).low_u64()
+                    };
+                    println!("{upgrade_index}");
+                }
+                ReadCommand::GetBlockHash { block_number } => {
+                    let height_serialized: u128 = block_number
+                        .parse()
+                        .map_err(|e| Error::Usage(format!("Invalid block number: {e}")))?;
+                    let block_hash = client
+                        .view_call("get_block_hash", height_serialized.to_le_bytes().to_vec())
+                        .await?
+                        .result;
+                    let block_hex = hex::encode(block_hash);
+                    println!("{block_hex}");
+                }
+                ReadCommand::GetCode { address_hex } => {
+                    let address = crate::cli::parse_address(&address_hex)?.as_bytes().to_vec();
+                    let code = client.view_call("get_code", address).await?.result;
+                    let code_hex = hex::encode(code);
+                    println!("{code_hex}");
+                }
+                ReadCommand::GetBalance { address_hex } => {
+                    let address = crate::cli::parse_address(&address_hex)?.as_bytes().to_vec();
+                    let balance = {
+                        let result = client.view_call("get_balance", address).await?;
+                        U256::from_big_endian(&result.result).low_u64()
+                    };
+                    println!("{balance}");
+                }
+                ReadCommand::GetNonce { address_hex } => {
+                    let address = crate::cli::parse_address(&address_hex)?.as_bytes().to_vec();
+                    let nonce = {
+                        let result = client.view_call("get_nonce", address).await?;
+                        U256::from_big_endian(&result.result).low_u64()
+                    };
+                    println!("{nonce}");
+                }
+                ReadCommand::GetStorageAt {
+                    address_hex,
+                    key_hex,
+                } => {
+                    let input = GetStorageAtArgs {
+                        address: crate::cli::parse_address(&address_hex)?,
+                        key: utils::hex_to_arr(&key_hex)?,
+                    };
+                    let storage = {
+                        let result = client
+                            .view_call("get_storage_at", borsh::to_vec(&input)?)
+                            .await?;
+                        H256::from_slice(&result.result)
+                    };
+                    println!("{storage}");
+                }
+                ReadCommand::GetPausedFlags => {
+                    let result = client.view_call("get_paused_flags", vec![]).await?.result;
+                    let paused_flags = PausedFlags::from_bits(u8::try_from_slice(&result)?.into());
+                    println!("{paused_flags}");
+                }
             }
-            ReadCommand::GetAuroraErc20 { nep_141_account } => {
-                let address = client
-                    .get_erc20_from_nep141(&nep_141_account)
-                    .await?
-                    .encode();
-                println!("{address}");
-            }
-            ReadCommand::GetEngineBridgeProver => {
-                let bridge_prover = client.get_bridge_prover().await?;
-                println!("{bridge_prover}");
-            }
-            ReadCommand::GetChainId => {
-                let chain_id = {
-                    let result = client.view_call("get_chain_id", vec![]).await?;
-                    U256::from_big_endian(&result.result).low_u64()
-                };
-                println!("{chain_id}");
-            }
-            ReadCommand::GetUpgradeIndex => {
-                let upgrade_index = {
-                    let result = client.view_call("get_upgrade_index", vec![]).await?;
-                    U256::from_big_endian(&result.result).low_u64()
-                };
-                println!("{upgrade_index}");
-            }
-            ReadCommand::GetBlockHash { block_number } => {
-                let height_serialized: u128 = block_number.parse::<u128>().unwrap();
-                let block_hash = client
-                    .view_call("get_block_hash", height_serialized.to_le_bytes().to_vec())
-                    .await?
-                    .result;
-                let block_hex = hex::encode(block_hash);
-                println!("{block_hex}");
-            }
-            ReadCommand::GetCode { address_hex } => {
-                let address = utils::hex_to_address(&address_hex)?.as_bytes().to_vec();
-                let code = client.view_call("get_code", address).await?.result;
-                let code_hex = hex::encode(code);
-                println!("{code_hex}");
-            }
-            ReadCommand::GetBalance { address_hex } => {
-                let address = utils::hex_to_address(&address_hex)?.as_bytes().to_vec();
-                let balance = {
-                    let result = client.view_call("get_balance", address).await?;
-                    U256::from_big_endian(&result.result).low_u64()
-                };
-                println!("{balance}");
-            }
-            ReadCommand::GetNonce { address_hex } => {
-                let address = utils::hex_to_address(&address_hex)?.as_bytes().to_vec();
-                let nonce = {
-                    let result = client.view_call("get_nonce", address).await?;
-                    U256::from_big_endian(&result.result).low_u64()
-                };
-                println!("{nonce}");
-            }
-            ReadCommand::GetStorageAt {
-                address_hex,
-                key_hex,
-            } => {
-                let input = GetStorageAtArgs {
-                    address: utils::hex_to_address(&address_hex)?,
-                    key: utils::hex_to_arr(&key_hex)?,
-                };
-                let storage = {
-                    let result = client
-                        .view_call("get_storage_at", borsh::to_vec(&input)?)
-                        .await?;
-                    H256::from_slice(&result.result)
-                };
-                println!("{storage}");
-            }
-            ReadCommand::GetPausedFlags => {
-                let paused_flags = client.view_call("get_paused_flags", vec![]).await?.result;
-                println!("{paused_flags:?}");
-            }
-        },
+        }
         Command::Write { subcommand } => match subcommand {
             WriteCommand::EngineInit {
                 wasm_path,
@@ -449,6 +477,7 @@ pub async fn execute_command(
                 ..
             } => {
                 let wasm_bytes = tokio::fs::read(wasm_path).await?;
+                wasm::check(&wasm_bytes, Contract::Engine)?;
                 let chain_id = chain_id.unwrap_or(AURORA_LOCAL_NET_CHAIN_ID);
                 let owner_id = owner_id.as_deref().unwrap_or(&config.engine_account_id);
                 let new_args = NewCallArgs::V2(NewCallArgsV2 {
@@ -460,13 +489,13 @@ pub async fn execute_command(
                 });
 
                 let deploy_response = client.deploy_contract(wasm_bytes).await?;
-                assert_tx_success(&deploy_response);
+                assert_tx_success(&deploy_response)?;
                 let next_nonce = deploy_response.transaction.nonce + 1;
 
                 let new_response = client
                     .contract_call_with_nonce("new", borsh::to_vec(&new_args)?, next_nonce)
                     .await?;
-                assert_tx_success(&new_response);
+                assert_tx_success(&new_response)?;
 
                 println!(
                     "Deploy of Engine to {} successful",
@@ -481,9 +510,7 @@ pub async fn execute_command(
                 deposit_yocto,
                 attached_gas,
             } => {
-                let source_private_key_hex = config.get_evm_secret_key()?;
-                let sk_bytes = utils::hex_to_arr(source_private_key_hex)?;
-                let sk = libsecp256k1::SecretKey::parse(&sk_bytes).unwrap();
+                let signer = config.get_evm_signer()?;
                 let promise = PromiseArgs::Create(parse_xcc_args(
                     &target_near_account,
                     method_name,
@@ -491,11 +518,11 @@ pub async fn execute_command(
                     json_args_stdin,
                     deposit_yocto,
                     attached_gas,
-                ));
+                )?);
                 let precompile_args = CrossContractCallArgs::Eager(promise);
                 let result = client
                     .send_aurora_transaction(
-                        &sk,
+                        signer.as_ref(),
                         Some(aurora_engine_precompiles::xcc::cross_contract_call::ADDRESS),
                         Wei::zero(),
                         borsh::to_vec(&precompile_args).unwrap(),
@@ -508,11 +535,11 @@ pub async fn execute_command(
                 amount,
                 input_data_hex,
             } => {
-                let (sk, target, amount) =
+                let (signer, target, amount) =
                     parse_write_call_args(config, &target_addr_hex, amount.as_deref())?;
                 let input = utils::hex_to_vec(&input_data_hex)?;
                 let result = client
-                    .send_aurora_transaction(&sk, Some(target), amount, input)
+                    .send_aurora_transaction(signer.as_ref(), Some(target), amount, input)
                     .await?;
                 println!("{result:?}");
             }
@@ -521,11 +548,11 @@ pub async fn execute_command(
                 target_addr_hex,
                 amount,
             } => {
-                let (sk, target, amount) =
+                let (signer, target, amount) =
                     parse_write_call_args(config, &target_addr_hex, amount.as_deref())?;
                 let input = erc20.abi_encode()?;
                 let result = client
-                    .send_aurora_transaction(&sk, Some(target), amount, input)
+                    .send_aurora_transaction(signer.as_ref(), Some(target), amount, input)
                     .await?;
                 println!("{result:?}");
             }
@@ -534,17 +561,19 @@ pub async fn execute_command(
                 target_addr_hex,
                 amount,
             } => {
-                let (sk, target, amount) =
+                let (signer, target, amount) =
                     parse_write_call_args(config, &target_addr_hex, amount.as_deref())?;
                 let input = contract_call.abi_encode()?;
                 let result = client
-                    .send_aurora_transaction(&sk, Some(target), amount, input)
+                    .send_aurora_transaction(signer.as_ref(), Some(target), amount, input)
                     .await?;
                 println!("{result:?}");
             }
             WriteCommand::FactoryUpdate { wasm_bytes_path } => {
-                let args = std::fs::read(wasm_bytes_path).unwrap();
-                let tx_outcome = client.contract_call("factory_update", args).await.unwrap();
+                let args = std::fs::read(&wasm_bytes_path)
+                    .map_err(|e| Error::Usage(format!("Couldn't read {wasm_bytes_path}: {e}")))?;
+                let tx_outcome = client.contract_call("factory_update", args).await?;
+                assert_tx_success(&tx_outcome)?;
                 println!("{tx_outcome:?}");
             }
             WriteCommand::DeployCode { code_byte_hex } => {
@@ -554,14 +583,19 @@ pub async fn execute_command(
                     tx_outcome.status
                 {
                     let result = SubmitResult::try_from_slice(&bytes)
-                        .expect("Failed to parse Engine outcome");
+                        .map_err(|e| anyhow::anyhow!("Failed to parse Engine outcome: {e}"))?;
                     if let TransactionStatus::Succeed(bytes) = result.status {
                         println!("Contact deployed to address: 0x{}", hex::encode(bytes));
                     } else {
-                        println!("Transaction reverted:\n{result:?}");
+                        return Err(
+                            Error::EvmRevert(format!("Transaction reverted:\n{result:?}")).into(),
+                        );
                     }
                 } else {
-                    println!("Transaction failed:\n{tx_outcome:?}");
+                    return Err(Error::near_transaction(format!(
+                        "Transaction failed:\n{tx_outcome:?}"
+                    ))
+                    .into());
                 }
             }
             WriteCommand::RegisterRelayer {
@@ -569,88 +603,90 @@ pub async fn execute_command(
             } => {
                 let relayer = utils::hex_to_vec(&relayer_eth_address_hex)?;
                 let tx_outcome = client.contract_call("register_relayer", relayer).await?;
+                assert_tx_success(&tx_outcome)?;
                 println!("{tx_outcome:?}");
             }
             WriteCommand::DeployERC20Token { nep141 } => {
-                let nep141: AccountId = nep141.parse().unwrap();
+                let nep141: AccountId = nep141
+                    .parse()
+                    .map_err(|e| Error::Usage(format!("Invalid NEP-141 account: {e}")))?;
                 let input = borsh::to_vec(&DeployErc20TokenArgs::Legacy(nep141))?;
                 let tx_outcome = client.contract_call("deploy_erc20_token", input).await?;
+                assert_tx_success(&tx_outcome)?;
                 println!("{tx_outcome:?}");
             }
             WriteCommand::Deposit { raw_proof } => {
                 let tx_outcome = client
                     .contract_call("deposit", raw_proof.as_bytes().to_vec())
                     .await?;
+                assert_tx_success(&tx_outcome)?;
                 println!("{tx_outcome:?}");
             }
             WriteCommand::SetPausedFlags { paused_mask } => {
                 let input = borsh::to_vec(&PauseEthConnectorArgs {
-                    paused_mask: u8::from_str(&paused_mask).unwrap(),
+                    paused_mask: u8::try_from(PausedFlags::from_str(&paused_mask)?.bits())?,
                 })?;
                 let tx_outcome = client.contract_call("set_paused_flags", input).await?;
+                assert_tx_success(&tx_outcome)?;
                 println!("{tx_outcome:?}");
             }
         },
         Command::Init { subcommand } => match subcommand {
-            InitCommand::Genesis { path } => {
-                let mut genesis = near_chain_configs::Genesis::from_file(
-                    &path,
-                    near_chain_configs::GenesisValidationMode::UnsafeFast,
-                )?;
-                let records = genesis.force_read_records();
-                let aurora_id: near_primitives::account::id::AccountId =
-                    config.engine_account_id.parse()?;
-                let contains_aurora = records.0.iter().any(|record| {
-                    if let StateRecord::AccessKey { account_id, .. } = record {
-                        account_id == &aurora_id
-                    } else {
-                        false
+            InitCommand::Genesis {
+                path,
+                wasm_path,
+                chain_id,
+                owner_id,
+                upgrade_delay_blocks,
+                accounts_path,
+            } => {
+                let engine = match wasm_path {
+                    Some(wasm_path) => {
+                        let code = tokio::fs::read(wasm_path).await?;
+                        wasm::check(&code, Contract::Engine)?;
+
+                        Some(genesis::Engine {
+                            code,
+                            chain_id: chain_id.unwrap_or(AURORA_LOCAL_NET_CHAIN_ID),
+                            owner_id: owner_id
+                                .as_deref()
+                                .unwrap_or(&config.engine_account_id)
+                                .parse()
+                                .map_err(|_| {
+                                    anyhow::anyhow!("Owner account is an invalid Near account")
+                                })?,
+                            upgrade_delay_blocks: upgrade_delay_blocks.unwrap_or_default(),
+                        })
                     }
-                });
-                if contains_aurora {
-                    println!("Aurora account already present");
-                    return Ok(());
-                }
-                let secret_key = near_crypto::SecretKey::from_random(near_crypto::KeyType::ED25519);
-                let public_key = secret_key.public_key();
-                let aurora_amount = Balance::from_near(1_000_000_000); // 1e9 NEAR
-                let aurora_key_record = StateRecord::AccessKey {
-                    account_id: aurora_id.clone(),
-                    public_key: public_key.clone(),
-                    access_key: AccessKey::full_access(),
-                };
-                let aurora_account_record = StateRecord::Account {
-                    account_id: aurora_id.clone(),
-                    account: Account::new(aurora_amount, Balance::ZERO, AccountContract::None, 0),
+                    None => None,
                 };
-                records.0.push(aurora_key_record);
-                records.0.push(aurora_account_record);
-                genesis.config.total_supply =
-                    genesis.config.total_supply.saturating_add(aurora_amount);
-                genesis.to_file(&path);
-                println!("Aurora account added to {path}");
-                let key_path = Path::new(&path).parent().unwrap().join("aurora_key.json");
-                let key_file = near_crypto::KeyFile {
-                    account_id: aurora_id,
-                    public_key,
-                    secret_key,
-                };
-                key_file
-                    .write_to_file(&key_path)
-                    .expect("Failed to write Aurora access key file");
-                println!("Aurora access key written to {}", key_path.display());
+                let accounts = accounts_path
+                    .map(genesis::Accounts::from_file)
+                    .transpose()?
+                    .unwrap_or_default();
+                genesis::add_records(&path, &config.engine_account_id, engine, accounts)?;
             }
             InitCommand::LocalConfig {
                 nearcore_config_path,
                 aurora_access_key_path,
             } => {
                 let nearcore_config: serde_json::Value = {
-                    let data = std::fs::read_to_string(nearcore_config_path)
-                        .expect("Failed to read nearcore config");
-                    serde_json::from_str(&data).expect("Failed to parse nearcore config")
+                    let data = std::fs::read_to_string(&nearcore_config_path).map_err(|e| {
+                        Error::Usage(format!(
+                            "Failed to read nearcore config {nearcore_config_path}: {e}"
+                        ))
+                    })?;
+                    serde_json::from_str(&data).map_err(|e| {
+                        Error::Usage(format!(
+                            "Failed to parse nearcore config {nearcore_config_path}: {e}"
+                        ))
+                    })?
                 };
-                let rpc_addr = extract_rpc_addr(&nearcore_config)
-                    .expect("Failed to parse rpc address from nearcore config");
+                let rpc_addr = extract_rpc_addr(&nearcore_config).ok_or_else(|| {
+                    Error::Usage(format!(
+                        "Failed to parse rpc address from nearcore config {nearcore_config_path}"
+                    ))
+                })?;
                 let rpc_addr = format!("http://{rpc_addr}");
                 let mut config = config.clone();
                 config.network = Network::Custom {
@@ -662,9 +698,11 @@ pub async fn execute_command(
                     config.near_key_path = Some(path);
                 }
 
-                config
-                    .to_file(config_path)
-                    .expect("Failed to write new CLI config file");
+                config.to_file(config_path).map_err(|e| {
+                    Error::Usage(format!(
+                        "Failed to write CLI config file {config_path}: {e}"
+                    ))
+                })?;
                 println!("Updated CLI config at {config_path}");
             }
         },
@@ -686,9 +724,9 @@ fn parse_read_call_args(
     target_addr_hex: &str,
     amount: Option<&str>,
 ) -> anyhow::Result<(Address, Address, Wei)> {
-    let target = utils::hex_to_address(target_addr_hex)?;
+    let target = crate::cli::parse_address(target_addr_hex)?;
     let sender = sender_addr_hex
-        .and_then(|x| utils::hex_to_address(&x).ok())
+        .and_then(|x| crate::cli::parse_address(&x).ok())
         .unwrap_or_default();
     let amount = amount
         .and_then(|a| U256::from_dec_str(a).ok())
@@ -701,14 +739,13 @@ fn parse_write_call_args(
     config: &Config,
     target_addr_hex: &str,
     amount: Option<&str>,
-) -> anyhow::Result<(libsecp256k1::SecretKey, Address, Wei)> {
-    let source_private_key_hex = config.get_evm_secret_key()?;
-    let secret_key = secret_key_from_hex(source_private_key_hex)?;
-    let target = utils::hex_to_address(target_addr_hex)?;
+) -> anyhow::Result<(Box<dyn EvmSigner>, Address, Wei)> {
+    let signer = config.get_evm_signer()?;
+    let target = crate::cli::parse_address(target_addr_hex)?;
     let amount = amount
         .and_then(|a| U256::from_dec_str(a).ok())
         .map_or_else(Wei::zero, Wei::new);
-    Ok((secret_key, target, amount))
+    Ok((signer, target, amount))
 }
 
 fn parse_xcc_args(
@@ -718,40 +755,42 @@ fn parse_xcc_args(
     json_args_stdin: Option<bool>,
     deposit_yocto: Option<String>,
     attached_gas: Option<String>,
-) -> PromiseCreateArgs {
-    let near_args = json_args.map_or_else(
-        || match json_args_stdin {
-            Some(true) => {
-                let mut buf = String::new();
-                std::io::Read::read_to_string(&mut std::io::stdin(), &mut buf).unwrap();
-                buf.into_bytes()
-            }
-            None | Some(false) => Vec::new(),
-        },
-        String::into_bytes,
-    );
-    let attached_balance =
-        deposit_yocto.map_or_else(|| Yocto::new(0), |x| Yocto::new(x.parse().unwrap()));
-    let attached_gas = attached_gas.map_or_else(
-        || NearGas::new(30_000_000_000_000),
-        |gas| NearGas::new(gas.parse().unwrap()),
-    );
+) -> anyhow::Result<PromiseCreateArgs> {
+    let near_args = match (json_args, json_args_stdin) {
+        (Some(args), _) => args.into_bytes(),
+        (None, Some(true)) => {
+            let mut buf = String::new();
+            std::io::Read::read_to_string(&mut std::io::stdin(), &mut buf)?;
+            buf.into_bytes()
+        }
+        (None, None | Some(false)) => Vec::new(),
+    };
+    let attached_balance = deposit_yocto
+        .map(|x| x.parse())
+        .transpose()
+        .map_err(|e| Error::Usage(format!("Invalid deposit: {e}")))?
+        .map_or_else(|| Yocto::new(0), Yocto::new);
+    let attached_gas = attached_gas
+        .map(|gas| gas.parse())
+        .transpose()
+        .map_err(|e| Error::Usage(format!("Invalid attached gas: {e}")))?
+        .map_or_else(|| NearGas::new(30_000_000_000_000), NearGas::new);
+    let target_account_id = target_near_account
+        .parse()
+        .map_err(|e| Error::Usage(format!("Invalid target account: {e}")))?;
 
-    PromiseCreateArgs {
-        target_account_id: target_near_account.parse().unwrap(),
+    Ok(PromiseCreateArgs {
+        target_account_id,
         method: method_name,
         args: near_args,
         attached_balance,
         attached_gas,
-    }
+    })
 }
 
-fn assert_tx_success(outcome: &FinalExecutionOutcomeView) {
-    assert!(
-        matches!(
-            &outcome.status,
-            near_primitives::views::FinalExecutionStatus::SuccessValue(_)
-        ),
-        "Transaction failed: {outcome:?}"
-    );
+fn assert_tx_success(outcome: &FinalExecutionOutcomeView) -> anyhow::Result<()> {
+    match &outcome.status {
+        near_primitives::views::FinalExecutionStatus::SuccessValue(_) => Ok(()),
+        status => Err(Error::near_transaction(format!("Transaction failed: {status:?}")).into()),
+    }
 }
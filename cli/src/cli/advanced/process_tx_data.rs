@@ -1,6 +1,6 @@
 use crate::{
+    error::Error,
     transaction_reader::{self, aggregator, filter},
-    utils,
 };
 use clap::Subcommand;
 use std::{path::Path, sync::Arc};
@@ -32,10 +32,16 @@ pub enum ProcessTxAction {
 pub async fn execute_command<P: AsRef<Path> + Send>(
     action: ProcessTxAction,
     input_files_list_path: P,
-) {
+) -> anyhow::Result<()> {
+    let input_files_list_path = input_files_list_path.as_ref();
     let paths_contents = tokio::fs::read_to_string(input_files_list_path)
         .await
-        .unwrap();
+        .map_err(|e| {
+            Error::Usage(format!(
+                "Couldn't read {}: {e}",
+                input_files_list_path.display()
+            ))
+        })?;
     let paths: Vec<String> = paths_contents
         .split('\n')
         .filter_map(|line| {
@@ -65,7 +71,7 @@ pub async fn execute_command<P: AsRef<Path> + Send>(
             }
         }
         ProcessTxAction::FilterTo { target_addr_hex } => {
-            let to = utils::hex_to_address(&target_addr_hex).unwrap();
+            let to = crate::cli::parse_address(&target_addr_hex)?;
             let f = Arc::new(filter::EthTxTo(to));
             transaction_reader::process_data::<aggregator::Echo, _>(paths, &f).await;
         }
@@ -102,4 +108,6 @@ pub async fn execute_command<P: AsRef<Path> + Send>(
             transaction_reader::process_data::<aggregator::FromToGasUsage, _>(paths, &f).await;
         }
     }
+
+    Ok(())
 }
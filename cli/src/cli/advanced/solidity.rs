@@ -1,3 +1,4 @@
+use crate::error::Error;
 use crate::utils;
 use clap::Subcommand;
 use serde_json::Value;
@@ -64,7 +65,7 @@ impl Solidity {
                     anyhow::bail!("Function must take only one argument");
                 }
                 let arg_type = &function.inputs.first().unwrap().kind;
-                let arg = read_arg(arg.as_deref(), *stdin_arg);
+                let arg = read_arg(arg.as_deref(), *stdin_arg)?;
 
                 function
                     .encode_input(&[utils::abi::parse_arg(arg.trim(), arg_type)?])
@@ -78,7 +79,7 @@ impl Solidity {
             } => {
                 let contract = utils::abi::read_contract(abi_path)?;
                 let function = contract.function(method_name)?;
-                let args: Value = serde_json::from_str(&read_arg(arg.as_deref(), *stdin_arg))?;
+                let args: Value = serde_json::from_str(&read_arg(arg.as_deref(), *stdin_arg)?)?;
                 let tokens = utils::abi::parse_args(&function.inputs, &args)?;
 
                 function.encode_input(&tokens).map_err(Into::into)
@@ -87,16 +88,17 @@ impl Solidity {
     }
 }
 
-fn read_arg(arg: Option<&str>, stdin_arg: Option<bool>) -> Cow<'_, str> {
+fn read_arg(arg: Option<&str>, stdin_arg: Option<bool>) -> anyhow::Result<Cow<'_, str>> {
     arg.map_or_else(
         || match stdin_arg {
             Some(true) => {
                 let mut buf = String::new();
-                std::io::Read::read_to_string(&mut std::io::stdin(), &mut buf).unwrap();
-                Cow::Owned(buf)
+                std::io::Read::read_to_string(&mut std::io::stdin(), &mut buf)
+                    .map_err(|e| Error::Usage(format!("Couldn't read the argument: {e}")))?;
+                Ok(Cow::Owned(buf))
             }
-            None | Some(false) => Cow::Owned(String::new()),
+            None | Some(false) => Ok(Cow::Owned(String::new())),
         },
-        Cow::Borrowed,
+        |arg| Ok(Cow::Borrowed(arg)),
     )
 }
@@ -0,0 +1,77 @@
+use clap::{Args, ValueEnum};
+use near_primitives::hash::CryptoHash;
+use near_primitives::types::{BlockId, BlockReference, Finality};
+
+/// Block to read the state at: by its height, its hash or the finality of the latest block.
+#[derive(Debug, Clone, Default, Args)]
+#[group(multiple = false)]
+pub struct BlockArgs {
+    /// NEAR block height to use for getting data
+    #[arg(long)]
+    pub block_height: Option<u64>,
+    /// NEAR block hash to use for getting data
+    #[arg(long, value_name = "HASH")]
+    pub block_hash: Option<CryptoHash>,
+    /// Finality of the latest NEAR block to use for getting data [default: final]
+    #[arg(long, value_enum)]
+    pub finality: Option<BlockFinality>,
+}
+
+/// Finality of the latest block: the optimistic one could still be skipped by the chain.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum BlockFinality {
+    Optimistic,
+    Final,
+}
+
+impl From<BlockFinality> for Finality {
+    fn from(finality: BlockFinality) -> Self {
+        match finality {
+            BlockFinality::Optimistic => Self::None,
+            BlockFinality::Final => Self::Final,
+        }
+    }
+}
+
+impl BlockArgs {
+    /// The block given by the arguments, or the final block if none of them is set.
+    #[must_use]
+    pub fn block_reference(&self) -> BlockReference {
+        match (self.block_height, self.block_hash, self.finality) {
+            (Some(height), _, _) => BlockId::Height(height).into(),
+            (None, Some(hash), _) => BlockId::Hash(hash).into(),
+            (None, None, finality) => {
+                Finality::from(finality.unwrap_or(BlockFinality::Final)).into()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_block_reference() {
+        assert_eq!(
+            BlockArgs::default().block_reference(),
+            BlockReference::Finality(Finality::Final)
+        );
+        assert_eq!(
+            BlockArgs {
+                block_height: Some(100),
+                ..BlockArgs::default()
+            }
+            .block_reference(),
+            BlockReference::BlockId(BlockId::Height(100))
+        );
+        assert_eq!(
+            BlockArgs {
+                finality: Some(BlockFinality::Optimistic),
+                ..BlockArgs::default()
+            }
+            .block_reference(),
+            BlockReference::Finality(Finality::None)
+        );
+    }
+}
@@ -1,5 +1,10 @@
+use aurora_engine_types::types::Address;
+
+use crate::utils;
+
 #[cfg(feature = "advanced")]
 mod advanced;
+pub mod block;
 #[cfg(feature = "simple")]
 pub mod simple;
 
@@ -19,3 +24,17 @@ const NEAR_LOCAL_ENDPOINT: &str = "http://127.0.0.1:3030/";
 const AURORA_MAINNET_ENDPOINT: &str = "https://mainnet.aurora.dev/";
 #[cfg(feature = "advanced")]
 const AURORA_TESTNET_ENDPOINT: &str = "https://testnet.aurora.dev/";
+
+/// Parse the EVM address given in the arguments, warning if its EIP-55 checksum is invalid.
+pub fn parse_address(h: &str) -> anyhow::Result<Address> {
+    let address = utils::hex_to_address(h)?;
+
+    if !utils::is_valid_checksum(h) {
+        eprintln!(
+            "Warning: the address: {h} has an invalid EIP-55 checksum, did you mean: {}?",
+            utils::to_checksum_address(&address)
+        );
+    }
+
+    Ok(address)
+}
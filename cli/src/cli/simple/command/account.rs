@@ -0,0 +1,182 @@
+//! NEAR account management: transfers, deletion of the account and its access keys.
+use near_crypto::PublicKey;
+use near_primitives::account::{AccessKey, AccessKeyPermission, FunctionCallPermission};
+use near_primitives::types::{AccountId, Balance};
+use near_primitives::views::{AccessKeyInfoView, AccessKeyPermissionView};
+use serde_json::json;
+
+use super::output::CommandOutput;
+use crate::client::Context;
+use crate::utils::near_to_yocto;
+
+/// Permission of the access key to add.
+pub enum KeyPermission {
+    FullAccess,
+    /// The key could call the methods of the receiver only, or any of its methods if the list is
+    /// empty. The allowance in NEAR is unlimited if it isn't set.
+    FunctionCall {
+        receiver: AccountId,
+        method_names: Vec<String>,
+        allowance: Option<f64>,
+    },
+}
+
+impl From<KeyPermission> for AccessKey {
+    fn from(permission: KeyPermission) -> Self {
+        match permission {
+            KeyPermission::FullAccess => Self::full_access(),
+            KeyPermission::FunctionCall {
+                receiver,
+                method_names,
+                allowance,
+            } => Self {
+                nonce: 0,
+                permission: AccessKeyPermission::FunctionCall(FunctionCallPermission {
+                    allowance: allowance.map(|near| Balance::from_yoctonear(near_to_yocto(near))),
+                    receiver_id: receiver.into(),
+                    method_names,
+                }),
+            },
+        }
+    }
+}
+
+/// Send NEAR from the signer's account to the receiver.
+pub async fn send_near(context: Context, receiver: AccountId, amount: f64) -> anyhow::Result<()> {
+    let client = context.client.near().sdk_client()?;
+    let outcome = client
+        .batch(&receiver)
+        .transfer(Balance::from_yoctonear(near_to_yocto(amount)))
+        .transact()
+        .await?;
+
+    CommandOutput::transaction(&outcome, "Error while sending NEAR")
+        .with_message(format!("{amount} NEAR has been sent to {receiver}"))
+        .print(&context.output_format)
+}
+
+/// Delete the signer's account and send its balance to the beneficiary.
+pub async fn delete_account(context: Context, beneficiary: AccountId) -> anyhow::Result<()> {
+    let client = context.client.near().sdk_client()?;
+    let account_id = client.signer().get_account_id();
+    let outcome = client
+        .batch(&account_id)
+        .delete_account(&beneficiary)
+        .transact()
+        .await?;
+
+    CommandOutput::transaction(&outcome, "Error while deleting account")
+        .with_message(format!(
+            "The account {account_id} has been deleted, its balance is sent to {beneficiary}"
+        ))
+        .print(&context.output_format)
+}
+
+/// Add the access key to the signer's account.
+pub async fn add_key(
+    context: Context,
+    public_key: PublicKey,
+    permission: KeyPermission,
+) -> anyhow::Result<()> {
+    let client = context.client.near().sdk_client()?;
+    let account_id = client.signer().get_account_id();
+    let outcome = client
+        .batch(&account_id)
+        .add_key(public_key.clone(), permission.into())
+        .transact()
+        .await?;
+
+    CommandOutput::transaction(&outcome, "Error while adding key")
+        .with_message(format!(
+            "The key {public_key} has been added to {account_id}"
+        ))
+        .print(&context.output_format)
+}
+
+/// Delete the access key of the signer's account.
+pub async fn delete_key(context: Context, public_key: PublicKey) -> anyhow::Result<()> {
+    let client = context.client.near().sdk_client()?;
+    let account_id = client.signer().get_account_id();
+    let outcome = client
+        .batch(&account_id)
+        .delete_key(public_key.clone())
+        .transact()
+        .await?;
+
+    CommandOutput::transaction(&outcome, "Error while deleting key")
+        .with_message(format!(
+            "The key {public_key} has been deleted from {account_id}"
+        ))
+        .print(&context.output_format)
+}
+
+/// List the access keys of the account with their permissions.
+pub async fn list_keys(context: Context, account: AccountId) -> anyhow::Result<()> {
+    let keys = context.client.near().view_access_keys(&account).await?;
+    let message = table(&keys);
+    let keys = keys
+        .iter()
+        .map(|key| {
+            json!({
+                "public_key": key.public_key,
+                "nonce": key.access_key.nonce,
+                "permission": key.access_key.permission,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    CommandOutput::value(keys)?
+        .with_message(message)
+        .print(&context.output_format)
+}
+
+fn table(keys: &[AccessKeyInfoView]) -> String {
+    if keys.is_empty() {
+        return "No access keys".to_string();
+    }
+
+    let width = keys
+        .iter()
+        .map(|key| key.public_key.to_string().len())
+        .chain(std::iter::once("PUBLIC KEY".len()))
+        .max()
+        .unwrap_or_default();
+
+    std::iter::once(format!(
+        "{:width$}  {:20}  PERMISSION",
+        "PUBLIC KEY", "NONCE"
+    ))
+    .chain(keys.iter().map(|key| {
+        format!(
+            "{:width$}  {:<20}  {}",
+            key.public_key.to_string(),
+            key.access_key.nonce,
+            permission(&key.access_key.permission)
+        )
+    }))
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+fn permission(permission: &AccessKeyPermissionView) -> String {
+    match permission {
+        AccessKeyPermissionView::FullAccess => "full access".to_string(),
+        AccessKeyPermissionView::FunctionCall {
+            allowance,
+            receiver_id,
+            method_names,
+        } => {
+            let methods = if method_names.is_empty() {
+                "any method".to_string()
+            } else {
+                method_names.join(", ")
+            };
+            let allowance = allowance.map_or_else(
+                || "unlimited allowance".to_string(),
+                |allowance| format!("allowance {allowance}"),
+            );
+
+            format!("function call to {receiver_id}: {methods}, {allowance}")
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use aurora_engine_types::account_id::AccountId;
+use aurora_engine_types::{H256, U256};
+
+use super::output::CommandOutput;
+use crate::cli::parse_address;
+use crate::cli::simple::OutputFormat;
+use crate::utils::{self, hex_to_arr, hex_to_vec};
+
+/// Print the address in the EIP-55 checksum encoding.
+pub fn checksum(address: &str, format: &OutputFormat) -> anyhow::Result<()> {
+    let address = hex_to_arr(address).map(aurora_engine_types::types::Address::from_array)?;
+    CommandOutput::value(utils::to_checksum_address(&address))?.print(format)
+}
+
+/// Check the EIP-55 checksum of the address.
+pub fn validate(address: &str, format: &OutputFormat) -> anyhow::Result<()> {
+    let checksum = hex_to_arr(address)
+        .map(aurora_engine_types::types::Address::from_array)
+        .map(|address| utils::to_checksum_address(&address))?;
+
+    anyhow::ensure!(
+        utils::is_valid_checksum(address),
+        "The address: {address} has an invalid EIP-55 checksum, expected: {checksum}"
+    );
+    CommandOutput::value(checksum)?.print(format)
+}
+
+/// Print the address of the contract deployed by the sender with the nonce.
+pub fn create(sender: &str, nonce: u64, format: &OutputFormat) -> anyhow::Result<()> {
+    let address = utils::create_address(parse_address(sender)?, U256::from(nonce));
+    CommandOutput::value(utils::to_checksum_address(&address))?.print(format)
+}
+
+/// Print the address of the contract deployed by the sender via `CREATE2` with the salt and
+/// the init code or its hash.
+pub fn create2(
+    sender: &str,
+    salt: &str,
+    init_code: Option<String>,
+    init_code_hash: Option<String>,
+    format: &OutputFormat,
+) -> anyhow::Result<()> {
+    let salt = hex_to_arr(salt).map(H256)?;
+    let init_code_hash = match (init_code, init_code_hash) {
+        (Some(code), None) => aurora_engine_sdk::keccak(&hex_to_vec(&code)?),
+        (None, Some(hash)) => hex_to_arr(&hash).map(H256)?,
+        _ => anyhow::bail!("Either init code or its hash must be provided"),
+    };
+    let address = utils::create2_address(parse_address(sender)?, salt, init_code_hash);
+    CommandOutput::value(utils::to_checksum_address(&address))?.print(format)
+}
+
+/// Print the account id of the XCC router sub-account for the address.
+pub fn xcc_router(engine: &str, address: &str, format: &OutputFormat) -> anyhow::Result<()> {
+    let address = parse_address(address)?;
+    let account_id: AccountId = format!("{}.{engine}", address.encode())
+        .parse()
+        .map_err(|e| anyhow::anyhow!("Couldn't create XCC router account id: {e}"))?;
+    CommandOutput::value(account_id)?.print(format)
+}
@@ -0,0 +1,220 @@
+//! Emergency pause of the engine in one go and the restoration of the state it had before.
+use std::mem::discriminant;
+use std::path::Path;
+
+use aurora_engine_types::parameters::connector::{PauseEthConnectorArgs, PausedMask};
+use aurora_engine_types::parameters::engine::PausePrecompilesCallArgs;
+use aurora_sdk_rs::aurora::error::EngineError;
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+use super::output::{CommandOutput, Status};
+use super::{ContractCall, FromCallResult, pause_contract_call, resume_contract_call};
+use crate::cli::simple::OutputFormat;
+use crate::client::{Context, NearClient};
+use crate::contract_call;
+use crate::error::Error;
+use crate::utils::pausable::{PausedFlags, PrecompileMask};
+
+/// State of the engine before the emergency pause.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub engine: String,
+    pub paused_precompiles: u32,
+    pub paused_flags: PausedMask,
+    /// Whether the contract had been paused before, so `resume` keeps it paused.
+    #[serde(default)]
+    pub contract_paused: bool,
+}
+
+/// Pause all the precompiles, the ETH connector and the contract.
+///
+/// The paused precompiles, ETH connector flags and contract are saved into the snapshot file
+/// beforehand.
+/// An existing snapshot is kept, so a repeated pause after a partial failure doesn't lose the
+/// state before the first one.
+pub async fn pause<P: AsRef<Path> + Send>(context: Context, snapshot: P) -> anyhow::Result<()> {
+    let path = snapshot.as_ref();
+    let client = context.client.near_latest();
+    let snapshot = if path.exists() {
+        read_snapshot(path, &client)?
+    } else {
+        let snapshot = take_snapshot(&client).await?;
+        std::fs::write(path, serde_json::to_string_pretty(&snapshot)?)?;
+        snapshot
+    };
+
+    let steps = vec![
+        Step::new(
+            precompiles_call("pause_precompiles", PrecompileMask::all().bits())?,
+            None,
+        ),
+        Step::new(
+            paused_flags_call(PausedMask::try_from(PausedFlags::all().bits())?)?,
+            None,
+        ),
+        Step::new((pause_contract_call(), vec![]), Some(EngineError::Paused)),
+    ];
+    let transactions = run(&client, steps, &context.output_format).await?;
+
+    output(&snapshot, &transactions)?
+        .with_message(format!(
+            "The engine has been paused, the previous state is saved in {}",
+            path.display()
+        ))
+        .print(&context.output_format)
+}
+
+/// Restore the paused precompiles, ETH connector flags and contract from the snapshot file,
+/// which is removed afterwards. The contract paused before the snapshot stays paused.
+pub async fn resume<P: AsRef<Path> + Send>(context: Context, snapshot: P) -> anyhow::Result<()> {
+    let path = snapshot.as_ref();
+    let client = context.client.near_latest();
+    let snapshot = read_snapshot(path, &client)?;
+    let resumed_precompiles = PrecompileMask::all().bits() & !snapshot.paused_precompiles;
+
+    let mut steps = vec![];
+
+    if !snapshot.contract_paused {
+        steps.push(Step::new(
+            (resume_contract_call(), vec![]),
+            Some(EngineError::Running),
+        ));
+    }
+
+    if resumed_precompiles != 0 {
+        steps.push(Step::new(
+            precompiles_call("resume_precompiles", resumed_precompiles)?,
+            None,
+        ));
+    }
+
+    steps.push(Step::new(paused_flags_call(snapshot.paused_flags)?, None));
+
+    let transactions = run(&client, steps, &context.output_format).await?;
+
+    std::fs::remove_file(path)?;
+
+    output(&snapshot, &transactions)?
+        .with_message(format!(
+            "The engine has been resumed to the state saved in {}",
+            path.display()
+        ))
+        .print(&context.output_format)
+}
+
+async fn take_snapshot(client: &NearClient) -> anyhow::Result<Snapshot> {
+    let paused_precompiles = client
+        .view_call("get_paused_precompiles", vec![])
+        .await
+        .and_then(u32::from_result)?;
+    let paused_flags = client
+        .view_call("get_paused_flags", vec![])
+        .await
+        .and_then(PausedMask::from_result)?;
+    let contract_paused = client
+        .view_call("is_paused", vec![])
+        .await
+        .and_then(bool::from_result)?;
+
+    Ok(Snapshot {
+        engine: client.engine_account_id.to_string(),
+        paused_precompiles,
+        paused_flags,
+        contract_paused,
+    })
+}
+
+fn read_snapshot(path: &Path, client: &NearClient) -> anyhow::Result<Snapshot> {
+    let snapshot: Snapshot = std::fs::read_to_string(path)
+        .map_err(|e| Error::Usage(format!("Couldn't read {}: {e}", path.display())))
+        .and_then(|json| {
+            serde_json::from_str(&json)
+                .map_err(|e| Error::Usage(format!("Wrong snapshot {}: {e}", path.display())))
+        })?;
+
+    if snapshot.engine != client.engine_account_id.as_str() {
+        return Err(Error::Usage(format!(
+            "The snapshot {} is taken from {}, not from {}",
+            path.display(),
+            snapshot.engine,
+            client.engine_account_id
+        ))
+        .into());
+    }
+
+    Ok(snapshot)
+}
+
+fn precompiles_call(method: &'static str, mask: u32) -> anyhow::Result<(ContractCall, Vec<u8>)> {
+    let args = borsh::to_vec(&PausePrecompilesCallArgs { paused_mask: mask })?;
+    let call = contract_call!(
+        method,
+        "The precompiles have been updated successfully",
+        "Error while updating precompiles"
+    );
+
+    Ok((call, args))
+}
+
+fn paused_flags_call(paused_mask: PausedMask) -> anyhow::Result<(ContractCall, Vec<u8>)> {
+    let args = borsh::to_vec(&PauseEthConnectorArgs { paused_mask })?;
+    let call = contract_call!(
+        "set_paused_flags",
+        "The pause mask has been set successfully",
+        "Error while setting pause mask"
+    );
+
+    Ok((call, args))
+}
+
+/// Call of the runbook. The call rejected with the `done` error is skipped, because the engine is
+/// already in the state the call would bring it to, e.g. the contract is already paused.
+struct Step {
+    call: ContractCall,
+    args: Vec<u8>,
+    done: Option<EngineError>,
+}
+
+impl Step {
+    fn new((call, args): (ContractCall, Vec<u8>), done: Option<EngineError>) -> Self {
+        Self { call, args, done }
+    }
+}
+
+/// Send the calls one by one and return the methods with the hashes of their transactions.
+/// The output of the first failed call is printed and returned as the error.
+async fn run(
+    client: &NearClient,
+    steps: Vec<Step>,
+    format: &OutputFormat,
+) -> anyhow::Result<Vec<Value>> {
+    let mut transactions = vec![];
+
+    for Step { call, args, done } in steps {
+        let output = call.transact(client, args, 0.0, None).await?;
+        let skipped = matches!(
+            (output.engine_error(), &done),
+            (Some(error), Some(done)) if discriminant(error) == discriminant(done)
+        );
+
+        if output.status == Status::Failure && !skipped {
+            return output.print(format).map(|()| transactions);
+        }
+
+        transactions.push(json!({
+            "method": call.method,
+            "tx_hash": output.tx_hash,
+            "skipped": skipped,
+        }));
+    }
+
+    Ok(transactions)
+}
+
+fn output(snapshot: &Snapshot, transactions: &[Value]) -> anyhow::Result<CommandOutput> {
+    CommandOutput::value(json!({
+        "snapshot": snapshot,
+        "transactions": transactions,
+    }))
+}
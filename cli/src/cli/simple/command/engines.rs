@@ -0,0 +1,207 @@
+//! Running the same admin command on many engines at once.
+use std::num::NonZeroUsize;
+use std::path::Path;
+use std::sync::Arc;
+
+use near_primitives::types::AccountId;
+use serde::Serialize;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+use super::output::{CommandOutput, Status};
+use super::{ContractCall, silo};
+use crate::cli::simple::{Command, OutputFormat};
+use crate::client::Context;
+use crate::error::{Error, Reported};
+
+/// Admin command, which could be run on many engines at once.
+pub enum EngineCommand {
+    PauseContract,
+    ResumeContract,
+    SetFixedGas { cost: u64 },
+    AddEntryToWhitelist { kind: String, entry: String },
+    RegisterRelayer { address: String },
+}
+
+impl TryFrom<Command> for EngineCommand {
+    type Error = Error;
+
+    fn try_from(command: Command) -> Result<Self, Self::Error> {
+        match command {
+            Command::PauseContract => Ok(Self::PauseContract),
+            Command::ResumeContract => Ok(Self::ResumeContract),
+            Command::SetFixedGas { cost } => Ok(Self::SetFixedGas { cost }),
+            Command::AddEntryToWhitelist { kind, entry } => {
+                Ok(Self::AddEntryToWhitelist { kind, entry })
+            }
+            Command::RegisterRelayer { address } => Ok(Self::RegisterRelayer { address }),
+            _ => Err(Error::Usage(
+                "--engines is supported by pause-contract, resume-contract, set-fixed-gas, \
+                 add-entry-to-whitelist and register-relayer only"
+                    .to_string(),
+            )),
+        }
+    }
+}
+
+impl EngineCommand {
+    fn contract_call(&self) -> anyhow::Result<(ContractCall, Vec<u8>)> {
+        match self {
+            Self::PauseContract => Ok((super::pause_contract_call(), vec![])),
+            Self::ResumeContract => Ok((super::resume_contract_call(), vec![])),
+            Self::SetFixedGas { cost } => silo::set_fixed_gas_call(*cost),
+            Self::AddEntryToWhitelist { kind, entry } => {
+                silo::add_entry_to_whitelist_call(kind, entry)
+            }
+            Self::RegisterRelayer { address } => super::register_relayer_call(address),
+        }
+    }
+}
+
+/// Outcome of the command on one of the engines.
+#[derive(Debug, Serialize)]
+struct EngineOutput {
+    engine: AccountId,
+    #[serde(flatten)]
+    output: CommandOutput,
+}
+
+/// Summary of the command run on all the engines.
+#[derive(Debug, Serialize)]
+struct Summary<'a> {
+    status: Status,
+    engines: &'a [EngineOutput],
+}
+
+/// Read the engine accounts from the file with an account per line. Blank lines and lines
+/// starting with `#` are skipped.
+pub fn read_engines<P: AsRef<Path>>(path: P) -> anyhow::Result<Vec<AccountId>> {
+    let path = path.as_ref();
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Couldn't read {}: {e}", path.display()))?;
+
+    let engines = content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            line.parse()
+                .map_err(|e| Error::Usage(format!("Wrong engine account: {line}: {e}")))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Otherwise the command would silently run on the default engine instead.
+    if engines.is_empty() {
+        return Err(Error::Usage(format!("No engine accounts in {}", path.display())).into());
+    }
+
+    Ok(engines)
+}
+
+/// Run the command on every engine concurrently with at most `parallelism` transactions in
+/// flight, and print the summary. The command fails if it has failed on any engine.
+pub async fn run(
+    context: Context,
+    engines: Vec<AccountId>,
+    command: EngineCommand,
+    parallelism: NonZeroUsize,
+) -> anyhow::Result<()> {
+    let (call, args) = command.contract_call()?;
+    let call = Arc::new(call);
+    let semaphore = Arc::new(Semaphore::new(parallelism.get()));
+    // All the transactions are signed by the same key, so the engines share the nonce cache.
+    // Concurrent transactions may be included out of order, and the client signs the ones
+    // rejected for a stale nonce again.
+    let client = context.client.near();
+    let mut tasks = JoinSet::new();
+
+    for (index, engine) in engines.iter().enumerate() {
+        let client = client.clone().with_engine_account_id(engine);
+        let (call, args, semaphore) = (call.clone(), args.clone(), semaphore.clone());
+
+        tasks.spawn(async move {
+            let _permit = semaphore.acquire_owned().await;
+            let output = call
+                .transact(&client, args, 0.0, None)
+                .await
+                .unwrap_or_else(|e| CommandOutput::error(&e));
+
+            (index, output)
+        });
+    }
+
+    let mut outputs = Vec::with_capacity(engines.len());
+
+    while let Some(result) = tasks.join_next().await {
+        outputs.push(result?);
+    }
+
+    outputs.sort_by_key(|(index, _)| *index);
+
+    let engines = engines
+        .into_iter()
+        .zip(outputs)
+        .map(|(engine, (_, output))| EngineOutput { engine, output })
+        .collect::<Vec<_>>();
+    let failed = engines
+        .iter()
+        .filter(|e| e.output.status == Status::Failure)
+        .count();
+    let failure = engines
+        .iter()
+        .find_map(|e| e.output.error.as_ref().map(|error| error.kind))
+        .map(|kind| Error::Engines {
+            message: format!(
+                "The command has failed on {failed} of {} engines",
+                engines.len()
+            ),
+            kind,
+        });
+
+    print(&engines, failure.is_some(), &context.output_format)?;
+
+    failure.map_or(Ok(()), |error| Err(Reported(error).into()))
+}
+
+fn print(engines: &[EngineOutput], failed: bool, format: &OutputFormat) -> anyhow::Result<()> {
+    match format {
+        OutputFormat::Plain => {
+            let width = engines
+                .iter()
+                .map(|e| e.engine.len())
+                .chain(std::iter::once("ENGINE".len()))
+                .max()
+                .unwrap_or_default();
+
+            println!("{:width$}  {:7}  DETAILS", "ENGINE", "STATUS");
+
+            for EngineOutput { engine, output } in engines {
+                let (status, details) = match (&output.error, &output.tx_hash) {
+                    (Some(error), _) => ("failure", error.message.clone()),
+                    (None, Some(hash)) => ("success", hash.to_string()),
+                    (None, None) => ("success", String::new()),
+                };
+
+                println!("{:width$}  {status:7}  {details}", engine.as_str());
+            }
+        }
+        OutputFormat::Json | OutputFormat::Toml => {
+            let summary = Summary {
+                status: if failed {
+                    Status::Failure
+                } else {
+                    Status::Success
+                },
+                engines,
+            };
+
+            if matches!(format, OutputFormat::Json) {
+                println!("{}", serde_json::to_string_pretty(&summary)?);
+            } else {
+                println!("{}", toml::to_string_pretty(&summary)?);
+            }
+        }
+    }
+
+    Ok(())
+}
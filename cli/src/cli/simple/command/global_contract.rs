@@ -0,0 +1,140 @@
+//! NEAR global contracts: publishing the engine or XCC router code once and using it from many
+//! accounts, such as silos, which don't pay for the storage of their own copy of the code.
+use std::path::Path;
+
+use aurora_sdk_rs::near::types::{GlobalContractDeployMode, GlobalContractIdentifier};
+use clap::ValueEnum;
+use near_primitives::hash::CryptoHash;
+use near_primitives::types::AccountId;
+use serde_json::json;
+
+use super::output::CommandOutput;
+use crate::client::Context;
+use crate::error::Error;
+use crate::utils::wasm::{self, Contract};
+
+/// The way the global contract is identified by the accounts using it.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum DeployMode {
+    /// By the hash of the code, which can't be changed
+    CodeHash,
+    /// By the account which has published it, which can publish a new code for all its users
+    AccountId,
+}
+
+impl From<DeployMode> for GlobalContractDeployMode {
+    fn from(mode: DeployMode) -> Self {
+        match mode {
+            DeployMode::CodeHash => Self::CodeHash,
+            DeployMode::AccountId => Self::AccountId,
+        }
+    }
+}
+
+/// Publish the code as a global contract from the signer's account.
+pub async fn deploy<P: AsRef<Path> + Send>(
+    context: Context,
+    path: P,
+    contract: Contract,
+    mode: DeployMode,
+) -> anyhow::Result<()> {
+    let path = path.as_ref();
+    let code = std::fs::read(path)
+        .map_err(|e| Error::Usage(format!("Couldn't read {}: {e}", path.display())))?;
+    wasm::check(&code, contract)?;
+    let code_hash = CryptoHash::hash_bytes(&code);
+    let client = context.client.near().sdk_client()?;
+    let account_id = client.signer().get_account_id();
+    let outcome = client
+        .batch(&account_id)
+        .deploy_global_contract(&code, mode.into())
+        .transact()
+        .await?;
+    let identifier = match mode {
+        DeployMode::CodeHash => format!("--code-hash {code_hash}"),
+        DeployMode::AccountId => format!("--account-id {account_id}"),
+    };
+
+    CommandOutput::transaction(&outcome, "Error while deploying global contract")
+        .with_message(format!(
+            "The global contract {code_hash} has been deployed by {account_id}, use it with \
+             `global-contract use {identifier}`"
+        ))
+        .print(&context.output_format)
+}
+
+/// Replace the code of the engine account with the global contract. The transaction is signed by
+/// the engine account with the key of the signer, which must be a full access key of the engine.
+pub async fn use_global_contract(
+    context: Context,
+    code_hash: Option<CryptoHash>,
+    account_id: Option<AccountId>,
+) -> anyhow::Result<()> {
+    let identifier = match (code_hash, account_id) {
+        (Some(code_hash), None) => GlobalContractIdentifier::CodeHash(code_hash),
+        (None, Some(account_id)) => GlobalContractIdentifier::AccountId(account_id),
+        _ => {
+            return Err(Error::Usage(
+                "Either --code-hash or --account-id of the global contract is required".to_string(),
+            )
+            .into());
+        }
+    };
+    let description = describe(&identifier);
+    let near = context.client.near();
+    let engine = near.engine_account_id.clone();
+    let outcome = near
+        .sdk_client()?
+        .batch(&engine)
+        .signer_id(&engine)
+        .use_global_contract(identifier)
+        .transact()
+        .await?;
+
+    CommandOutput::transaction(&outcome, "Error while switching to global contract")
+        .with_message(format!(
+            "{engine} now uses the global contract {description}"
+        ))
+        .print(&context.output_format)
+}
+
+/// Print the global contract the account uses, if any.
+pub async fn get(context: Context, account: Option<AccountId>) -> anyhow::Result<()> {
+    let near = context.client.near();
+    let account = account.unwrap_or_else(|| near.engine_account_id.clone());
+    let view = near.view_account(account.as_str()).await?;
+    let identifier = view
+        .global_contract_hash
+        .map(GlobalContractIdentifier::CodeHash)
+        .or_else(|| {
+            view.global_contract_account_id
+                .clone()
+                .map(GlobalContractIdentifier::AccountId)
+        });
+    let message = identifier.as_ref().map_or_else(
+        || format!("{account} doesn't use a global contract"),
+        |identifier| {
+            format!(
+                "{account} uses the global contract {}",
+                describe(identifier)
+            )
+        },
+    );
+
+    CommandOutput::value(json!({
+        "account_id": account,
+        "global_contract_hash": view.global_contract_hash,
+        "global_contract_account_id": view.global_contract_account_id,
+    }))?
+    .with_message(message)
+    .print(&context.output_format)
+}
+
+fn describe(identifier: &GlobalContractIdentifier) -> String {
+    match identifier {
+        GlobalContractIdentifier::CodeHash(code_hash) => format!("with the code hash {code_hash}"),
+        GlobalContractIdentifier::AccountId(account_id) => {
+            format!("published by {account_id}")
+        }
+    }
+}
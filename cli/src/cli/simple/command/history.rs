@@ -0,0 +1,304 @@
+//! Time series of the engine state: a getter evaluated at every sampled block of a range, which
+//! requires an archival node for the blocks older than a few epochs.
+use std::io::Write;
+use std::num::{NonZeroU32, NonZeroU64, NonZeroUsize};
+use std::str::FromStr;
+use std::time::Duration;
+
+use aurora_engine_types::H256;
+use aurora_engine_types::U256;
+use aurora_engine_types::borsh::BorshDeserialize;
+use aurora_engine_types::parameters::engine::{GetStorageAtArgs, TransactionStatus, ViewCallArgs};
+use aurora_engine_types::types::Wei;
+use clap::{Subcommand, ValueEnum};
+use futures::{StreamExt, stream};
+use near_primitives::types::BlockId;
+use near_primitives::views::CallResult;
+use serde_json::{Value, json};
+use tokio::sync::Mutex;
+use tokio::time::{Interval, MissedTickBehavior};
+
+use super::FromCallResult;
+use crate::cli::parse_address;
+use crate::client::{Context, NearClient};
+use crate::error::Error;
+use crate::utils::pausable::PausedFlags;
+
+/// Selector of the ERC-20 `balanceOf(address)` function.
+const BALANCE_OF: [u8; 4] = [0x70, 0xa0, 0x82, 0x31];
+
+/// Value read at every sampled block.
+#[derive(Debug, Clone, Subcommand)]
+pub enum Getter {
+    /// Balance of the EVM address in wei
+    Balance { address: String },
+    /// Nonce of the EVM address
+    Nonce { address: String },
+    /// Value of the storage slot of the EVM contract
+    StorageAt {
+        #[arg(long)]
+        address: String,
+        #[arg(long)]
+        key: String,
+    },
+    /// ERC-20 balance of the holder, read with the `balanceOf` view call
+    Erc20Balance {
+        /// Address of the ERC-20 token
+        #[arg(long)]
+        token: String,
+        /// Address of the holder
+        #[arg(long)]
+        holder: String,
+    },
+    /// Version of the engine
+    Version,
+    /// Paused flags of the ETH connector
+    PausedFlags,
+}
+
+/// Format of the written time series.
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+pub enum Format {
+    /// Comma-separated `block_height,value,error` rows with the header
+    #[default]
+    Csv,
+    /// JSON object per line with the `block_height` and the `value` or the `error`
+    Jsonl,
+}
+
+/// Blocks to sample: every `step` block from `from` to `to` inclusive.
+#[derive(Debug, Clone, Copy)]
+pub struct BlockRange {
+    pub from: u64,
+    pub to: u64,
+    pub step: NonZeroU64,
+}
+
+impl BlockRange {
+    fn heights(self) -> impl Iterator<Item = u64> {
+        (self.from..=self.to).step_by(usize::try_from(self.step.get()).unwrap_or(usize::MAX))
+    }
+}
+
+/// View call of the getter with the decoder of its result.
+struct Query {
+    method: &'static str,
+    args: Vec<u8>,
+    decode: fn(CallResult) -> anyhow::Result<(String, Value)>,
+}
+
+impl TryFrom<Getter> for Query {
+    type Error = anyhow::Error;
+
+    fn try_from(getter: Getter) -> Result<Self, Self::Error> {
+        let query = match getter {
+            Getter::Balance { address } => Self {
+                method: "get_balance",
+                args: parse_address(&address)?.as_bytes().to_vec(),
+                decode: decode::<U256>,
+            },
+            Getter::Nonce { address } => Self {
+                method: "get_nonce",
+                args: parse_address(&address)?.as_bytes().to_vec(),
+                decode: decode::<U256>,
+            },
+            Getter::StorageAt { address, key } => Self {
+                method: "get_storage_at",
+                args: borsh::to_vec(&GetStorageAtArgs {
+                    address: parse_address(&address)?,
+                    key: H256::from_str(&key)
+                        .map_err(|e| Error::Usage(format!("Wrong storage key {key}: {e}")))?
+                        .0,
+                })?,
+                decode: decode::<H256>,
+            },
+            Getter::Erc20Balance { token, holder } => {
+                let holder = parse_address(&holder)?;
+                let input = [&BALANCE_OF[..], &[0; 12], holder.as_bytes()].concat();
+
+                Self {
+                    method: "view",
+                    args: borsh::to_vec(&ViewCallArgs {
+                        sender: holder,
+                        address: parse_address(&token)?,
+                        amount: Wei::zero().to_bytes(),
+                        input,
+                    })?,
+                    decode: decode_erc20_balance,
+                }
+            }
+            Getter::Version => Self {
+                method: "get_version",
+                args: vec![],
+                decode: decode::<String>,
+            },
+            Getter::PausedFlags => Self {
+                method: "get_paused_flags",
+                args: vec![],
+                decode: decode::<PausedFlags>,
+            },
+        };
+
+        Ok(query)
+    }
+}
+
+/// Value or error of the getter at the block.
+struct Sample {
+    block_height: u64,
+    result: Result<(String, Value), String>,
+}
+
+/// Evaluate the getter at every sampled block and write the samples to `output` in the order of
+/// the blocks.
+///
+/// At most `parallelism` requests are in flight and at most `rate_limit` are sent per second.
+/// The blocks the getter fails at, such as the skipped ones, are written with the error.
+pub async fn run<W: Write + Send>(
+    context: Context,
+    getter: Getter,
+    range: BlockRange,
+    format: Format,
+    parallelism: NonZeroUsize,
+    rate_limit: Option<NonZeroU32>,
+    output: &mut W,
+) -> anyhow::Result<()> {
+    if range.from > range.to {
+        return Err(Error::Usage(format!(
+            "The first block {} is after the last block {}",
+            range.from, range.to
+        ))
+        .into());
+    }
+
+    let query = Query::try_from(getter)?;
+    let limiter = rate_limit.map(|rate| {
+        let mut interval = tokio::time::interval(Duration::from_secs(1) / rate.get());
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        Mutex::new(interval)
+    });
+    let client = context.client.near();
+    // The heights are fed lazily, so only `parallelism` requests and their samples are held.
+    let mut samples = stream::iter(range.heights())
+        .map(|block_height| {
+            let client = client
+                .clone()
+                .with_block_reference(BlockId::Height(block_height).into());
+            let (query, limiter) = (&query, limiter.as_ref());
+
+            async move {
+                let result = sample(&client, query, limiter)
+                    .await
+                    .map_err(|e| e.to_string());
+
+                Sample {
+                    block_height,
+                    result,
+                }
+            }
+        })
+        .buffered(parallelism.get());
+
+    if matches!(format, Format::Csv) {
+        writeln!(output, "block_height,value,error")?;
+    }
+
+    while let Some(sample) = samples.next().await {
+        writeln!(output, "{}", line(&sample, format))?;
+    }
+
+    Ok(())
+}
+
+async fn sample(
+    client: &NearClient,
+    query: &Query,
+    limiter: Option<&Mutex<Interval>>,
+) -> anyhow::Result<(String, Value)> {
+    if let Some(limiter) = limiter {
+        limiter.lock().await.tick().await;
+    }
+
+    let result = client.view_call(query.method, query.args.clone()).await?;
+
+    (query.decode)(result)
+}
+
+fn decode<T: FromCallResult>(result: CallResult) -> anyhow::Result<(String, Value)> {
+    let value = T::from_result(result)?;
+
+    Ok((value.to_string(), value.to_value()))
+}
+
+fn decode_erc20_balance(CallResult { result, .. }: CallResult) -> anyhow::Result<(String, Value)> {
+    match TransactionStatus::try_from_slice(&result)? {
+        TransactionStatus::Succeed(output) if output.len() == 32 => {
+            let balance = U256::from_big_endian(&output);
+            Ok((balance.to_string(), balance.to_string().into()))
+        }
+        status => anyhow::bail!("balanceOf has failed: {status:?}"),
+    }
+}
+
+fn line(sample: &Sample, format: Format) -> String {
+    match (format, &sample.result) {
+        (Format::Csv, Ok((value, _))) => format!("{},{},", sample.block_height, csv_field(value)),
+        (Format::Csv, Err(error)) => format!("{},,{}", sample.block_height, csv_field(error)),
+        (Format::Jsonl, Ok((_, value))) => {
+            json!({ "block_height": sample.block_height, "value": value }).to_string()
+        }
+        (Format::Jsonl, Err(error)) => {
+            json!({ "block_height": sample.block_height, "error": error }).to_string()
+        }
+    }
+}
+
+/// The field is quoted if it contains a comma, a quote or a line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_heights() {
+        let range = BlockRange {
+            from: 100,
+            to: 105,
+            step: NonZeroU64::new(2).unwrap(),
+        };
+
+        assert_eq!(range.heights().collect::<Vec<_>>(), [100, 102, 104]);
+    }
+
+    #[test]
+    fn test_csv_line() {
+        let ok = Sample {
+            block_height: 100,
+            result: Ok((
+                "deposit, withdraw".to_string(),
+                json!(["deposit", "withdraw"]),
+            )),
+        };
+        let err = Sample {
+            block_height: 101,
+            result: Err("Block \"101\" is missing".to_string()),
+        };
+
+        assert_eq!(line(&ok, Format::Csv), "100,\"deposit, withdraw\",");
+        assert_eq!(
+            line(&err, Format::Csv),
+            "101,,\"Block \"\"101\"\" is missing\""
+        );
+        assert_eq!(
+            line(&ok, Format::Jsonl),
+            r#"{"block_height":100,"value":["deposit","withdraw"]}"#
+        );
+    }
+}
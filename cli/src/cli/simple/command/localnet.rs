@@ -0,0 +1,554 @@
+//! Bootstrap of Aurora EVM on a running local NEAR node.
+//!
+//! It creates the engine and key manager accounts, deploys and initializes the engine, sets the
+//! ETH connector data, updates the XCC router and adds a relayer key. Every step is skipped if it
+//! has already been done, so the bootstrap could be repeated.
+use std::path::{Path, PathBuf};
+
+use aurora_sdk_rs::near::client::Client;
+use aurora_sdk_rs::near::error::Error as NearError;
+use near_crypto::{KeyFile, KeyType, SecretKey};
+use near_jsonrpc_client::errors::{JsonRpcError, JsonRpcServerError};
+use near_jsonrpc_client::methods::query::RpcQueryError;
+use near_primitives::account::AccessKey;
+use near_primitives::hash::CryptoHash;
+use near_primitives::types::{AccountId, Balance};
+use near_primitives::views::AccountView;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use super::output::{CommandOutput, Status, Step, StepStatus, steps_table};
+use super::{
+    ContractCall, add_relayer_key_call, eth_connector_contract_data_call, factory_update_call,
+    init_call, register_relayer_call, set_key_manager_call,
+};
+use crate::client::{Context, NearClient};
+use crate::error::Error;
+use crate::utils::wasm::{self, Contract};
+use crate::utils::{self, near_to_yocto};
+
+const ENGINE_KEY_FILE: &str = "aurora_key.json";
+const KEY_MANAGER_KEY_FILE: &str = "manager_key.json";
+const RELAYER_KEY_FILE: &str = "relayer_key.json";
+const PROFILE_FILE: &str = "profile.env";
+const STATE_FILE: &str = "bootstrap.json";
+
+/// Configuration of the bootstrap, read from a TOML file. Every field has a default value.
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Aurora EVM account, a sub-account of the signer.
+    pub engine: AccountId,
+    /// Initial balance of the engine account in NEAR.
+    pub engine_balance: f64,
+    pub chain_id: u64,
+    /// Owner of the engine, the engine account itself if omitted.
+    pub owner: Option<AccountId>,
+    pub upgrade_delay_blocks: u64,
+    /// Relayer key manager, a sub-account of the engine or of the signer.
+    pub key_manager: AccountId,
+    /// Initial balance of the key manager account in NEAR.
+    pub key_manager_balance: f64,
+    /// Allowance of the relayer key in NEAR.
+    pub relayer_allowance: f64,
+    /// EVM address registered as the relayer of the engine account.
+    pub relayer_address: Option<String>,
+    /// Data of the internal ETH connector, which isn't set if omitted.
+    pub eth_connector: Option<EthConnector>,
+    /// Directory the keys and the profile are written to.
+    pub out_dir: PathBuf,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            engine: "aurora.node0".parse().unwrap(),
+            engine_balance: 100.0,
+            chain_id: 1_313_161_556,
+            owner: None,
+            upgrade_delay_blocks: 1,
+            key_manager: "key-manager.aurora.node0".parse().unwrap(),
+            key_manager_balance: 10.0,
+            relayer_allowance: 0.5,
+            relayer_address: None,
+            eth_connector: None,
+            out_dir: PathBuf::from("localnet"),
+        }
+    }
+}
+
+impl Config {
+    /// Read the configuration from the file, or use the default one if there is no file.
+    pub fn read<P: AsRef<Path>>(path: Option<P>) -> anyhow::Result<Self> {
+        let Some(path) = path else {
+            return Ok(Self::default());
+        };
+        let path = path.as_ref();
+
+        std::fs::read_to_string(path)
+            .map_err(|e| Error::Usage(format!("Couldn't read {}: {e}", path.display())))
+            .and_then(|content| {
+                toml::from_str(&content)
+                    .map_err(|e| Error::Usage(format!("Wrong config {}: {e}", path.display())))
+            })
+            .map_err(Into::into)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EthConnector {
+    pub prover_id: String,
+    pub custodian_address: String,
+    /// FT metadata in JSON, the default metadata is used if omitted.
+    pub ft_metadata_path: Option<PathBuf>,
+}
+
+/// Steps without a view method to check whether they are done, recorded next to the keys.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct State {
+    engine: Option<AccountId>,
+    xcc_router_hash: Option<CryptoHash>,
+    key_manager: Option<AccountId>,
+    relayer_address: Option<String>,
+}
+
+/// Perform every step of the bootstrap which hasn't been done yet, and write the keys and the
+/// profile into the output directory.
+///
+/// The engine account is created by the signer of the CLI, the engine is deployed, initialized
+/// and configured with the engine key, and the relayer key is added with the key manager key.
+pub async fn bootstrap<P: AsRef<Path> + Send>(
+    context: Context,
+    engine_wasm: P,
+    xcc_router_wasm: Option<P>,
+    config: Config,
+) -> anyhow::Result<()> {
+    let engine_code = read_wasm(engine_wasm.as_ref(), Contract::Engine)?;
+    let xcc_router_code = xcc_router_wasm
+        .map(|path| read_wasm(path.as_ref(), Contract::XccRouter))
+        .transpose()?;
+    std::fs::create_dir_all(&config.out_dir)?;
+
+    let near = context.client.near_latest();
+    let mut bootstrap = Bootstrap {
+        engine_key: config.out_dir.join(ENGINE_KEY_FILE),
+        key_manager_key: config.out_dir.join(KEY_MANAGER_KEY_FILE),
+        relayer_key: config.out_dir.join(RELAYER_KEY_FILE),
+        state_path: config.out_dir.join(STATE_FILE),
+        state: State::default(),
+        steps: vec![],
+        context: &context,
+        near: &near,
+        config: &config,
+    };
+
+    let engine = bootstrap.deploy_engine(&engine_code).await?;
+    bootstrap
+        .configure_engine(&engine, xcc_router_code.as_deref())
+        .await?;
+    bootstrap.add_relayer(&engine).await?;
+
+    let profile = config.out_dir.join(PROFILE_FILE);
+    write_profile(
+        &profile,
+        &near,
+        &config,
+        &bootstrap.key_manager_key,
+        &bootstrap.relayer_key,
+    )?;
+
+    CommandOutput::value(json!({
+        "steps": bootstrap.steps,
+        "engine_key": bootstrap.engine_key,
+        "key_manager_key": bootstrap.key_manager_key,
+        "relayer_key": bootstrap.relayer_key,
+        "profile": profile,
+    }))?
+    .with_message(table(&bootstrap.steps, &profile))
+    .print(&context.output_format)
+}
+
+/// Steps performed so far with the keys and the recorded state in the output directory.
+struct Bootstrap<'a> {
+    engine_key: PathBuf,
+    key_manager_key: PathBuf,
+    relayer_key: PathBuf,
+    state_path: PathBuf,
+    state: State,
+    steps: Vec<Step>,
+    context: &'a Context,
+    near: &'a NearClient,
+    config: &'a Config,
+}
+
+impl Bootstrap<'_> {
+    /// Create the engine account if it doesn't exist and deploy the code if there is none, and
+    /// return the client signing with the engine key.
+    async fn deploy_engine(&mut self, code: &[u8]) -> anyhow::Result<Client> {
+        let config = self.config;
+        let view = view_account(self.near, &config.engine).await?;
+
+        if view.is_some() {
+            self.skip("create-engine-account");
+        } else {
+            let root = self.near.sdk_client()?;
+            let output = create_account(
+                &root,
+                &config.engine,
+                config.engine_balance,
+                &self.engine_key,
+            )
+            .await?;
+            self.done("create-engine-account", output)?;
+        }
+
+        let engine = self
+            .near
+            .sdk_client()?
+            .with_signer(read_key(&self.engine_key, &config.engine)?);
+        let is_deployed = view.is_some_and(|view| view.code_hash != CryptoHash::default());
+
+        if is_deployed {
+            self.skip("deploy-engine");
+            // The recorded steps are trusted only if the engine has been there before, so they
+            // are forgotten after the restart of the node with a new genesis.
+            self.state = read_state(&self.state_path)?
+                .filter(|state| state.engine.as_ref() == Some(&config.engine))
+                .unwrap_or_default();
+        } else {
+            let outcome = engine.batch(&config.engine).deploy(code).transact().await?;
+            let output = CommandOutput::transaction(&outcome, "Error while deploying Aurora EVM");
+            self.done("deploy-engine", output)?;
+        }
+
+        self.state.engine = Some(config.engine.clone());
+        write_state(&self.state_path, &self.state)?;
+
+        Ok(engine)
+    }
+
+    /// Initialize the engine, set the ETH connector data and update the XCC router.
+    async fn configure_engine(
+        &mut self,
+        engine: &Client,
+        xcc_router_code: Option<&[u8]>,
+    ) -> anyhow::Result<()> {
+        let config = self.config;
+
+        if engine.view(&config.engine, "get_owner").await.is_ok() {
+            self.skip("init-engine");
+        } else {
+            let owner = config.owner.as_ref().unwrap_or(&config.engine);
+            let call = init_call(
+                config.chain_id,
+                engine_account_id(owner)?,
+                config.upgrade_delay_blocks,
+            )?;
+            let output = transact(engine, &config.engine, call, 0.0).await?;
+            self.done("init-engine", output)?;
+        }
+
+        if let Some(connector) = &config.eth_connector {
+            if engine
+                .view(&config.engine, "get_bridge_prover")
+                .await
+                .is_ok()
+            {
+                self.skip("set-eth-connector-contract-data");
+            } else {
+                let call = eth_connector_contract_data_call(
+                    &connector.prover_id,
+                    &connector.custodian_address,
+                    connector.ft_metadata_path.as_ref(),
+                )?;
+                let output = transact(engine, &config.engine, call, 0.0).await?;
+                self.done("set-eth-connector-contract-data", output)?;
+            }
+        }
+
+        if let Some(code) = xcc_router_code {
+            let hash = CryptoHash::hash_bytes(code);
+
+            if self.state.xcc_router_hash == Some(hash) {
+                self.skip("factory-update");
+            } else {
+                let call = (factory_update_call(), code.to_vec());
+                let output = transact(engine, &config.engine, call, 0.0).await?;
+                self.done("factory-update", output)?;
+                self.state.xcc_router_hash = Some(hash);
+                write_state(&self.state_path, &self.state)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Create the key manager, add the relayer key with it and register the relayer address.
+    async fn add_relayer(&mut self, engine: &Client) -> anyhow::Result<()> {
+        let config = self.config;
+
+        if view_account(self.near, &config.key_manager)
+            .await?
+            .is_some()
+        {
+            self.skip("create-key-manager-account");
+        } else {
+            let parent = if config.key_manager.is_sub_account_of(&config.engine) {
+                engine.clone()
+            } else {
+                self.near.sdk_client()?
+            };
+            let output = create_account(
+                &parent,
+                &config.key_manager,
+                config.key_manager_balance,
+                &self.key_manager_key,
+            )
+            .await?;
+            self.done("create-key-manager-account", output)?;
+        }
+
+        if self.state.key_manager.as_ref() == Some(&config.key_manager) {
+            self.skip("set-key-manager");
+        } else {
+            let call = set_key_manager_call(Some(engine_account_id(&config.key_manager)?))?;
+            let output = transact(engine, &config.engine, call, 0.0).await?;
+            self.done("set-key-manager", output)?;
+            self.state.key_manager = Some(config.key_manager.clone());
+            write_state(&self.state_path, &self.state)?;
+        }
+
+        let relayer = relayer_key_file(&self.relayer_key, &config.engine)?;
+
+        if self
+            .near
+            .view_access_keys(&config.engine)
+            .await?
+            .iter()
+            .any(|key| key.public_key == relayer.public_key)
+        {
+            self.skip("add-relayer-key");
+        } else {
+            let key_manager =
+                engine.with_signer(read_key(&self.key_manager_key, &config.key_manager)?);
+            let public_key =
+                relayer.public_key.to_string().parse().map_err(|e| {
+                    anyhow::anyhow!("Wrong public key {}: {e:?}", relayer.public_key)
+                })?;
+            let call = add_relayer_key_call(public_key)?;
+            let output =
+                transact(&key_manager, &config.engine, call, config.relayer_allowance).await?;
+            self.done("add-relayer-key", output)?;
+        }
+
+        if let Some(address) = &config.relayer_address {
+            if self.state.relayer_address.as_ref() == Some(address) {
+                self.skip("register-relayer");
+            } else {
+                let call = register_relayer_call(address)?;
+                let output = transact(engine, &config.engine, call, 0.0).await?;
+                self.done("register-relayer", output)?;
+                self.state.relayer_address = Some(address.clone());
+                write_state(&self.state_path, &self.state)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn skip(&mut self, name: &'static str) {
+        self.steps.push(Step {
+            name,
+            status: StepStatus::Skipped,
+            tx_hash: None,
+        });
+    }
+
+    /// Record the step, or print the output of the failed transaction and return it as the
+    /// error, which stops the bootstrap.
+    fn done(&mut self, name: &'static str, output: CommandOutput) -> anyhow::Result<()> {
+        if output.status == Status::Failure {
+            return output.print(&self.context.output_format);
+        }
+
+        self.steps.push(Step {
+            name,
+            status: StepStatus::Done,
+            tx_hash: output.tx_hash,
+        });
+
+        Ok(())
+    }
+}
+
+fn read_wasm(path: &Path, contract: Contract) -> anyhow::Result<Vec<u8>> {
+    let code = std::fs::read(path)
+        .map_err(|e| Error::Usage(format!("Couldn't read {}: {e}", path.display())))?;
+    wasm::check(&code, contract)?;
+
+    Ok(code)
+}
+
+/// View the account, or return `None` if it doesn't exist.
+async fn view_account(
+    near: &NearClient,
+    account_id: &AccountId,
+) -> anyhow::Result<Option<AccountView>> {
+    match near.sdk_client()?.view_account(account_id).await {
+        Ok(view) => Ok(Some(view)),
+        Err(NearError::RpcQueryError(JsonRpcError::ServerError(
+            JsonRpcServerError::HandlerError(RpcQueryError::UnknownAccount { .. }),
+        ))) => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Create the sub-account of the signer of the client with a new key written into the key file.
+async fn create_account(
+    parent: &Client,
+    account_id: &AccountId,
+    balance: f64,
+    key_file: &Path,
+) -> anyhow::Result<CommandOutput> {
+    let parent_id = parent.signer().get_account_id();
+
+    if !account_id.is_sub_account_of(&parent_id) {
+        return Err(Error::Usage(format!(
+            "{account_id} must be a sub-account of {parent_id} to be created"
+        ))
+        .into());
+    }
+
+    let secret_key = SecretKey::from_random(KeyType::ED25519);
+    let outcome = parent
+        .batch(account_id)
+        .create_account()
+        .transfer(Balance::from_yoctonear(near_to_yocto(balance)))
+        .add_key(secret_key.public_key(), AccessKey::full_access())
+        .transact()
+        .await?;
+    let output = CommandOutput::transaction(&outcome, "Error while creating account");
+
+    if output.status == Status::Success {
+        KeyFile {
+            account_id: account_id.clone(),
+            public_key: secret_key.public_key(),
+            secret_key,
+        }
+        .write_to_file(key_file)?;
+    }
+
+    Ok(output)
+}
+
+fn engine_account_id(
+    account_id: &AccountId,
+) -> anyhow::Result<aurora_engine_types::account_id::AccountId> {
+    account_id
+        .as_str()
+        .parse()
+        .map_err(|e| anyhow::anyhow!("{e}"))
+}
+
+fn read_key(key_file: &Path, account_id: &AccountId) -> anyhow::Result<near_crypto::Signer> {
+    let signer = utils::read_key_file(key_file).map_err(|e| {
+        Error::Usage(format!(
+            "{account_id} exists, but its key couldn't be read from {}: {e}",
+            key_file.display()
+        ))
+    })?;
+
+    Ok(signer.into())
+}
+
+/// Read the relayer key of the engine, or generate and write a new one.
+fn relayer_key_file(path: &Path, engine: &AccountId) -> anyhow::Result<KeyFile> {
+    if path.exists() {
+        let signer = utils::read_key_file(path)?;
+
+        return Ok(KeyFile {
+            account_id: signer.account_id,
+            public_key: signer.public_key,
+            secret_key: signer.secret_key,
+        });
+    }
+
+    let secret_key = SecretKey::from_random(KeyType::ED25519);
+    let key = KeyFile {
+        account_id: engine.clone(),
+        public_key: secret_key.public_key(),
+        secret_key,
+    };
+    key.write_to_file(path)?;
+
+    Ok(key)
+}
+
+async fn transact(
+    client: &Client,
+    engine: &AccountId,
+    (call, args): (ContractCall, Vec<u8>),
+    deposit: f64,
+) -> anyhow::Result<CommandOutput> {
+    let outcome = client
+        .call(engine, call.method)
+        .args(args)
+        .deposit(Balance::from_yoctonear(near_to_yocto(deposit)))
+        .max_gas()
+        .transact()
+        .await?;
+
+    Ok(CommandOutput::transaction(&outcome, &call.error_message))
+}
+
+fn read_state(path: &Path) -> anyhow::Result<Option<State>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content = std::fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&content).ok())
+}
+
+fn write_state(path: &Path, state: &State) -> anyhow::Result<()> {
+    std::fs::write(path, serde_json::to_string_pretty(state)?).map_err(Into::into)
+}
+
+/// Shell profile exporting the RPC endpoint, the engine and the key paths used by the CLI.
+fn write_profile(
+    path: &Path,
+    near: &NearClient,
+    config: &Config,
+    key_manager_key: &Path,
+    relayer_key: &Path,
+) -> anyhow::Result<()> {
+    let out_dir = std::path::absolute(&config.out_dir)?;
+    let lines = [
+        "# Generated by `aurora-cli localnet bootstrap`, use it with `source`.".to_string(),
+        format!("export NEAR_RPC_URL={}", near.server_addr()),
+        format!(
+            "export NEAR_KEY_PATH={}",
+            out_dir.join(ENGINE_KEY_FILE).display()
+        ),
+        format!("export AURORA_ENGINE={}", config.engine),
+        format!(
+            "export AURORA_KEY_MANAGER_KEY_PATH={}",
+            std::path::absolute(key_manager_key)?.display()
+        ),
+        format!(
+            "export AURORA_RELAYER_KEY_PATH={}",
+            std::path::absolute(relayer_key)?.display()
+        ),
+    ];
+
+    std::fs::write(path, lines.join("\n") + "\n").map_err(Into::into)
+}
+
+fn table(steps: &[Step], profile: &Path) -> String {
+    format!(
+        "{}\n\nThe profile is written to {}",
+        steps_table(steps),
+        profile.display()
+    )
+}
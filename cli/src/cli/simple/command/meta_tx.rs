@@ -0,0 +1,52 @@
+use aurora_sdk_rs::near::delegate;
+use near_primitives::gas::Gas;
+
+use super::output::CommandOutput;
+use super::parse_args;
+use crate::client::Context;
+use crate::utils::near_to_yocto;
+
+/// Sign the call of the engine's method by the key from `--near-key-path` as a delegate action
+/// and print it in base64, so a relayer could submit it.
+pub async fn create(
+    context: Context,
+    method: &str,
+    args: Option<String>,
+    gas: u64,
+    deposit: f64,
+    ttl: u64,
+) -> anyhow::Result<()> {
+    let args = args
+        .as_deref()
+        .map(parse_args)
+        .transpose()?
+        .unwrap_or_default();
+    let signed = context
+        .client
+        .near()
+        .create_delegate_action(
+            method,
+            args,
+            Gas::from_teragas(gas),
+            near_to_yocto(deposit),
+            ttl,
+        )
+        .await?;
+
+    CommandOutput::value(delegate::encode(&signed))?.print(&context.output_format)
+}
+
+/// Submit the signed delegate action paying for the gas by the account from `--near-key-path`.
+pub async fn relay(context: Context, delegate_action: &str) -> anyhow::Result<()> {
+    let signed = delegate::decode(delegate_action)?;
+    let sender_id = signed.delegate_action.sender_id.clone();
+    let outcome = context.client.near().relay_delegate_action(signed).await?;
+    let message = format!(
+        "The delegate action of {sender_id} has been relayed successfully. (hash: {})",
+        outcome.transaction.hash
+    );
+
+    CommandOutput::transaction(&outcome, "Error while relaying the delegate action")
+        .with_message(message)
+        .print(&context.output_format)
+}
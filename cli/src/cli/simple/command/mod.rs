@@ -11,33 +11,55 @@ use aurora_engine_types::parameters::connector::{
 use aurora_engine_types::parameters::engine::{
     CallArgs, DeployErc20TokenArgs, FunctionCallArgsV2, GetStorageAtArgs, NewCallArgs,
     NewCallArgsV2, PausePrecompilesCallArgs, RelayerKeyArgs, RelayerKeyManagerArgs, SetOwnerArgs,
-    SetUpgradeDelayBlocksArgs, SubmitResult, TransactionStatus,
+    SetUpgradeDelayBlocksArgs, TransactionStatus,
 };
 use aurora_engine_types::parameters::xcc::FundXccArgs;
 use aurora_engine_types::public_key::{KeyType, PublicKey};
 use aurora_engine_types::types::Address;
 use aurora_engine_types::{H256, U256, types::Wei};
+use aurora_sdk_rs::aurora::signer::EvmSigner;
 use clap::ValueEnum;
 use near_primitives::hash::CryptoHash;
 use near_primitives::views::{CallResult, FinalExecutionStatus, TxExecutionStatus};
-use serde_json::{Value, to_string_pretty};
+use serde_json::Value;
 
+use crate::cli::parse_address;
 use crate::cli::simple::OutputFormat;
 use crate::cli::simple::WithdrawSerialization;
 use crate::{
-    client::Context,
-    utils::{self, hex_to_address, hex_to_arr, hex_to_vec, near_to_yocto, secret_key_from_hex},
+    client::{Context, NearClient},
+    error::Error,
+    utils::pausable::{PausedFlags, PrecompileMask},
+    utils::wasm::{self, Contract, WasmInfo},
+    utils::{self, hex_to_arr, hex_to_vec, near_to_yocto},
 };
-
+use output::CommandOutput;
+
+pub mod account;
+pub mod address;
+pub mod emergency;
+pub mod engines;
+pub mod global_contract;
+pub mod history;
+pub mod localnet;
+pub mod meta_tx;
+pub mod output;
+pub mod relayer_keys;
+pub mod sign;
 pub mod silo;
+pub mod upgrade;
+
+/// Address of the canonical deterministic-deployment proxy, which deploys the code from the input
+/// prefixed by a 32-byte salt with `CREATE2`.
+pub const DETERMINISTIC_DEPLOYMENT_PROXY: &str = "0x4e59b44847b379578588920cA78FbF26c0B4956C";
 
 #[macro_export]
 macro_rules! contract_call {
     ($method:expr, $success_msg:expr, $error_msg:expr) => {
         ContractCall {
             method: $method,
-            success_message: &format!($success_msg),
-            error_message: &format!($error_msg),
+            success_message: format!($success_msg),
+            error_message: format!($error_msg),
         }
     };
 }
@@ -104,19 +126,12 @@ pub async fn get_eth_connector_contract_account(context: Context) -> anyhow::Res
 /// Deploy Aurora EVM smart contract.
 pub async fn deploy_aurora<P: AsRef<Path> + Send>(context: Context, path: P) -> anyhow::Result<()> {
     let code = std::fs::read(path)?;
-    let result = match context.client.near().deploy_contract(code).await {
-        Ok(outcome) => match outcome.status {
-            FinalExecutionStatus::SuccessValue(_) => {
-                "Aurora EVM has been deployed successfully".to_string()
-            }
-            FinalExecutionStatus::Failure(e) => format!("Error while deployed Aurora EVM: {e}"),
-            _ => "Error: Bad transaction status".to_string(),
-        },
-        Err(e) => format!("{e:?}"),
-    };
-    println!("{result}");
+    wasm::check(&code, Contract::Engine)?;
+    let outcome = context.client.near().deploy_contract(code).await?;
 
-    Ok(())
+    CommandOutput::transaction(&outcome, "Error while deploying Aurora EVM")
+        .with_message("Aurora EVM has been deployed successfully")
+        .print(&context.output_format)
 }
 
 /// Initialize Aurora EVM smart contract.
@@ -127,19 +142,27 @@ pub async fn init(
     upgrade_delay_blocks: Option<u64>,
 ) -> anyhow::Result<()> {
     let owner_id = to_account_id(owner_id, &context)?;
-    let aurora_init_args = borsh::to_vec(&NewCallArgs::V2(NewCallArgsV2 {
+    let (call, args) = init_call(chain_id, owner_id, upgrade_delay_blocks.unwrap_or_default())?;
+    call.proceed(context, args).await
+}
+
+fn init_call(
+    chain_id: u64,
+    owner_id: AccountId,
+    upgrade_delay_blocks: u64,
+) -> anyhow::Result<(ContractCall, Vec<u8>)> {
+    let args = borsh::to_vec(&NewCallArgs::V2(NewCallArgsV2 {
         chain_id: H256::from_low_u64_be(chain_id).into(),
         owner_id,
-        upgrade_delay_blocks: upgrade_delay_blocks.unwrap_or_default(),
+        upgrade_delay_blocks,
     }))?;
-
-    contract_call!(
+    let call = contract_call!(
         "new",
         "Aurora EVM has been initialized successfully",
         "Error while initializing Aurora EVM"
-    )
-    .proceed(context, aurora_init_args)
-    .await
+    );
+
+    Ok((call, args))
 }
 
 /// Deploy EVM byte code.
@@ -148,11 +171,10 @@ pub async fn deploy_evm_code(
     code: String,
     abi_path: Option<String>,
     args: Option<String>,
-    sk: Option<&str>,
+    create2_salt: Option<String>,
+    factory: Option<String>,
+    signer: &dyn EvmSigner,
 ) -> anyhow::Result<()> {
-    let sk = sk
-        .ok_or_else(|| anyhow::anyhow!("Deploy EVM code requires Aurora secret key"))
-        .and_then(secret_key_from_hex)?;
     let input =
         if let Some((abi_path, args)) = abi_path.and_then(|path| args.map(|args| (path, args))) {
             let contract = utils::abi::read_contract(abi_path)?;
@@ -167,64 +189,91 @@ pub async fn deploy_evm_code(
             hex::decode(code)?
         };
 
-    let result = context
-        .client
-        .near()
-        .send_aurora_transaction(&sk, None, Wei::zero(), input)
-        .await?;
-    let output = match result.status {
-        FinalExecutionStatus::NotStarted | FinalExecutionStatus::Started => {
-            anyhow::bail!("Error while deploying EVM code: Bad status of the transaction")
-        }
-        FinalExecutionStatus::Failure(e) => {
-            anyhow::bail!("Error while deploying EVM code: {e}")
-        }
-        FinalExecutionStatus::SuccessValue(ref bytes) => {
-            let result = SubmitResult::try_from_slice(bytes)?;
-            if let TransactionStatus::Succeed(bytes) = result.status {
-                format!(
-                    "Contract has been deployed to address: 0x{} successfully, gas used: {}",
-                    hex::encode(bytes),
-                    result.gas_used,
-                )
-            } else {
-                format!("Transaction reverted: {result:?}")
-            }
+    let near = context.client.near_latest();
+    let mut messages = vec![];
+    let (factory, input) = if let Some(salt) = create2_salt {
+        let factory = parse_address(factory.as_deref().unwrap_or(DETERMINISTIC_DEPLOYMENT_PROXY))?;
+        let salt = hex_to_arr(&salt).map(H256)?;
+        let address = utils::create2_address(factory, salt, aurora_engine_sdk::keccak(&input));
+        let checksum = utils::to_checksum_address(&address);
+        messages.push(format!("Predicted address of the contract: {checksum}"));
+
+        if !get_evm_code(&near, address).await?.is_empty() {
+            messages.push(
+                "Contract already exists at the predicted address, skipping deployment".into(),
+            );
+            return CommandOutput::default()
+                .with_result(checksum.into())
+                .with_message(messages.join("\n"))
+                .print(&context.output_format);
         }
+
+        anyhow::ensure!(
+            !get_evm_code(&near, factory).await?.is_empty(),
+            "There is no factory contract at address: {}",
+            utils::to_checksum_address(&factory)
+        );
+
+        (Some(factory), [salt.as_bytes(), &input].concat())
+    } else {
+        (None, input)
     };
 
-    println!("{output}");
+    let outcome = near
+        .send_aurora_transaction(signer, factory, Wei::zero(), input)
+        .await?;
+    let mut output = CommandOutput::transaction(&outcome, "Error while deploying EVM code");
+
+    if let Some(address) = output.decode_submit_result(&outcome)? {
+        messages.push(format!(
+            "Contract has been deployed to address: 0x{} successfully, gas used: {}",
+            hex::encode(address),
+            output.evm_gas_used.unwrap_or_default(),
+        ));
+    }
 
-    Ok(())
+    output
+        .with_message(messages.join("\n"))
+        .print(&context.output_format)
 }
 
-/// Creates new NEAR account.
+/// Creates new NEAR account. The direct sub-account is signed by the key of its parent if the
+/// key file of the parent is passed.
 pub async fn create_account(
     context: Context,
     account: &str,
     initial_balance: f64,
+    parent_key: Option<&Path>,
 ) -> anyhow::Result<()> {
-    match context
+    let (outcome, secret_key) = context
         .client
         .near()
-        .create_account(account, initial_balance)
-        .await
-    {
-        Ok(result) => println!("{result}"),
-        Err(e) => eprintln!("{e:?}"),
+        .create_account(account, initial_balance, parent_key)
+        .await?;
+    let output = CommandOutput::transaction(&outcome, "Error while creating account");
+
+    // The `create_account` method of the `near` and `testnet` accounts returns `false` on failure.
+    if output.result == Some(Value::Bool(false)) {
+        return output
+            .failure(Error::NearTransaction(
+                "Error while creating account".to_string(),
+            ))
+            .print(&context.output_format);
     }
 
-    Ok(())
+    output
+        .with_result(serde_json::json!({
+            "account_id": account,
+            "public_key": secret_key.public_key().to_string(),
+            "private_key": secret_key.to_string(),
+        }))
+        .print(&context.output_format)
 }
 
 /// View new NEAR account.
 pub async fn view_account(context: Context, account: &str) -> anyhow::Result<()> {
-    match context.client.near().view_account(account).await {
-        Ok(result) => println!("{result}"),
-        Err(e) => eprintln!("{e:?}"),
-    }
-
-    Ok(())
+    let view = context.client.near().view_account(account).await?;
+    CommandOutput::value(view)?.print(&context.output_format)
 }
 
 pub async fn call(
@@ -234,7 +283,7 @@ pub async fn call(
     value: Option<u128>,
     from: Option<AccountId>,
 ) -> anyhow::Result<()> {
-    let contract = hex_to_address(&address)?;
+    let contract = parse_address(&address)?;
     let input = input.map_or(Ok(vec![]), hex::decode)?;
     let args = borsh::to_vec(&CallArgs::V2(FunctionCallArgsV2 {
         contract,
@@ -243,36 +292,25 @@ pub async fn call(
     }))
     .unwrap_or_default();
 
-    let result = if let Some(account_id) = from {
+    let outcome = if let Some(account_id) = from {
         context
             .client
-            .near()
+            .near_latest()
             .contract_call_from("call", args, account_id.to_string().parse()?)
             .await?
     } else {
-        context.client.near().contract_call("call", args).await?
+        context
+            .client
+            .near_latest()
+            .contract_call("call", args)
+            .await?
     };
+    let mut output = CommandOutput::transaction(&outcome, "Error while calling EVM contract");
+    output.decode_submit_result(&outcome)?;
 
-    match result.status {
-        FinalExecutionStatus::NotStarted | FinalExecutionStatus::Started => {}
-        FinalExecutionStatus::Failure(failure) => println!("Bad execution status: {failure:?}"),
-        FinalExecutionStatus::SuccessValue(result) => {
-            let submit_result = SubmitResult::try_from_slice(&result)?;
-            match submit_result.status {
-                TransactionStatus::Succeed(_) => {
-                    println!("The call has been executed successfully");
-                }
-                TransactionStatus::Revert(_) => {
-                    println!("The call has been reverted");
-                }
-                _ => {
-                    println!("The call has been failed");
-                }
-            }
-        }
-    }
-
-    Ok(())
+    output
+        .with_message("The call has been executed successfully")
+        .print(&context.output_format)
 }
 
 /// Read-only call of the EVM smart contract.
@@ -284,32 +322,34 @@ pub async fn view_call(
     from: String,
     abi_path: String,
 ) -> anyhow::Result<()> {
-    let target = hex_to_address(&address)?;
+    let target = parse_address(&address)?;
     let contract = utils::abi::read_contract(abi_path)?;
     let func = contract.function(&function)?;
     let args: Value = args.map_or(Ok(Value::Null), |args| serde_json::from_str(&args))?;
     let tokens = utils::abi::parse_args(&func.inputs, &args)?;
     let input = func.encode_input(&tokens)?;
-    let from = hex_to_address(&from)?;
+    let from = parse_address(&from)?;
     let result = context
         .client
         .near()
-        .view_contract_call(from, target, Wei::zero(), input, context.block_height)
+        .view_contract_call(from, target, Wei::zero(), input)
         .await?;
 
-    if let TransactionStatus::Succeed(bytes) = result {
-        let parsed_output = func.decode_output(&bytes)?;
-        let result = parsed_output
+    let output = if let TransactionStatus::Succeed(bytes) = result {
+        let tokens = func
+            .decode_output(&bytes)?
             .iter()
             .map(ethabi::Token::to_string)
-            .collect::<Vec<_>>()
-            .join(", ");
-        println!("{result}");
+            .collect::<Vec<_>>();
+        CommandOutput::value(&tokens)?.with_message(tokens.join(", "))
     } else {
-        println!("{result:?}");
-    }
+        CommandOutput::default().failure(Error::EvmRevert(format!(
+            "Aurora transaction status: {}",
+            output::evm_status(&result)
+        )))
+    };
 
-    Ok(())
+    output.print(&context.output_format)
 }
 
 /// Modifying call of the EVM smart contract.
@@ -320,12 +360,9 @@ pub async fn submit(
     args: Option<String>,
     abi_path: String,
     value: Option<String>,
-    sk: Option<&str>,
+    signer: &dyn EvmSigner,
 ) -> anyhow::Result<()> {
-    let sk = sk
-        .ok_or_else(|| anyhow::anyhow!("Call contract requires Aurora secret key"))
-        .and_then(secret_key_from_hex)?;
-    let target = hex_to_address(&address)?;
+    let target = parse_address(&address)?;
     let contract = utils::abi::read_contract(abi_path)?;
     let func = contract.function(&function)?;
     let args: Value = args.map_or(Ok(Value::Null), |args| serde_json::from_str(&args))?;
@@ -335,57 +372,33 @@ pub async fn submit(
         .and_then(|a| U256::from_dec_str(&a).ok())
         .map_or_else(Wei::zero, Wei::new);
 
-    let result = context
+    let outcome = context
         .client
-        .near()
-        .send_aurora_transaction(&sk, Some(target), amount, input)
+        .near_latest()
+        .send_aurora_transaction(signer, Some(target), amount, input)
         .await?;
-    let (gas, status) = match result.status {
-        FinalExecutionStatus::NotStarted | FinalExecutionStatus::Started => {
-            anyhow::bail!("Error while calling EVM transaction: Bad status of the transaction")
-        }
-        FinalExecutionStatus::Failure(e) => {
-            anyhow::bail!("Error while calling EVM transaction: {e}")
-        }
-        FinalExecutionStatus::SuccessValue(bytes) => {
-            let result = SubmitResult::try_from_slice(&bytes)?;
-            let status = match result.status {
-                TransactionStatus::Succeed(_) => "successful",
-                TransactionStatus::Revert(_) => "reverted",
-                TransactionStatus::OutOfGas => "out_of_gas",
-                TransactionStatus::OutOfFund => "out_of_fund",
-                TransactionStatus::OutOfOffset => "out_of_offset",
-                TransactionStatus::CallTooDeep => "call_too_deep",
-                TransactionStatus::StackUnderflow => "stack_underflow",
-                TransactionStatus::StackOverflow => "stack_overflow",
-                TransactionStatus::InvalidJump => "invalid_jump",
-                TransactionStatus::InvalidRange => "invalid_range",
-                TransactionStatus::DesignatedInvalid => "designated_invalid",
-                TransactionStatus::CreateCollision => "create_collision",
-                TransactionStatus::CreateContractLimit => "create_contract_limit",
-                TransactionStatus::InvalidCode(_) => "invalid_code",
-                TransactionStatus::PCUnderflow => "pc_underflow",
-                TransactionStatus::CreateEmpty => "create_empty",
-                TransactionStatus::MaxNonce => "max_nonce",
-                TransactionStatus::UsizeOverflow => "usize_overflow",
-                TransactionStatus::Other(_) => "other",
-                TransactionStatus::CreateContractStartingWithEF => {
-                    "create_contract_starting_with_ef"
-                }
-            };
-
-            (result.gas_used, status)
-        }
-    };
+    let mut output = CommandOutput::transaction(&outcome, "Error while calling EVM transaction");
 
-    println!("Aurora transaction status: {status}, gas used: {gas}");
+    if let Some(bytes) = output.decode_submit_result(&outcome)? {
+        let tokens = func
+            .decode_output(&bytes)?
+            .iter()
+            .map(ethabi::Token::to_string)
+            .collect::<Vec<_>>();
+        let message = format!(
+            "Aurora transaction status: successful, gas used: {}",
+            output.evm_gas_used.unwrap_or_default()
+        );
+        output = output.with_result(tokens.into()).with_message(message);
+    }
 
-    Ok(())
+    output.print(&context.output_format)
 }
 
 /// Upgrade Aurora Contract with provided code.
 pub async fn upgrade<P: AsRef<Path> + Send>(context: Context, path: P) -> anyhow::Result<()> {
     let code = std::fs::read(path)?;
+    wasm::check(&code, Contract::Engine)?;
 
     contract_call!(
         "upgrade",
@@ -399,38 +412,45 @@ pub async fn upgrade<P: AsRef<Path> + Send>(context: Context, path: P) -> anyhow
 /// Stage code for delayed upgrade.
 pub async fn stage_upgrade<P: AsRef<Path> + Send>(context: Context, path: P) -> anyhow::Result<()> {
     let code = std::fs::read(path)?;
+    wasm::check(&code, Contract::Engine)?;
 
+    stage_upgrade_call().proceed(context, code).await
+}
+
+fn stage_upgrade_call() -> ContractCall {
     contract_call!(
         "stage_upgrade",
         "The code has been saved for staged upgrade successfully",
         "Error while staging code for upgrade"
     )
-    .proceed(context, code)
-    .await
 }
 
 /// Deploy staged upgrade.
 pub async fn deploy_upgrade(context: Context) -> anyhow::Result<()> {
+    deploy_upgrade_call().proceed(context, vec![]).await
+}
+
+fn deploy_upgrade_call() -> ContractCall {
     contract_call!(
         "deploy_upgrade",
         "The upgrade has been applied successfully",
         "Error while deploying upgrade"
     )
-    .proceed(context, vec![])
-    .await
 }
 
 /// Updates the bytecode for user's router contracts.
 pub async fn factory_update(context: Context, path: String) -> anyhow::Result<()> {
     let code = std::fs::read(path)?;
+    wasm::check(&code, Contract::XccRouter)?;
+    factory_update_call().proceed(context, code).await
+}
 
+fn factory_update_call() -> ContractCall {
     contract_call!(
         "factory_update",
         "The bytecode of user's router contract has been updated successfully",
         "Error while updating the bytecode of user's router contract"
     )
-    .proceed(context, code)
-    .await
 }
 
 /// Returns the address of the `wNEAR` ERC-20 contract
@@ -459,7 +479,7 @@ pub async fn fund_xcc_sub_account(
     deposit: f64,
 ) -> anyhow::Result<()> {
     let args = borsh::to_vec(&FundXccArgs {
-        target: hex_to_address(&target)?,
+        target: parse_address(&target)?,
         wnear_account_id: account_id
             .map(|id| id.parse().map_err(|e| anyhow::anyhow!("{e}")))
             .transpose()?,
@@ -491,15 +511,19 @@ pub async fn set_owner(context: Context, account_id: String) -> anyhow::Result<(
 
 /// Register relayer address.
 pub async fn register_relayer(context: Context, address: String) -> anyhow::Result<()> {
-    let args = hex_to_vec(&address)?;
+    let (call, args) = register_relayer_call(&address)?;
+    call.proceed(context, args).await
+}
 
-    contract_call!(
+fn register_relayer_call(address: &str) -> anyhow::Result<(ContractCall, Vec<u8>)> {
+    let args = hex_to_vec(address)?;
+    let call = contract_call!(
         "register_relayer",
         "The new relayer has been registered successfully",
         "Error while registering a new relayer"
-    )
-    .proceed(context, args)
-    .await
+    );
+
+    Ok((call, args))
 }
 
 /// Start hashchain
@@ -526,29 +550,33 @@ pub async fn start_hashchain(
 
 /// Pause contract
 pub async fn pause_contract(context: Context) -> anyhow::Result<()> {
+    pause_contract_call().proceed(context, vec![]).await
+}
+
+fn pause_contract_call() -> ContractCall {
     contract_call!(
         "pause_contract",
         "The contract has been paused successfully",
         "Error while pausing the contract"
     )
-    .proceed(context, vec![])
-    .await
 }
 
 /// Resume contract
 pub async fn resume_contract(context: Context) -> anyhow::Result<()> {
+    resume_contract_call().proceed(context, vec![]).await
+}
+
+fn resume_contract_call() -> ContractCall {
     contract_call!(
         "resume_contract",
         "The contract has been resumed successfully",
         "Error while resuming the contract"
     )
-    .proceed(context, vec![])
-    .await
 }
 
 /// Return value in storage for key at address.
 pub async fn get_storage_at(context: Context, address: String, key: String) -> anyhow::Result<()> {
-    let address = hex_to_address(&address)?;
+    let address = parse_address(&address)?;
     let key = H256::from_str(&key)?;
     let input = borsh::to_vec(&GetStorageAtArgs {
         address,
@@ -559,46 +587,87 @@ pub async fn get_storage_at(context: Context, address: String, key: String) -> a
 }
 
 /// Return EVM address from NEAR account.
-pub fn encode_address(account: &str) {
+pub fn encode_address(account: &str, format: &OutputFormat) -> anyhow::Result<()> {
     let result = near_account_to_evm_address(account.as_bytes()).encode();
-    println!("0x{result}");
+    CommandOutput::value(format!("0x{result}"))?.print(format)
 }
 
 /// Return an address and corresponding private key in JSON format.
-pub fn key_pair(random: bool, seed: Option<u64>) -> anyhow::Result<()> {
+pub fn key_pair(random: bool, seed: Option<u64>, format: &OutputFormat) -> anyhow::Result<()> {
     let (address, sk) = utils::gen_key_pair(random, seed)?;
-    println!(
-        "{}",
-        serde_json::to_string_pretty(&serde_json::json!({
-            "address": format!("0x{}", address.encode()),
-            "secret_key": hex::encode(sk.serialize()),
-        }))?
-    );
 
-    Ok(())
+    CommandOutput::value(serde_json::json!({
+        "address": format!("0x{}", address.encode()),
+        "secret_key": hex::encode(sk.serialize()),
+    }))?
+    .print(format)
+}
+
+/// Print the summary of the WASM file and whether it looks like the engine or the XCC router.
+pub fn inspect_wasm<P: AsRef<Path>>(path: P, format: &OutputFormat) -> anyhow::Result<()> {
+    let path = path.as_ref();
+    let code = std::fs::read(path)
+        .map_err(|e| Error::Usage(format!("Couldn't read {}: {e}", path.display())))?;
+    let info = WasmInfo::parse(&code)?;
+    let missing = info.missing_engine_exports();
+    let (contract, warnings) = if info.is_xcc_router() {
+        ("xcc-router", info.warnings(Contract::XccRouter))
+    } else {
+        ("engine", info.warnings(Contract::Engine))
+    };
+    let message = [
+        format!("Size:      {} bytes", info.size),
+        format!("SHA-256:   {}", info.sha256),
+        format!("Code hash: {}", info.code_hash),
+        format!(
+            "Version:   {}",
+            info.version.as_deref().unwrap_or("unknown")
+        ),
+        format!("Contract:  {contract}"),
+        format!("Exports:   {}", info.exports.join(", ")),
+    ]
+    .into_iter()
+    .chain(warnings.iter().map(|warning| format!("Warning: {warning}")))
+    .collect::<Vec<_>>()
+    .join("\n");
+
+    CommandOutput::value(serde_json::json!({
+        "size": info.size,
+        "sha256": info.sha256,
+        "code_hash": info.code_hash,
+        "version": info.version,
+        "contract": contract,
+        "exports": info.exports,
+        "missing_engine_exports": missing,
+        "warnings": warnings,
+    }))?
+    .with_message(message)
+    .print(format)
 }
 
 /// Return randomly generated content of the key file for `AccountId`.
-pub fn gen_near_key(account_id: &str, key_type: KeyType) -> anyhow::Result<()> {
+pub fn gen_near_key(
+    account_id: &str,
+    key_type: KeyType,
+    format: &OutputFormat,
+) -> anyhow::Result<()> {
     let near_key_type = near_crypto::KeyType::try_from(u8::from(key_type))?;
     let secret_key = near_crypto::SecretKey::from_random(near_key_type);
     let public_key = secret_key.public_key();
 
-    println!(
-        "{}",
-        serde_json::to_string_pretty(&serde_json::json!({
-            "account_id": account_id,
-            "public_key": public_key,
-            "secret_key": secret_key
-        }))?
-    );
-
-    Ok(())
+    CommandOutput::value(serde_json::json!({
+        "account_id": account_id,
+        "public_key": public_key,
+        "secret_key": secret_key
+    }))?
+    .print(format)
 }
 
 /// Pause precompiles with mask.
-pub async fn pause_precompiles(context: Context, mask: u32) -> anyhow::Result<()> {
-    let args = borsh::to_vec(&PausePrecompilesCallArgs { paused_mask: mask })?;
+pub async fn pause_precompiles(context: Context, mask: PrecompileMask) -> anyhow::Result<()> {
+    let args = borsh::to_vec(&PausePrecompilesCallArgs {
+        paused_mask: mask.bits(),
+    })?;
 
     contract_call!(
         "pause_precompiles",
@@ -610,8 +679,10 @@ pub async fn pause_precompiles(context: Context, mask: u32) -> anyhow::Result<()
 }
 
 /// Resume precompiles with mask.
-pub async fn resume_precompiles(context: Context, mask: u32) -> anyhow::Result<()> {
-    let args = borsh::to_vec(&PausePrecompilesCallArgs { paused_mask: mask })?;
+pub async fn resume_precompiles(context: Context, mask: PrecompileMask) -> anyhow::Result<()> {
+    let args = borsh::to_vec(&PausePrecompilesCallArgs {
+        paused_mask: mask.bits(),
+    })?;
 
     contract_call!(
         "resume_precompiles",
@@ -624,12 +695,14 @@ pub async fn resume_precompiles(context: Context, mask: u32) -> anyhow::Result<(
 
 /// Return paused precompiles.
 pub async fn get_paused_precompiles(context: Context) -> anyhow::Result<()> {
-    get_value::<u32>(context, "get_paused_precompiles", None).await
+    get_value::<PrecompileMask>(context, "get_paused_precompiles", None).await
 }
 
 /// Set the paused mask for ETH connector.
-pub async fn set_paused_flags(context: Context, paused_mask: PausedMask) -> anyhow::Result<()> {
-    let args = borsh::to_vec(&PauseEthConnectorArgs { paused_mask })?;
+pub async fn set_paused_flags(context: Context, flags: PausedFlags) -> anyhow::Result<()> {
+    let args = borsh::to_vec(&PauseEthConnectorArgs {
+        paused_mask: PausedMask::try_from(flags.bits())?,
+    })?;
 
     contract_call!(
         "set_paused_flags",
@@ -642,7 +715,7 @@ pub async fn set_paused_flags(context: Context, paused_mask: PausedMask) -> anyh
 
 /// Return the paused mask for ETH connector.
 pub async fn get_paused_flags(context: Context) -> anyhow::Result<()> {
-    get_value::<PausedMask>(context, "get_paused_flags", None).await
+    get_value::<PausedFlags>(context, "get_paused_flags", None).await
 }
 
 /// Set relayer key manager.
@@ -650,19 +723,23 @@ pub async fn set_key_manager(
     context: Context,
     key_manager: Option<AccountId>,
 ) -> anyhow::Result<()> {
+    let (call, args) = set_key_manager_call(key_manager)?;
+    call.proceed(context, args).await
+}
+
+fn set_key_manager_call(key_manager: Option<AccountId>) -> anyhow::Result<(ContractCall, Vec<u8>)> {
     let message = key_manager.as_ref().map_or_else(
         || "has been removed".to_string(),
         |account_id| format!("{account_id} has been set"),
     );
     let args = serde_json::to_vec(&RelayerKeyManagerArgs { key_manager })?;
-
-    contract_call!(
+    let call = contract_call!(
         "set_key_manager",
         "The key manager {message} successfully",
         "Error while setting key manager"
-    )
-    .proceed(context, args)
-    .await
+    );
+
+    Ok((call, args))
 }
 
 /// Add relayer's public key.
@@ -671,28 +748,36 @@ pub async fn add_relayer_key(
     public_key: PublicKey,
     allowance: f64,
 ) -> anyhow::Result<()> {
-    let args = serde_json::to_vec(&RelayerKeyArgs { public_key })?;
+    let (call, args) = add_relayer_key_call(public_key)?;
+    call.proceed_with_deposit(context, args, allowance).await
+}
 
-    contract_call!(
+fn add_relayer_key_call(public_key: PublicKey) -> anyhow::Result<(ContractCall, Vec<u8>)> {
+    let args = serde_json::to_vec(&RelayerKeyArgs { public_key })?;
+    let call = contract_call!(
         "add_relayer_key",
         "The public key: {public_key} has been added successfully",
         "Error while adding a public key"
-    )
-    .proceed_with_deposit(context, args, allowance)
-    .await
+    );
+
+    Ok((call, args))
 }
 
 /// Remove relayer public key.
 pub async fn remove_relayer_key(context: Context, public_key: PublicKey) -> anyhow::Result<()> {
-    let args = serde_json::to_vec(&RelayerKeyArgs { public_key })?;
+    let (call, args) = remove_relayer_key_call(public_key)?;
+    call.proceed(context, args).await
+}
 
-    contract_call!(
+fn remove_relayer_key_call(public_key: PublicKey) -> anyhow::Result<(ContractCall, Vec<u8>)> {
+    let args = serde_json::to_vec(&RelayerKeyArgs { public_key })?;
+    let call = contract_call!(
         "remove_relayer_key",
         "The public key: {public_key} has been removed successfully",
         "Error while removing a public key"
-    )
-    .proceed(context, args)
-    .await
+    );
+
+    Ok((call, args))
 }
 
 /// Set a delay in blocks for an upgrade.
@@ -718,7 +803,7 @@ pub async fn get_erc20_from_nep141(context: Context, account_id: String) -> anyh
 
 /// Get NEP-141 account id from the address of ERC-20.
 pub async fn get_nep141_from_erc20(context: Context, address: String) -> anyhow::Result<()> {
-    let args = hex_to_address(&address)?.as_bytes().to_vec();
+    let args = parse_address(&address)?.as_bytes().to_vec();
     get_value::<AccountId>(context, "get_nep141_from_erc20", Some(args)).await
 }
 
@@ -731,12 +816,9 @@ pub async fn get_erc20_metadata(context: Context, identifier: String) -> anyhow:
         .near()
         .view_call("get_erc20_metadata", args)
         .await?;
-    let output = serde_json::from_slice::<Erc20Metadata>(&result.result)
-        .and_then(|metadata| serde_json::to_string_pretty(&metadata))?;
+    let metadata = serde_json::from_slice::<Erc20Metadata>(&result.result)?;
 
-    println!("{output}");
-
-    Ok(())
+    CommandOutput::value(metadata)?.print(&context.output_format)
 }
 
 /// Set a metadata of ERC-20 contract.
@@ -838,21 +920,31 @@ pub async fn set_eth_connector_contract_data<P: AsRef<Path> + Send>(
     custodian_address: String,
     ft_metadata_path: P,
 ) -> anyhow::Result<()> {
+    let (call, args) =
+        eth_connector_contract_data_call(&prover_id, &custodian_address, Some(ft_metadata_path))?;
+    call.proceed(context, args).await
+}
+
+/// The default FT metadata is used if the metadata file couldn't be read.
+fn eth_connector_contract_data_call<P: AsRef<Path>>(
+    prover_id: &str,
+    custodian_address: &str,
+    ft_metadata_path: Option<P>,
+) -> anyhow::Result<(ContractCall, Vec<u8>)> {
     let args = borsh::to_vec(&InitCallArgs {
         prover_account: prover_id.parse().map_err(|e| anyhow::anyhow!("{e}"))?,
         eth_custodian_address: custodian_address.trim_start_matches("0x").to_string(),
         metadata: utils::ft_metadata::parse_ft_metadata(
-            std::fs::read_to_string(ft_metadata_path).ok(),
+            ft_metadata_path.and_then(|path| std::fs::read_to_string(path).ok()),
         )?,
     })?;
-
-    contract_call!(
+    let call = contract_call!(
         "set_eth_connector_contract_data",
         "ETH connector data has been set successfully",
         "Error while setting ETH connector data"
-    )
-    .proceed(context, args)
-    .await
+    );
+
+    Ok((call, args))
 }
 
 pub async fn transaction_status(
@@ -865,13 +957,16 @@ pub async fn transaction_status(
         .near()
         .transaction_status(tx_hash, wait_until.into())
         .await?;
+    let mut output = CommandOutput::value(&rsp)?;
 
-    println!("{}", to_string_pretty(&rsp)?);
+    if let Some(outcome) = rsp.final_execution_outcome {
+        output = output.with_outcome(&outcome.into_outcome());
+    }
 
-    Ok(())
+    output.print(&context.output_format)
 }
 
-async fn get_value<T: FromCallResult + Display>(
+async fn get_value<T: FromCallResult>(
     context: Context,
     method_name: &str,
     args: Option<Vec<u8>>,
@@ -879,12 +974,19 @@ async fn get_value<T: FromCallResult + Display>(
     let result = context
         .client
         .near()
-        .view_call_for_block(method_name, args.unwrap_or_default(), context.block_height)
+        .view_call(method_name, args.unwrap_or_default())
         .await?;
     let output = T::from_result(result)?;
-    println!("{output}");
 
-    Ok(())
+    CommandOutput::value(output.to_value())?
+        .with_message(output.to_string())
+        .print(&context.output_format)
+}
+
+async fn get_evm_code(near: &NearClient, address: Address) -> anyhow::Result<Vec<u8>> {
+    near.view_call("get_code", address.as_bytes().to_vec())
+        .await
+        .map(|result| result.result)
 }
 
 fn to_account_id(id: Option<String>, context: &Context) -> anyhow::Result<AccountId> {
@@ -903,7 +1005,7 @@ fn to_account_id(id: Option<String>, context: &Context) -> anyhow::Result<Accoun
 }
 
 fn str_to_identifier(id: &str) -> anyhow::Result<Erc20Identifier> {
-    hex_to_address(id).map(Into::into).or_else(|_| {
+    parse_address(id).map(Into::into).or_else(|_| {
         id.parse::<AccountId>()
             .map(Into::into)
             .map_err(|e| anyhow::anyhow!("{e}"))
@@ -949,10 +1051,25 @@ impl Display for WaitUntil {
     }
 }
 
-trait FromCallResult {
+/// Arguments of the method are either JSON or bytes in hex with the `0x` prefix.
+fn parse_args(args: &str) -> anyhow::Result<Vec<u8>> {
+    if args.starts_with("0x") {
+        hex_to_vec(args)
+    } else {
+        let json: serde_json::Value = serde_json::from_str(args)?;
+        serde_json::to_vec(&json).map_err(Into::into)
+    }
+}
+
+trait FromCallResult: Display {
     fn from_result(result: CallResult) -> anyhow::Result<Self>
     where
         Self: Sized;
+
+    /// The value in the structured output, which is the displayed string by default.
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
 }
 
 impl FromCallResult for H256 {
@@ -973,6 +1090,10 @@ impl FromCallResult for u64 {
         buffer.copy_from_slice(&result.result);
         Ok(Self::from_le_bytes(buffer))
     }
+
+    fn to_value(&self) -> Value {
+        (*self).into()
+    }
 }
 
 impl FromCallResult for u32 {
@@ -981,6 +1102,20 @@ impl FromCallResult for u32 {
         buffer.copy_from_slice(&result.result);
         Ok(Self::from_le_bytes(buffer))
     }
+
+    fn to_value(&self) -> Value {
+        (*self).into()
+    }
+}
+
+impl FromCallResult for bool {
+    fn from_result(result: CallResult) -> anyhow::Result<Self> {
+        Ok(borsh::from_slice(&result.result)?)
+    }
+
+    fn to_value(&self) -> Value {
+        (*self).into()
+    }
 }
 
 impl FromCallResult for String {
@@ -1002,10 +1137,34 @@ impl FromCallResult for AccountId {
     }
 }
 
+impl FromCallResult for PrecompileMask {
+    fn from_result(result: CallResult) -> anyhow::Result<Self> {
+        u32::from_result(result).map(Self::from_bits)
+    }
+
+    fn to_value(&self) -> Value {
+        (*self).to_value()
+    }
+}
+
+impl FromCallResult for PausedFlags {
+    fn from_result(result: CallResult) -> anyhow::Result<Self> {
+        PausedMask::from_result(result).map(|mask| Self::from_bits(mask.into()))
+    }
+
+    fn to_value(&self) -> Value {
+        (*self).to_value()
+    }
+}
+
 impl FromCallResult for PausedMask {
     fn from_result(result: CallResult) -> anyhow::Result<Self> {
         Self::try_from_slice(&result.result).map_err(|e| anyhow::anyhow!("{e}"))
     }
+
+    fn to_value(&self) -> Value {
+        (*self).into()
+    }
 }
 
 impl Display for HexString {
@@ -1014,13 +1173,16 @@ impl Display for HexString {
     }
 }
 
-struct ContractCall<'a> {
-    method: &'a str,
-    success_message: &'a str,
-    error_message: &'a str,
+/// Decodes the bytes returned by the contract method.
+type DecodeOutput = fn(&[u8]) -> anyhow::Result<Value>;
+
+struct ContractCall {
+    method: &'static str,
+    success_message: String,
+    error_message: String,
 }
 
-impl ContractCall<'_> {
+impl ContractCall {
     async fn proceed(&self, context: Context, args: Vec<u8>) -> anyhow::Result<()> {
         self.proceed_with_deposit(context, args, 0.0).await
     }
@@ -1029,7 +1191,7 @@ impl ContractCall<'_> {
         &self,
         context: Context,
         args: Vec<u8>,
-        output: fn(&[u8]) -> String,
+        output: DecodeOutput,
     ) -> anyhow::Result<()> {
         self.proceed_with_deposit_and_output(context, args, 0.0, Some(output))
             .await
@@ -1045,52 +1207,46 @@ impl ContractCall<'_> {
             .await
     }
 
+    /// Call the method and print the outcome. The output function decodes the returned bytes
+    /// if the method doesn't return JSON or text.
     async fn proceed_with_deposit_and_output(
         &self,
         context: Context,
         args: Vec<u8>,
         deposit: f64,
-        output: Option<fn(&[u8]) -> String>,
+        output: Option<DecodeOutput>,
     ) -> anyhow::Result<()> {
+        self.transact(&context.client.near(), args, deposit, output)
+            .await?
+            .print(&context.output_format)
+    }
+
+    /// Call the method and return the outcome with the message of the plain format.
+    async fn transact(
+        &self,
+        client: &NearClient,
+        args: Vec<u8>,
+        deposit: f64,
+        output: Option<DecodeOutput>,
+    ) -> anyhow::Result<CommandOutput> {
         let yocto = near_to_yocto(deposit);
-        let outcome = context
-            .client
-            .near()
+        let outcome = client
             .contract_call_with_deposit(self.method, args, yocto)
             .await?;
+        let mut result = CommandOutput::transaction(&outcome, &self.error_message);
 
-        match outcome.status {
-            FinalExecutionStatus::NotStarted | FinalExecutionStatus::Started => {
-                anyhow::bail!("{}: Bad transaction status", self.error_message)
-            }
-            FinalExecutionStatus::Failure(e) => {
-                anyhow::bail!("{}: {e}", self.error_message)
-            }
-            FinalExecutionStatus::SuccessValue(result) => match context.output_format {
-                // TODO: The output could be serialized with JSON or Borsh.
-                // TODO: In the case of Borsh we should provide a type for deserializing the output in the corresponding object.
-                OutputFormat::Plain => match to_string_pretty(&result) {
-                    Ok(msg) if !result.is_empty() => {
-                        if let Some(output) = output {
-                            println!("{}, {}", self.success_message, output(&result));
-                        } else {
-                            println!("{}: {msg}", self.success_message);
-                        }
-                    }
-                    Ok(_) | Err(_) => println!("{}", self.success_message),
-                },
-                OutputFormat::Json => {
-                    let formatted = to_string_pretty(&outcome.transaction_outcome)?;
-                    println!("{formatted}");
-                }
-                OutputFormat::Toml => {
-                    let formatted = toml::to_string_pretty(&outcome.transaction_outcome)?;
-                    println!("{formatted}");
-                }
-            },
+        if let (Some(output), FinalExecutionStatus::SuccessValue(bytes)) = (output, &outcome.status)
+        {
+            result = result.with_result(output(bytes)?);
         }
 
-        Ok(())
+        let message = match &result.result {
+            Some(Value::String(value)) => format!("{}: {value}", self.success_message),
+            Some(value) => format!("{}: {value}", self.success_message),
+            None => self.success_message.clone(),
+        };
+
+        Ok(result.with_message(message))
     }
 }
 
@@ -1114,19 +1270,20 @@ pub async fn add_relayer(
             function_call_pub_key,
         )
         .await?;
-
-    rsp.assert_success();
-
-    println!(
+    let message = format!(
         "Relayer has been added successfully. (hash: {})",
         rsp.transaction.hash
     );
-    Ok(())
+
+    CommandOutput::transaction(&rsp, "Error while adding relayer")
+        .with_message(message)
+        .print(&context.output_format)
 }
 
-fn erc20_output(bytes: &[u8]) -> String {
-    let raw_bytes: Vec<u8> = BorshDeserialize::try_from_slice(bytes).unwrap();
-    let erc20_address = Address::try_from_slice(&raw_bytes).unwrap();
+/// Address of the deployed ERC-20 token.
+fn erc20_output(bytes: &[u8]) -> anyhow::Result<Value> {
+    let raw_bytes: Vec<u8> = BorshDeserialize::try_from_slice(bytes)?;
+    let erc20_address = Address::try_from_slice(&raw_bytes).map_err(|e| anyhow::anyhow!("{e}"))?;
 
-    format!("token address: 0x{}", erc20_address.encode())
+    Ok(format!("0x{}", erc20_address.encode()).into())
 }
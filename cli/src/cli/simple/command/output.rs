@@ -0,0 +1,396 @@
+use aurora_engine_types::borsh::BorshDeserialize;
+use aurora_engine_types::parameters::engine::{SubmitResult, TransactionStatus};
+use aurora_sdk_rs::aurora::error::EngineError;
+use near_primitives::hash::CryptoHash;
+use near_primitives::views::{FinalExecutionOutcomeView, FinalExecutionStatus};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::cli::simple::OutputFormat;
+use crate::error::{Error, ErrorKind, Reported};
+
+/// Status of the command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Success,
+    Failure,
+}
+
+/// The result of a command, which has the same schema for every command.
+#[derive(Debug, Serialize)]
+pub struct CommandOutput {
+    /// Whether the command has succeeded.
+    pub status: Status,
+    /// Hash of the NEAR transaction sent by the command.
+    pub tx_hash: Option<CryptoHash>,
+    /// Decoded return value.
+    pub result: Option<Value>,
+    /// NEAR gas burnt by the transaction and all its receipts.
+    pub gas_burnt: Option<u64>,
+    /// EVM gas used by the Aurora transaction.
+    pub evm_gas_used: Option<u64>,
+    /// Logs of the transaction and all its receipts.
+    pub logs: Vec<String>,
+    /// The reason of the failure.
+    pub error: Option<ErrorOutput>,
+    /// Human-readable message printed instead of the result in the plain format.
+    #[serde(skip)]
+    pub message: Option<String>,
+    #[serde(skip)]
+    failure: Option<Error>,
+}
+
+/// Status of a step of a command made of several transactions.
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StepStatus {
+    Done,
+    Skipped,
+}
+
+/// A step of a command made of several transactions, skipped if it has been done already.
+#[derive(Debug, Serialize)]
+pub struct Step {
+    pub name: &'static str,
+    pub status: StepStatus,
+    pub tx_hash: Option<CryptoHash>,
+}
+
+/// The table of the steps with their statuses and transactions for the plain format.
+#[must_use]
+pub fn steps_table(steps: &[Step]) -> String {
+    let width = steps
+        .iter()
+        .map(|step| step.name.len())
+        .chain(std::iter::once("STEP".len()))
+        .max()
+        .unwrap_or_default();
+
+    std::iter::once(format!("{:width$}  {:7}  TX HASH", "STEP", "STATUS"))
+        .chain(steps.iter().map(|step| {
+            let status = match step.status {
+                StepStatus::Done => "done",
+                StepStatus::Skipped => "skipped",
+            };
+            let hash = step
+                .tx_hash
+                .map(|hash| hash.to_string())
+                .unwrap_or_default();
+
+            format!("{:width$}  {status:7}  {hash}", step.name)
+        }))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The envelope of the error with its kind and the exit code of the process.
+#[derive(Debug, Serialize)]
+pub struct ErrorOutput {
+    pub kind: ErrorKind,
+    pub exit_code: u8,
+    pub message: String,
+}
+
+impl CommandOutput {
+    /// Successful output with the value.
+    pub fn value<T: Serialize>(value: T) -> anyhow::Result<Self> {
+        let value = serde_json::to_value(value)?;
+
+        Ok(Self {
+            result: (!value.is_null()).then_some(value),
+            ..Self::default()
+        })
+    }
+
+    /// Output of the NEAR transaction. The error message is used as a prefix of the failure.
+    #[must_use]
+    pub fn transaction(outcome: &FinalExecutionOutcomeView, error_message: &str) -> Self {
+        let output = Self::default().with_outcome(outcome);
+
+        match &outcome.status {
+            FinalExecutionStatus::NotStarted | FinalExecutionStatus::Started => output.failure(
+                Error::NearTransaction(format!("{error_message}: Bad transaction status")),
+            ),
+            FinalExecutionStatus::Failure(e) => {
+                output.failure(Error::near_transaction(format!("{error_message}: {e}")))
+            }
+            FinalExecutionStatus::SuccessValue(value) => Self {
+                result: decode_value(value),
+                ..output
+            },
+        }
+    }
+
+    /// Add the hash, burnt gas and logs of the transaction without changing the status.
+    #[must_use]
+    pub fn with_outcome(self, outcome: &FinalExecutionOutcomeView) -> Self {
+        let outcomes = std::iter::once(&outcome.transaction_outcome)
+            .chain(&outcome.receipts_outcome)
+            .map(|outcome| &outcome.outcome);
+
+        Self {
+            tx_hash: Some(outcome.transaction.hash),
+            gas_burnt: Some(outcomes.clone().map(|o| o.gas_burnt.as_gas()).sum()),
+            logs: outcomes.flat_map(|o| o.logs.iter().cloned()).collect(),
+            ..self
+        }
+    }
+
+    /// Decode the result of the Aurora transaction returned by `submit` or `call`, and fail if
+    /// the EVM execution hasn't succeeded. The output of a successful execution is returned.
+    pub fn decode_submit_result(
+        &mut self,
+        outcome: &FinalExecutionOutcomeView,
+    ) -> anyhow::Result<Option<Vec<u8>>> {
+        let FinalExecutionStatus::SuccessValue(bytes) = &outcome.status else {
+            return Ok(None);
+        };
+        let result = SubmitResult::try_from_slice(bytes)?;
+        self.evm_gas_used = Some(result.gas_used);
+
+        if let TransactionStatus::Succeed(bytes) = result.status {
+            self.result = Some(format!("0x{}", hex::encode(&bytes)).into());
+            Ok(Some(bytes))
+        } else {
+            let error = Error::EvmRevert(format!(
+                "Aurora transaction status: {}, gas used: {}",
+                evm_status(&result.status),
+                result.gas_used
+            ));
+            *self = Self {
+                result: None,
+                ..std::mem::take(self).failure(error)
+            };
+            Ok(None)
+        }
+    }
+
+    #[must_use]
+    pub fn with_result(self, result: Value) -> Self {
+        Self {
+            result: Some(result),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn with_message<M: Into<String>>(self, message: M) -> Self {
+        Self {
+            message: Some(message.into()),
+            ..self
+        }
+    }
+
+    #[must_use]
+    pub fn failure(self, error: Error) -> Self {
+        Self {
+            status: Status::Failure,
+            error: Some(ErrorOutput {
+                kind: error.kind(),
+                exit_code: error.kind().exit_code(),
+                message: error.to_string(),
+            }),
+            failure: Some(error),
+            ..self
+        }
+    }
+
+    /// Aurora Engine error of the failed output, e.g. `ERR_PAUSED`.
+    #[must_use]
+    pub const fn engine_error(&self) -> Option<&EngineError> {
+        match &self.failure {
+            Some(Error::Engine { error, .. }) => Some(error),
+            _ => None,
+        }
+    }
+
+    /// Failed output of the error, which has happened before the command could produce a result.
+    #[must_use]
+    pub fn error(error: &anyhow::Error) -> Self {
+        let kind = ErrorKind::of(error);
+
+        Self {
+            status: Status::Failure,
+            error: Some(ErrorOutput {
+                kind,
+                exit_code: kind.exit_code(),
+                message: format!("{error:#}"),
+            }),
+            ..Self::default()
+        }
+    }
+
+    /// Print the output in the format. The failed output is returned as an error, so the command
+    /// exits with the code of the error kind in every format.
+    pub fn print(self, format: &OutputFormat) -> anyhow::Result<()> {
+        match format {
+            OutputFormat::Plain => {
+                let text = match self.status {
+                    Status::Success => self.message.clone().or_else(|| self.result_text()),
+                    Status::Failure => self.result_text(),
+                };
+
+                if let Some(text) = text {
+                    println!("{text}");
+                }
+            }
+            OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&self)?),
+            OutputFormat::Toml => println!("{}", toml::to_string_pretty(&self)?),
+        }
+
+        self.failure
+            .map_or(Ok(()), |error| Err(Reported(error).into()))
+    }
+
+    fn result_text(&self) -> Option<String> {
+        self.result.as_ref().map(|result| match result {
+            Value::String(string) => string.clone(),
+            value => serde_json::to_string_pretty(value).unwrap_or_else(|_| value.to_string()),
+        })
+    }
+}
+
+impl Default for CommandOutput {
+    fn default() -> Self {
+        Self {
+            status: Status::Success,
+            tx_hash: None,
+            result: None,
+            gas_burnt: None,
+            evm_gas_used: None,
+            logs: vec![],
+            error: None,
+            message: None,
+            failure: None,
+        }
+    }
+}
+
+/// Snake case name of the EVM execution status.
+#[must_use]
+pub const fn evm_status(status: &TransactionStatus) -> &'static str {
+    match status {
+        TransactionStatus::Succeed(_) => "successful",
+        TransactionStatus::Revert(_) => "reverted",
+        TransactionStatus::OutOfGas => "out_of_gas",
+        TransactionStatus::OutOfFund => "out_of_fund",
+        TransactionStatus::OutOfOffset => "out_of_offset",
+        TransactionStatus::CallTooDeep => "call_too_deep",
+        TransactionStatus::StackUnderflow => "stack_underflow",
+        TransactionStatus::StackOverflow => "stack_overflow",
+        TransactionStatus::InvalidJump => "invalid_jump",
+        TransactionStatus::InvalidRange => "invalid_range",
+        TransactionStatus::DesignatedInvalid => "designated_invalid",
+        TransactionStatus::CreateCollision => "create_collision",
+        TransactionStatus::CreateContractLimit => "create_contract_limit",
+        TransactionStatus::InvalidCode(_) => "invalid_code",
+        TransactionStatus::PCUnderflow => "pc_underflow",
+        TransactionStatus::CreateEmpty => "create_empty",
+        TransactionStatus::MaxNonce => "max_nonce",
+        TransactionStatus::UsizeOverflow => "usize_overflow",
+        TransactionStatus::Other(_) => "other",
+        TransactionStatus::CreateContractStartingWithEF => "create_contract_starting_with_ef",
+    }
+}
+
+/// The value returned by a contract is JSON, UTF-8 text or raw bytes, which are encoded in hex.
+fn decode_value(bytes: &[u8]) -> Option<Value> {
+    if bytes.is_empty() {
+        return None;
+    }
+
+    if let Ok(value) = serde_json::from_slice::<Value>(bytes) {
+        return (!value.is_null()).then_some(value);
+    }
+
+    let text = std::str::from_utf8(bytes)
+        .ok()
+        .filter(|text| !text.chars().any(char::is_control))
+        .map_or_else(|| format!("0x{}", hex::encode(bytes)), str::to_string);
+
+    Some(Value::String(text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_value() {
+        assert_eq!(decode_value(b""), None);
+        assert_eq!(decode_value(b"null"), None);
+        assert_eq!(
+            decode_value(br#"{"a":1}"#),
+            Some(serde_json::json!({"a": 1}))
+        );
+        assert_eq!(decode_value(b"3.9.0"), Some("3.9.0".into()));
+        assert_eq!(decode_value(&[0, 1, 0xff]), Some("0x0001ff".into()));
+    }
+
+    #[test]
+    fn test_json_schema() {
+        let output = CommandOutput::value(42).unwrap().with_message("42");
+        let json = serde_json::to_value(&output).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "status": "success",
+                "tx_hash": null,
+                "result": 42,
+                "gas_burnt": null,
+                "evm_gas_used": null,
+                "logs": [],
+                "error": null,
+            })
+        );
+    }
+
+    #[test]
+    fn test_toml_skips_missing_fields() {
+        let output = CommandOutput::value("0x01")
+            .unwrap()
+            .failure(Error::EvmRevert("reverted".to_string()));
+        let toml = toml::to_string_pretty(&output).unwrap();
+
+        assert_eq!(
+            toml,
+            "status = \"failure\"\nresult = \"0x01\"\nlogs = []\n\n[error]\nkind = \"evm_revert\"\nexit_code = 5\nmessage = \"reverted\"\n"
+        );
+    }
+
+    #[test]
+    fn test_error_envelope() {
+        let error = anyhow::Error::from(Error::Rpc("connection refused".to_string()))
+            .context("Couldn't get the chain id");
+        let json = serde_json::to_value(CommandOutput::error(&error)).unwrap();
+
+        assert_eq!(json["status"], "failure");
+        assert_eq!(
+            json["error"],
+            serde_json::json!({
+                "kind": "rpc",
+                "exit_code": 3,
+                "message": "Couldn't get the chain id: connection refused",
+            })
+        );
+    }
+
+    #[test]
+    fn test_failure_is_error() {
+        let error = CommandOutput::default()
+            .failure(Error::near_transaction(
+                "Error while pausing the contract: Smart contract panicked: ERR_NOT_ALLOWED",
+            ))
+            .print(&OutputFormat::Json)
+            .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Error while pausing the contract: Smart contract panicked: ERR_NOT_ALLOWED"
+        );
+        assert_eq!(ErrorKind::of(&error), ErrorKind::Engine);
+        assert!(CommandOutput::default().print(&OutputFormat::Plain).is_ok());
+    }
+}
@@ -0,0 +1,211 @@
+//! Relayer keys: function-call access keys of the engine, which relayers sign transactions with.
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+use aurora_engine_types::public_key::PublicKey as EnginePublicKey;
+use near_crypto::{KeyFile, PublicKey, SecretKey};
+use near_primitives::types::{AccountId, Balance};
+use near_primitives::views::{AccessKeyInfoView, AccessKeyPermissionView, TxExecutionStatus};
+use serde::Serialize;
+use serde_json::json;
+
+use super::output::{CommandOutput, Status};
+use super::{add_relayer_key_call, remove_relayer_key_call};
+use crate::client::{Context, NearClient};
+use crate::error::Error;
+use crate::utils;
+
+/// Methods the relayer keys are allowed to call, as set by the engine.
+const RELAYER_METHODS: [&str; 3] = ["call", "submit", "submit_with_args"];
+
+/// Relayer key with the allowance left.
+#[derive(Debug, Serialize)]
+struct RelayerKey {
+    public_key: PublicKey,
+    /// Allowance left in yoctoNEAR, or `None` if it is unlimited.
+    allowance: Option<Balance>,
+    nonce: u64,
+    method_names: Vec<String>,
+}
+
+impl RelayerKey {
+    /// The function-call key of the engine, which could call the relayer methods only.
+    fn from_view(key: AccessKeyInfoView, engine: &AccountId) -> Option<Self> {
+        let AccessKeyPermissionView::FunctionCall {
+            allowance,
+            receiver_id,
+            method_names,
+        } = key.access_key.permission
+        else {
+            return None;
+        };
+        let is_relayer = receiver_id == engine.as_str()
+            && !method_names.is_empty()
+            && method_names
+                .iter()
+                .all(|method| RELAYER_METHODS.contains(&method.as_str()));
+
+        is_relayer.then_some(Self {
+            public_key: key.public_key,
+            allowance,
+            nonce: key.access_key.nonce,
+            method_names,
+        })
+    }
+}
+
+/// List the relayer keys of the engine with their allowance left.
+pub async fn list(context: Context) -> anyhow::Result<()> {
+    let client = context.client.near();
+    let keys = relayer_keys(&client).await?;
+    let message = table(&keys);
+
+    CommandOutput::value(&keys)?
+        .with_message(message)
+        .print(&context.output_format)
+}
+
+/// Add a new relayer key with the allowance in NEAR, wait until the key is final, replace the
+/// relayer's key file with the new key and remove the old one.
+///
+/// The new key is written next to the key file before it is added, so it isn't lost if the
+/// rotation is interrupted.
+pub async fn rotate<P: AsRef<Path> + Send>(
+    context: Context,
+    key_file: P,
+    allowance: f64,
+) -> anyhow::Result<()> {
+    let key_file = key_file.as_ref();
+    let client = context.client.near_latest();
+    let old = utils::read_key_file(key_file)
+        .map_err(|e| Error::Usage(format!("Couldn't read {}: {e}", key_file.display())))?;
+
+    if old.account_id != client.engine_account_id {
+        return Err(Error::Usage(format!(
+            "The key file {} is of {}, not of the engine {}",
+            key_file.display(),
+            old.account_id,
+            client.engine_account_id
+        ))
+        .into());
+    }
+
+    if !relayer_keys(&client)
+        .await?
+        .iter()
+        .any(|key| key.public_key == old.public_key)
+    {
+        return Err(Error::Usage(format!(
+            "{} isn't a relayer key of {}",
+            old.public_key, client.engine_account_id
+        ))
+        .into());
+    }
+
+    let secret_key = SecretKey::from_random(old.public_key.key_type());
+    let new = KeyFile {
+        account_id: old.account_id,
+        public_key: secret_key.public_key(),
+        secret_key,
+    };
+    let pending = pending_path(key_file);
+    new.write_to_file(&pending)?;
+
+    let (call, args) = add_relayer_key_call(engine_public_key(&new.public_key)?)?;
+    let added = call.transact(&client, args, allowance, None).await?;
+
+    if added.status == Status::Failure {
+        return added.print(&context.output_format);
+    }
+
+    if let Some(hash) = added.tx_hash {
+        client
+            .transaction_status(hash, TxExecutionStatus::Final)
+            .await?;
+    }
+
+    std::fs::rename(&pending, key_file)?;
+
+    let (call, args) = remove_relayer_key_call(engine_public_key(&old.public_key)?)?;
+    let removed = call.transact(&client, args, 0.0, None).await?;
+
+    if removed.status == Status::Failure {
+        return removed.print(&context.output_format);
+    }
+
+    CommandOutput::value(json!({
+        "old_public_key": old.public_key,
+        "new_public_key": new.public_key,
+        "key_file": key_file,
+        "add_tx_hash": added.tx_hash,
+        "remove_tx_hash": removed.tx_hash,
+    }))?
+    .with_message(format!(
+        "The relayer key {} has been replaced by {}, the new key is written to {}",
+        old.public_key,
+        new.public_key,
+        key_file.display()
+    ))
+    .print(&context.output_format)
+}
+
+async fn relayer_keys(client: &NearClient) -> anyhow::Result<Vec<RelayerKey>> {
+    let keys = client.view_access_keys(&client.engine_account_id).await?;
+
+    Ok(keys
+        .into_iter()
+        .filter_map(|key| RelayerKey::from_view(key, &client.engine_account_id))
+        .collect())
+}
+
+fn table(keys: &[RelayerKey]) -> String {
+    if keys.is_empty() {
+        return "No relayer keys".to_string();
+    }
+
+    let rows = keys
+        .iter()
+        .map(|key| {
+            let allowance = key.allowance.map_or_else(
+                || "unlimited".to_string(),
+                |allowance| allowance.to_string(),
+            );
+            (key.public_key.to_string(), allowance, key.nonce)
+        })
+        .collect::<Vec<_>>();
+    let width = rows
+        .iter()
+        .map(|(public_key, ..)| public_key.len())
+        .max()
+        .unwrap_or_default();
+    let allowance_width = rows
+        .iter()
+        .map(|(_, allowance, _)| allowance.len())
+        .chain(std::iter::once("ALLOWANCE LEFT".len()))
+        .max()
+        .unwrap_or_default();
+
+    std::iter::once(format!(
+        "{:width$}  {:allowance_width$}  NONCE",
+        "PUBLIC KEY", "ALLOWANCE LEFT"
+    ))
+    .chain(rows.iter().map(|(public_key, allowance, nonce)| {
+        format!("{public_key:width$}  {allowance:allowance_width$}  {nonce}")
+    }))
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+/// Path of the new key until it replaces the key file, e.g. `relayer.json.new`.
+fn pending_path(key_file: &Path) -> PathBuf {
+    let mut path = OsString::from(key_file);
+    path.push(".new");
+    path.into()
+}
+
+fn engine_public_key(public_key: &PublicKey) -> anyhow::Result<EnginePublicKey> {
+    public_key
+        .to_string()
+        .parse()
+        .map_err(|e| anyhow::anyhow!("Wrong public key {public_key}: {e:?}"))
+}
@@ -0,0 +1,98 @@
+use aurora_engine_types::H256;
+
+use super::output::CommandOutput;
+use crate::cli::parse_address;
+use crate::cli::simple::OutputFormat;
+use crate::error::Error;
+use crate::utils::{self, eip712::TypedData, hex_to_vec, secret_key_from_hex};
+
+/// Sign the message according to EIP-191 (`personal_sign`).
+pub fn sign_message(
+    message: &str,
+    is_hex: bool,
+    sk: Option<&str>,
+    format: &OutputFormat,
+) -> anyhow::Result<()> {
+    let message = message_bytes(message, is_hex)?;
+    let hash = utils::hash_personal_message(&message);
+
+    print_signature(hash, sk, format)
+}
+
+/// Sign the typed structured data according to EIP-712.
+pub fn sign_typed_data(data: &str, sk: Option<&str>, format: &OutputFormat) -> anyhow::Result<()> {
+    let hash = read_typed_data(data)?.signing_hash()?;
+    print_signature(hash, sk, format)
+}
+
+/// Recover the signer of the EIP-191 message or EIP-712 typed data and compare it with
+/// the expected address if provided.
+pub fn verify_signature(
+    signature: &str,
+    message: Option<String>,
+    is_hex: bool,
+    typed_data: Option<String>,
+    address: Option<String>,
+    format: &OutputFormat,
+) -> anyhow::Result<()> {
+    let hash = match (message, typed_data) {
+        (Some(message), None) => utils::hash_personal_message(&message_bytes(&message, is_hex)?),
+        (None, Some(data)) => read_typed_data(&data)?.signing_hash()?,
+        _ => anyhow::bail!("Either a message or typed data must be provided"),
+    };
+    let signature = hex_to_vec(signature)?;
+    let signer = utils::recover_address(hash, &signature)?;
+    let mut output = serde_json::json!({
+        "hash": format!("{hash:#x}"),
+        "signer": format!("0x{}", signer.encode()),
+    });
+
+    if let Some(address) = address {
+        let is_valid = parse_address(&address)? == signer;
+        output["valid"] = is_valid.into();
+
+        if !is_valid {
+            return CommandOutput::value(output)?
+                .failure(Error::Other(format!(
+                    "The signature doesn't match the address: {address}"
+                )))
+                .print(format);
+        }
+    }
+
+    CommandOutput::value(output)?.print(format)
+}
+
+fn print_signature(hash: H256, sk: Option<&str>, format: &OutputFormat) -> anyhow::Result<()> {
+    let sk = sk
+        .ok_or_else(|| anyhow::anyhow!("Signing requires Aurora secret key"))
+        .and_then(secret_key_from_hex)?;
+    let signer = utils::address_from_secret_key(&sk)?;
+    let signature = utils::sign_hash(hash, &sk);
+
+    CommandOutput::value(serde_json::json!({
+        "address": format!("0x{}", signer.encode()),
+        "hash": format!("{hash:#x}"),
+        "signature": format!("0x{}", hex::encode(signature)),
+    }))?
+    .print(format)
+}
+
+fn message_bytes(message: &str, is_hex: bool) -> anyhow::Result<Vec<u8>> {
+    if is_hex {
+        hex_to_vec(message)
+    } else {
+        Ok(message.as_bytes().to_vec())
+    }
+}
+
+/// Typed data could be passed as JSON or as a path to the file with JSON.
+fn read_typed_data(data: &str) -> anyhow::Result<TypedData> {
+    let path = std::path::Path::new(data);
+
+    if path.is_file() {
+        TypedData::from_json(&std::fs::read_to_string(path)?)
+    } else {
+        TypedData::from_json(data)
+    }
+}
@@ -5,13 +5,14 @@ use aurora_engine_types::parameters::silo::{
 };
 use aurora_engine_types::types::EthGas;
 use near_primitives::views::CallResult;
+use serde_json::Value;
 use std::fmt::{Display, Formatter};
 
 use super::{ContractCall, get_value};
 use crate::cli::command::FromCallResult;
+use crate::cli::parse_address;
 use crate::client::Context;
 use crate::contract_call;
-use crate::utils::hex_to_address;
 
 /// Return fixed gas cost.
 pub async fn get_fixed_gas_cost(client: Context) -> anyhow::Result<()> {
@@ -20,17 +21,21 @@ pub async fn get_fixed_gas_cost(client: Context) -> anyhow::Result<()> {
 
 /// Set fixed gas cost.
 pub async fn set_fixed_gas(client: Context, cost: u64) -> anyhow::Result<()> {
+    let (call, args) = set_fixed_gas_call(cost)?;
+    call.proceed(client, args).await
+}
+
+pub(super) fn set_fixed_gas_call(cost: u64) -> anyhow::Result<(ContractCall, Vec<u8>)> {
     let args = borsh::to_vec(&FixedGasArgs {
         fixed_gas: Some(EthGas::new(cost)),
     })?;
-
-    contract_call!(
+    let call = contract_call!(
         "set_fixed_gas",
         "The fixed gas: {cost} has been set successfully",
         "Error while setting fixed gas"
-    )
-    .proceed(client, args)
-    .await
+    );
+
+    Ok((call, args))
 }
 
 /// Return Silo parameters.
@@ -45,7 +50,7 @@ pub async fn set_silo_params(
 ) -> anyhow::Result<()> {
     let args = borsh::to_vec(&Some(SiloParamsArgs {
         fixed_gas: EthGas::new(gas),
-        erc20_fallback_address: hex_to_address(&fallback_address)?,
+        erc20_fallback_address: parse_address(&fallback_address)?,
     }))?;
 
     contract_call!(
@@ -102,15 +107,22 @@ pub async fn add_entry_to_whitelist(
     kind: String,
     entry: String,
 ) -> anyhow::Result<()> {
-    let args = get_whitelist_args(&kind, &entry)?;
+    let (call, args) = add_entry_to_whitelist_call(&kind, &entry)?;
+    call.proceed(client, args).await
+}
 
-    contract_call!(
+pub(super) fn add_entry_to_whitelist_call(
+    kind: &str,
+    entry: &str,
+) -> anyhow::Result<(ContractCall, Vec<u8>)> {
+    let args = get_whitelist_args(kind, entry)?;
+    let call = contract_call!(
         "add_entry_to_whitelist",
         "The entry: {entry} has been added to the whitelist successfully",
         "Error while adding entry to whitelist"
-    )
-    .proceed(client, args)
-    .await
+    );
+
+    Ok((call, args))
 }
 
 /// Add a batch of entries to the whitelist.
@@ -168,7 +180,7 @@ pub fn get_whitelist_args(kind: &str, entry: &str) -> anyhow::Result<Vec<u8>> {
         WhitelistKind::EvmAdmin | WhitelistKind::Address => {
             WhitelistArgs::WhitelistAddressArgs(WhitelistAddressArgs {
                 kind,
-                address: hex_to_address(entry)?,
+                address: parse_address(entry)?,
             })
         }
     })
@@ -182,6 +194,10 @@ impl FromCallResult for WhitelistStatus {
         let args = WhitelistStatusArgs::try_from_slice(&result.result)?;
         Ok(Self(args))
     }
+
+    fn to_value(&self) -> Value {
+        self.0.active.into()
+    }
 }
 
 impl Display for WhitelistStatus {
@@ -198,6 +214,10 @@ impl FromCallResult for FixedGas {
         let args = FixedGasArgs::try_from_slice(&result.result)?;
         Ok(Self(args))
     }
+
+    fn to_value(&self) -> Value {
+        self.0.fixed_gas.map(EthGas::as_u64).into()
+    }
 }
 
 impl Display for FixedGas {
@@ -217,6 +237,15 @@ impl FromCallResult for SiloParams {
         let args = Option::<SiloParamsArgs>::try_from_slice(&result.result)?;
         Ok(Self(args))
     }
+
+    fn to_value(&self) -> Value {
+        self.0.as_ref().map_or(Value::Null, |params| {
+            serde_json::json!({
+                "fixed_gas": params.fixed_gas.as_u64(),
+                "erc20_fallback_address": format!("0x{}", params.erc20_fallback_address.encode()),
+            })
+        })
+    }
 }
 
 impl Display for SiloParams {
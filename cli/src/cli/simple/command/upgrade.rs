@@ -0,0 +1,287 @@
+//! Staged upgrade of the engine from staging the code to the check of the new version.
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
+
+use near_primitives::hash::CryptoHash;
+use near_primitives::views::{
+    FinalExecutionOutcomeView, FinalExecutionOutcomeViewEnum, TxExecutionStatus,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use super::output::{CommandOutput, Status, Step, StepStatus, steps_table};
+use super::{FromCallResult, deploy_upgrade_call, parse_args, stage_upgrade_call};
+use crate::client::{Context, NearClient};
+use crate::error::Error;
+use crate::utils::wasm::{self, Contract};
+
+/// Interval between the checks of the final block height while waiting for the upgrade index.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Progress of the upgrade, which is saved after every step and removed once the upgrade is
+/// verified, so the interrupted upgrade is resumed from the step it has stopped at.
+#[derive(Debug, Serialize, Deserialize)]
+struct State {
+    engine: String,
+    code_hash: CryptoHash,
+    previous_version: String,
+    upgrade_index: Option<u64>,
+    /// Hash of the migration transaction, which is saved before it's sent.
+    #[serde(default)]
+    migration_tx: Option<CryptoHash>,
+    migrated: bool,
+}
+
+/// Stage the code, wait until the upgrade index is reached, deploy the upgrade, call the migration
+/// method and check that the version has changed.
+///
+/// The steps which have been done are skipped on the next run with the same state file, and the
+/// staging and deployment are skipped if the code is already deployed.
+pub async fn run<P: AsRef<Path> + Send>(
+    context: Context,
+    wasm: P,
+    migration: Option<String>,
+    migration_args: Option<String>,
+    state_path: P,
+) -> anyhow::Result<()> {
+    let wasm = wasm.as_ref();
+    let state_path = state_path.as_ref();
+    let code = std::fs::read(wasm)
+        .map_err(|e| Error::Usage(format!("Couldn't read {}: {e}", wasm.display())))?;
+    wasm::check(&code, Contract::Engine)?;
+    let code_hash = CryptoHash::hash_bytes(&code);
+    let migration_args = migration_args
+        .as_deref()
+        .map(parse_args)
+        .transpose()?
+        .unwrap_or_default();
+    let near = context.client.near_latest();
+    let deployed_hash = near.view_code().await?.hash;
+
+    let mut state = match read_state(state_path, &near, code_hash)? {
+        Some(state) => state,
+        None if deployed_hash == code_hash => {
+            return Err(Error::Usage(format!(
+                "The code {code_hash} is already deployed to {}",
+                near.engine_account_id
+            ))
+            .into());
+        }
+        None => State {
+            engine: near.engine_account_id.to_string(),
+            code_hash,
+            previous_version: version(&near).await?,
+            upgrade_index: None,
+            migration_tx: None,
+            migrated: false,
+        },
+    };
+    write_state(state_path, &state)?;
+
+    let mut steps = vec![];
+
+    if deployed_hash == code_hash {
+        steps.extend(["stage_upgrade", "wait", "deploy_upgrade"].map(skipped));
+    } else {
+        let upgrade_index = if let Some(index) = state.upgrade_index {
+            steps.push(skipped("stage_upgrade"));
+            index
+        } else {
+            let output = stage_upgrade_call()
+                .transact(&near, code, 0.0, None)
+                .await?;
+            done(&context, &mut steps, "stage_upgrade", output)?;
+            wait_for_finality(&near, &steps).await?;
+            let index = near
+                .view_call("get_upgrade_index", vec![])
+                .await
+                .and_then(u64::from_result)?;
+            state.upgrade_index = Some(index);
+            write_state(state_path, &state)?;
+            index
+        };
+
+        wait_for_block(&near, upgrade_index).await?;
+        steps.push(Step {
+            name: "wait",
+            status: StepStatus::Done,
+            tx_hash: None,
+        });
+
+        let output = deploy_upgrade_call()
+            .transact(&near, vec![], 0.0, None)
+            .await?;
+        done(&context, &mut steps, "deploy_upgrade", output)?;
+        wait_for_finality(&near, &steps).await?;
+    }
+
+    match migration {
+        Some(method) if !state.migrated => {
+            let outcome = migrate(&near, &method, migration_args, &mut state, state_path).await?;
+            let output = CommandOutput::transaction(&outcome, "Error while migrating the state");
+            done(&context, &mut steps, "migration", output)?;
+            wait_for_finality(&near, &steps).await?;
+            state.migrated = true;
+            write_state(state_path, &state)?;
+        }
+        _ => steps.push(skipped("migration")),
+    }
+
+    let version = version(&near).await?;
+
+    if version == state.previous_version {
+        return CommandOutput::default()
+            .failure(Error::Other(format!(
+                "The version {version} hasn't changed after the upgrade, the progress is kept in {}",
+                state_path.display()
+            )))
+            .print(&context.output_format);
+    }
+
+    std::fs::remove_file(state_path)?;
+
+    CommandOutput::value(json!({
+        "steps": steps,
+        "previous_version": state.previous_version,
+        "version": version,
+    }))?
+    .with_message(format!(
+        "{}\n\nThe engine has been upgraded from {} to {version}",
+        steps_table(&steps),
+        state.previous_version
+    ))
+    .print(&context.output_format)
+}
+
+async fn version(near: &NearClient) -> anyhow::Result<String> {
+    near.view_call("get_version", vec![])
+        .await
+        .and_then(String::from_result)
+}
+
+/// Call the migration method, saving the hash of its transaction before sending it. If the
+/// previous run has been interrupted after sending the migration, the outcome of the sent
+/// transaction is checked instead of calling the method again.
+async fn migrate(
+    near: &NearClient,
+    method: &str,
+    args: Vec<u8>,
+    state: &mut State,
+    state_path: &Path,
+) -> anyhow::Result<FinalExecutionOutcomeView> {
+    if let Some(hash) = state.migration_tx {
+        return near
+            .transaction_status(hash, TxExecutionStatus::Final)
+            .await?
+            .final_execution_outcome
+            .map(FinalExecutionOutcomeViewEnum::into_outcome)
+            .ok_or_else(|| {
+                Error::Rpc(format!("No outcome of the migration transaction {hash}")).into()
+            });
+    }
+
+    let transaction = near.sign_contract_call(method, args).await?;
+    state.migration_tx = Some(transaction.get_hash());
+    write_state(state_path, state)?;
+
+    near.broadcast_tx(transaction).await
+}
+
+/// Poll the final block height until it reaches the upgrade index, showing the progress.
+async fn wait_for_block(near: &NearClient, upgrade_index: u64) -> anyhow::Result<()> {
+    let mut stderr = std::io::stderr();
+    let mut waited = false;
+
+    loop {
+        let height = near.final_block_height().await?;
+
+        if height >= upgrade_index {
+            if waited {
+                writeln!(stderr)?;
+            }
+            return Ok(());
+        }
+
+        write!(
+            stderr,
+            "\rWaiting for the upgrade index {upgrade_index}: {} blocks left",
+            upgrade_index - height
+        )?;
+        stderr.flush()?;
+        waited = true;
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+/// Wait until the transaction of the last step is final, so the state read after it isn't stale:
+/// the transaction is returned once it's executed, and the view calls read the final block.
+async fn wait_for_finality(near: &NearClient, steps: &[Step]) -> anyhow::Result<()> {
+    if let Some(hash) = steps.last().and_then(|step| step.tx_hash) {
+        near.transaction_status(hash, TxExecutionStatus::Final)
+            .await?;
+    }
+
+    Ok(())
+}
+
+const fn skipped(name: &'static str) -> Step {
+    Step {
+        name,
+        status: StepStatus::Skipped,
+        tx_hash: None,
+    }
+}
+
+/// Record the step, or print the output of the failed transaction and return it as the error.
+fn done(
+    context: &Context,
+    steps: &mut Vec<Step>,
+    name: &'static str,
+    output: CommandOutput,
+) -> anyhow::Result<()> {
+    if output.status == Status::Failure {
+        return output.print(&context.output_format);
+    }
+
+    steps.push(Step {
+        name,
+        status: StepStatus::Done,
+        tx_hash: output.tx_hash,
+    });
+
+    Ok(())
+}
+
+fn read_state(
+    path: &Path,
+    near: &NearClient,
+    code_hash: CryptoHash,
+) -> anyhow::Result<Option<State>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let state: State = std::fs::read_to_string(path)
+        .map_err(|e| Error::Usage(format!("Couldn't read {}: {e}", path.display())))
+        .and_then(|json| {
+            serde_json::from_str(&json)
+                .map_err(|e| Error::Usage(format!("Wrong upgrade state {}: {e}", path.display())))
+        })?;
+
+    if state.engine != near.engine_account_id.as_str() || state.code_hash != code_hash {
+        return Err(Error::Usage(format!(
+            "The upgrade state {} is for the code {} on {}, remove it to start a new upgrade",
+            path.display(),
+            state.code_hash,
+            state.engine
+        ))
+        .into());
+    }
+
+    Ok(Some(state))
+}
+
+fn write_state(path: &Path, state: &State) -> anyhow::Result<()> {
+    std::fs::write(path, serde_json::to_string_pretty(state)?).map_err(Into::into)
+}
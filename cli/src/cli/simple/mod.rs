@@ -1,12 +1,23 @@
 use aurora_engine_types::account_id::AccountId;
 use aurora_engine_types::public_key::{KeyType, PublicKey};
-use clap::{Parser, Subcommand, ValueEnum};
+use aurora_sdk_rs::aurora::signer::EvmSigner;
+use aurora_sdk_rs::near::cassette::Cassette;
+use aurora_sdk_rs::near::delegate::DEFAULT_BLOCK_HEIGHT_TTL;
+use clap::{Args, Parser, Subcommand, ValueEnum};
 use near_primitives::hash::CryptoHash;
 use shadow_rs::shadow;
+use std::num::{NonZeroU32, NonZeroU64, NonZeroUsize};
+use std::path::PathBuf;
+use std::process::ExitCode;
 use std::str::FromStr;
 use std::sync::LazyLock;
 
-use crate::utils::near_to_yocto;
+use crate::cli::block::BlockArgs;
+use crate::cli::parse_address;
+use crate::error::{Error, ErrorKind, Reported};
+use crate::utils::pausable::{PausedFlags, PrecompileMask};
+use crate::utils::wasm::Contract;
+use crate::utils::{EvmSignerOptions, near_to_yocto};
 
 pub mod command;
 
@@ -28,17 +39,47 @@ pub struct Cli {
     #[arg(long, value_enum, default_value_t = Network::Localnet)]
     pub network: Network,
     /// Aurora EVM account
-    #[arg(long, value_name = "ACCOUNT_ID", default_value = "aurora")]
+    #[arg(
+        long,
+        value_name = "ACCOUNT_ID",
+        env = "AURORA_ENGINE",
+        default_value = "aurora"
+    )]
     pub engine: String,
+    /// Run the command on each of the comma-separated Aurora EVM accounts instead
+    #[arg(
+        long,
+        value_name = "ACCOUNT_ID,...",
+        value_delimiter = ',',
+        conflicts_with = "engines_file"
+    )]
+    pub engines: Vec<near_primitives::types::AccountId>,
+    /// Run the command on each of the Aurora EVM accounts listed in the file, one per line
+    #[arg(long, value_name = "PATH")]
+    pub engines_file: Option<PathBuf>,
+    /// Maximum number of engines, or blocks of `history`, the command runs on concurrently
+    #[arg(long, value_name = "N", default_value = "4")]
+    pub parallelism: NonZeroUsize,
     /// The way output of a command would be formatted
     #[arg(long, default_value = "plain")]
     pub output_format: OutputFormat,
     /// Path to file with NEAR account id and secret key in JSON format
     #[arg(long)]
     pub near_key_path: Option<String>,
-    /// NEAR block height to use for getting data
-    #[arg(long)]
-    pub block_height: Option<u64>,
+    #[command(flatten)]
+    pub block: BlockArgs,
+    /// Record NEAR RPC requests and responses into the directory
+    #[arg(long, value_name = "DIR", conflicts_with = "replay")]
+    pub record: Option<PathBuf>,
+    /// Replay NEAR RPC responses from the directory without network access
+    #[arg(long, value_name = "DIR")]
+    pub replay: Option<PathBuf>,
+    /// Log RPC calls with their latency, or with params and responses if repeated (-vv)
+    #[arg(short, long, action = clap::ArgAction::Count)]
+    pub verbose: u8,
+    /// Log RPC requests and responses to stderr, or append them to the file
+    #[arg(long, value_name = "FILE", num_args = 0..=1, require_equals = true, default_missing_value = "-")]
+    pub trace_rpc: Option<PathBuf>,
     #[clap(subcommand)]
     pub command: Command,
 }
@@ -53,12 +94,62 @@ pub enum Command {
         /// Initial account balance in NEAR
         #[arg(long, short)]
         balance: f64,
+        /// Key file of the parent account, if it differs from the signer
+        #[arg(long, value_name = "PATH")]
+        parent_key: Option<PathBuf>,
     },
     /// View NEAR account
     ViewAccount {
         /// `AccountId`
         account: String,
     },
+    /// Send NEAR to the account
+    SendNear {
+        /// Receiver of NEAR
+        receiver: near_primitives::types::AccountId,
+        /// Amount in NEAR
+        amount: f64,
+    },
+    /// Delete the signer's NEAR account and send its balance to the beneficiary
+    DeleteAccount {
+        /// Account receiving the balance of the deleted account
+        #[arg(long)]
+        beneficiary: near_primitives::types::AccountId,
+    },
+    /// Add an access key to the signer's account, with full access unless the receiver is set
+    AddKey {
+        /// Public key to add
+        public_key: near_crypto::PublicKey,
+        /// Contract the function-call key is allowed to call
+        #[arg(long, value_name = "ACCOUNT_ID")]
+        receiver: Option<near_primitives::types::AccountId>,
+        /// Comma-separated methods the function-call key is allowed to call, any if omitted
+        #[arg(long, value_delimiter = ',', requires = "receiver")]
+        method_names: Vec<String>,
+        /// Allowance of the function-call key in NEAR, unlimited if omitted
+        #[arg(long, requires = "receiver")]
+        allowance: Option<f64>,
+    },
+    /// Delete an access key of the signer's account
+    DeleteKey {
+        /// Public key to delete
+        public_key: near_crypto::PublicKey,
+    },
+    /// List access keys of the NEAR account
+    ListKeys {
+        /// `AccountId`
+        account: near_primitives::types::AccountId,
+    },
+    /// Set up Aurora EVM on a running local NEAR node
+    Localnet {
+        #[command(subcommand)]
+        command: LocalnetCommand,
+    },
+    /// Inspect WASM files before they are deployed
+    Wasm {
+        #[command(subcommand)]
+        command: WasmCommand,
+    },
     /// Deploy Aurora EVM smart contract
     DeployAurora {
         /// Path to the WASM file
@@ -103,6 +194,26 @@ pub enum Command {
         #[arg(short, long)]
         key: String,
     },
+    /// Write the value of the getter at every sampled block of the range in CSV or JSONL
+    History {
+        /// First block of the range
+        #[arg(long)]
+        from_block: u64,
+        /// Last block of the range
+        #[arg(long)]
+        to_block: u64,
+        /// Sample every N-th block
+        #[arg(long, value_name = "N", default_value = "1")]
+        step: NonZeroU64,
+        /// Format of the samples
+        #[arg(long, value_enum, default_value_t = command::history::Format::Csv)]
+        format: command::history::Format,
+        /// Maximum number of requests per second, unlimited if omitted
+        #[arg(long, value_name = "N")]
+        rate_limit: Option<NonZeroU32>,
+        #[command(subcommand)]
+        getter: command::history::Getter,
+    },
     /// Register relayer address
     RegisterRelayer { address: String },
     /// Start hashchain
@@ -119,11 +230,22 @@ pub enum Command {
     /// Resume contract
     ResumeContract,
     /// Pause precompiles
-    PausePrecompiles { mask: u32 },
+    PausePrecompiles {
+        /// Comma-separated precompiles: exit-to-near, exit-to-ethereum, or all, or the mask
+        mask: PrecompileMask,
+    },
     /// Resume precompiles
-    ResumePrecompiles { mask: u32 },
+    ResumePrecompiles {
+        /// Comma-separated precompiles: exit-to-near, exit-to-ethereum, or all, or the mask
+        mask: PrecompileMask,
+    },
     /// Return paused precompiles
     GetPausedPrecompiles,
+    /// Pause everything at once or restore the state from before the pause
+    Emergency {
+        #[command(subcommand)]
+        command: EmergencyCommand,
+    },
     /// Updates the bytecode for user's router contracts
     FactoryUpdate { path: String },
     /// Return the address of the `wNEAR` ERC-20 contract
@@ -140,11 +262,23 @@ pub enum Command {
         deposit: f64,
     },
     /// Upgrade contract with provided code
-    Upgrade { path: String },
+    #[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+    Upgrade {
+        /// Path to the WASM file with the new code
+        #[arg(required = true)]
+        path: Option<String>,
+        #[command(subcommand)]
+        command: Option<UpgradeCommand>,
+    },
     /// Stage a new code for upgrade
     StageUpgrade { path: String },
     /// Deploy staged upgrade
     DeployUpgrade,
+    /// Publish the engine or XCC router code as a NEAR global contract and switch accounts to it
+    GlobalContract {
+        #[command(subcommand)]
+        command: GlobalContractCommand,
+    },
     /// Deploy EVM smart contract's code in hex
     Deploy {
         /// Code in HEX to deploy
@@ -156,9 +290,14 @@ pub enum Command {
         /// Path to ABI of the contract
         #[arg(long)]
         abi_path: Option<String>,
-        /// Aurora EVM secret key
+        /// Salt in hex to deploy the code with `CREATE2`
         #[arg(long)]
-        aurora_secret_key: Option<String>,
+        create2_salt: Option<String>,
+        /// `CREATE2` factory, the deployment proxy by default
+        #[arg(long, requires = "create2_salt")]
+        factory: Option<String>,
+        #[command(flatten)]
+        signer: EvmSignerArgs,
     },
     /// Call a method of the smart contract
     Call {
@@ -210,12 +349,21 @@ pub enum Command {
         /// Value sending in EVM transaction
         #[arg(long)]
         value: Option<String>,
-        /// Aurora EVM secret key
-        #[arg(long)]
-        aurora_secret_key: Option<String>,
+        #[command(flatten)]
+        signer: EvmSignerArgs,
     },
     /// Encode address
     EncodeAddress { account: String },
+    /// Address utilities: EIP-55 checksums and derivation of addresses
+    Address {
+        #[command(subcommand)]
+        command: AddressCommand,
+    },
+    /// Delegate (meta) transactions paid by a relayer (NEP-366)
+    MetaTx {
+        #[command(subcommand)]
+        command: MetaTxCommand,
+    },
     /// Return Public and Secret ED25519 keys
     KeyPair {
         /// Random
@@ -225,6 +373,43 @@ pub enum Command {
         #[arg(long)]
         seed: Option<u64>,
     },
+    /// Sign a message according to EIP-191 (`personal_sign`)
+    SignMessage {
+        /// Message to sign
+        message: String,
+        /// The message is encoded in hex
+        #[arg(long, default_value_t = false)]
+        hex: bool,
+        /// Aurora EVM secret key
+        #[arg(long)]
+        aurora_secret_key: Option<String>,
+    },
+    /// Sign typed structured data according to EIP-712
+    SignTypedData {
+        /// Typed data in JSON or path to the JSON file
+        data: String,
+        /// Aurora EVM secret key
+        #[arg(long)]
+        aurora_secret_key: Option<String>,
+    },
+    /// Recover the signer of an EIP-191 message or EIP-712 typed data
+    VerifySignature {
+        /// Signature in hex
+        #[arg(long)]
+        signature: String,
+        /// Signed message
+        #[arg(long, conflicts_with = "typed_data")]
+        message: Option<String>,
+        /// The message is encoded in hex
+        #[arg(long, default_value_t = false)]
+        hex: bool,
+        /// Signed typed data in JSON or path to the JSON file
+        #[arg(long)]
+        typed_data: Option<String>,
+        /// Expected address of the signer
+        #[arg(long)]
+        address: Option<String>,
+    },
     /// Return randomly generated NEAR key for `AccountId`
     GenerateNearKey {
         /// `AccountId`
@@ -309,6 +494,11 @@ pub enum Command {
         /// Public key
         public_key: PublicKey,
     },
+    /// List and rotate relayer keys
+    RelayerKeys {
+        #[command(subcommand)]
+        command: RelayerKeysCommand,
+    },
     /// Get delay for upgrade in blocks
     GetUpgradeDelayBlocks,
     /// Set delay for upgrade in blocks
@@ -389,8 +579,8 @@ pub enum Command {
     },
     /// Set eth connector paused flags
     SetPausedFlags {
-        /// Pause mask
-        mask: u8,
+        /// Comma-separated paused features: deposit, withdraw, or all, none, or the mask
+        mask: PausedFlags,
     },
     /// Get eth connector paused flags
     GetPausedFlags,
@@ -415,6 +605,226 @@ pub enum Command {
     },
 }
 
+#[derive(Subcommand)]
+pub enum AddressCommand {
+    /// Return the address in the EIP-55 checksum encoding
+    Checksum {
+        /// Address in hex
+        address: String,
+    },
+    /// Validate the EIP-55 checksum of the address
+    Validate {
+        /// Address in hex
+        address: String,
+    },
+    /// Return the address of the contract deployed by the sender with the nonce (`CREATE`)
+    Create {
+        /// Address of the deployer
+        #[arg(long)]
+        sender: String,
+        /// Nonce of the deployer
+        #[arg(long)]
+        nonce: u64,
+    },
+    /// Return the address of the contract deployed by the sender with the salt (`CREATE2`)
+    Create2 {
+        /// Address of the deployer, e.g. a factory contract
+        #[arg(long)]
+        sender: String,
+        /// Salt in hex, 32 bytes
+        #[arg(long)]
+        salt: String,
+        /// Init code in hex
+        #[arg(long, conflicts_with = "init_code_hash")]
+        init_code: Option<String>,
+        /// Keccak256 hash of the init code in hex
+        #[arg(long)]
+        init_code_hash: Option<String>,
+    },
+    /// Return the account id of the XCC router sub-account for the address
+    XccRouter {
+        /// Address in hex
+        address: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum EmergencyCommand {
+    /// Save the paused precompiles and ETH connector flags into the snapshot and pause the
+    /// contract, all precompiles and the ETH connector
+    Pause {
+        /// File with the state of the engine before the pause
+        #[arg(long, default_value = "emergency-snapshot.json")]
+        snapshot: PathBuf,
+    },
+    /// Resume the contract and restore the precompiles and ETH connector flags from the snapshot
+    Resume {
+        /// File with the state of the engine before the pause
+        #[arg(long, default_value = "emergency-snapshot.json")]
+        snapshot: PathBuf,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum LocalnetCommand {
+    /// Create the accounts, deploy, initialize and configure the engine, add a relayer key, and
+    /// write the keys and a profile. The steps which have been done already are skipped
+    Bootstrap {
+        /// Path to the Aurora EVM WASM file
+        #[arg(long, value_name = "PATH")]
+        engine_wasm: PathBuf,
+        /// Path to the XCC router WASM file, the router isn't updated if omitted
+        #[arg(long, value_name = "PATH")]
+        xcc_router_wasm: Option<PathBuf>,
+        /// TOML file with the accounts, balances and engine parameters, the defaults if omitted
+        #[arg(long, value_name = "PATH")]
+        config: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum WasmCommand {
+    /// Print the exported methods, the engine version, SHA-256 and NEAR code hash of the WASM
+    /// file, and whether it looks like the engine or the XCC router
+    Inspect {
+        /// Path to the WASM file
+        path: PathBuf,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum UpgradeCommand {
+    /// Stage the code, wait for the upgrade index, deploy the upgrade, migrate the state and check
+    /// the new version. The interrupted upgrade is resumed from the step it has stopped at
+    Run {
+        /// Path to the WASM file with the new code
+        #[arg(long, value_name = "PATH")]
+        wasm: PathBuf,
+        /// Method of the new code to call after the upgrade to migrate the state
+        #[arg(long, value_name = "METHOD")]
+        migration: Option<String>,
+        /// Arguments of the migration method in JSON or in hex with the 0x prefix
+        #[arg(long, value_name = "ARGS", requires = "migration")]
+        migration_args: Option<String>,
+        /// File with the progress of the upgrade, removed once the upgrade is done
+        #[arg(long, value_name = "PATH", default_value = "upgrade-state.json")]
+        state: PathBuf,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum GlobalContractCommand {
+    /// Publish the WASM file as a global contract from the signer's account
+    Deploy {
+        /// Path to the WASM file
+        path: PathBuf,
+        /// Contract the WASM file is checked to be
+        #[arg(long, value_enum, default_value_t = Contract::Engine)]
+        contract: Contract,
+        /// Identify the global contract by the hash of its code or by the signer's account
+        #[arg(long, value_enum, default_value_t = command::global_contract::DeployMode::CodeHash)]
+        mode: command::global_contract::DeployMode,
+    },
+    /// Replace the code of the engine account with the global contract. The key must be a full
+    /// access key of the engine account
+    Use {
+        /// Hash of the global contract deployed by the hash
+        #[arg(
+            long,
+            conflicts_with = "account_id",
+            required_unless_present = "account_id"
+        )]
+        code_hash: Option<CryptoHash>,
+        /// Account which has deployed the global contract by the account id
+        #[arg(long, value_name = "ACCOUNT_ID", required_unless_present = "code_hash")]
+        account_id: Option<near_primitives::types::AccountId>,
+    },
+    /// Show the global contract the account uses
+    Get {
+        /// Account to check, the engine account if omitted
+        account: Option<near_primitives::types::AccountId>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum RelayerKeysCommand {
+    /// List function-call keys of the engine used by relayers with their allowance left
+    List,
+    /// Add a new relayer key, wait until it is final, replace the key file and remove the old key
+    Rotate {
+        /// Key file of the relayer with the key to rotate
+        #[arg(long)]
+        key_file: PathBuf,
+        /// Allowance of the new key in NEAR
+        #[arg(long)]
+        allowance: f64,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum MetaTxCommand {
+    /// Sign a call of the engine's method as a delegate action and print it in base64
+    Create {
+        /// Name of the method to call
+        #[arg(long)]
+        method: String,
+        /// Arguments of the method in JSON or in hex with the 0x prefix
+        #[arg(long)]
+        args: Option<String>,
+        /// Gas for the call in `TGas`
+        #[arg(long, default_value_t = 100)]
+        gas: u64,
+        /// Attached deposit in NEAR
+        #[arg(long, default_value_t = 0.0)]
+        deposit: f64,
+        /// Number of blocks during which the delegate action is valid
+        #[arg(long, default_value_t = DEFAULT_BLOCK_HEIGHT_TTL)]
+        ttl: u64,
+    },
+    /// Submit the signed delegate action and pay for the gas
+    Relay {
+        /// Signed delegate action in base64
+        delegate_action: String,
+    },
+}
+
+/// Signer of EVM transactions: a secret key, a keystore file or a remote signing service.
+#[derive(Args)]
+pub struct EvmSignerArgs {
+    /// Aurora EVM secret key
+    #[arg(long, group = "evm_signer")]
+    pub aurora_secret_key: Option<String>,
+    /// Path to the encrypted JSON keystore file
+    #[arg(long, group = "evm_signer")]
+    pub aurora_keystore: Option<String>,
+    /// Keystore password or `AURORA_KEYSTORE_PASSWORD` env
+    #[arg(long, env = "AURORA_KEYSTORE_PASSWORD", hide_env = true)]
+    pub keystore_password: Option<String>,
+    /// Remote signer URL: http(s):// or unix://
+    #[arg(long, group = "evm_signer", requires = "signer_address")]
+    pub remote_signer: Option<String>,
+    /// EVM address of the remote signer's account
+    #[arg(long, requires = "remote_signer")]
+    pub signer_address: Option<String>,
+}
+
+impl EvmSignerArgs {
+    pub fn into_signer(self) -> anyhow::Result<Box<dyn EvmSigner>> {
+        EvmSignerOptions {
+            secret_key: self.aurora_secret_key,
+            keystore: self.aurora_keystore,
+            keystore_password: self.keystore_password,
+            remote_signer: self.remote_signer,
+            signer_address: self
+                .signer_address
+                .as_deref()
+                .map(parse_address)
+                .transpose()?,
+        }
+        .into_signer()
+    }
+}
+
 #[derive(Debug, Clone, ValueEnum)]
 pub enum Network {
     Localnet,
@@ -461,11 +871,48 @@ impl FromStr for WithdrawSerialization {
     }
 }
 
-#[allow(clippy::too_many_lines)]
+/// Print the error in the output format and return the exit code matching the kind of the error.
+/// The failure already printed by a command as its output is printed once more in the plain
+/// format only.
+#[must_use]
+pub fn report(error: &anyhow::Error, format: &OutputFormat) -> ExitCode {
+    if matches!(format, OutputFormat::Plain) {
+        return crate::error::report(error);
+    }
+
+    if !error.chain().any(<dyn std::error::Error>::is::<Reported>)
+        && command::output::CommandOutput::error(error)
+            .print(format)
+            .is_err()
+    {
+        return crate::error::report(error);
+    }
+
+    ExitCode::from(ErrorKind::of(error).exit_code())
+}
+
+#[allow(clippy::too_many_lines, clippy::large_stack_frames)]
 pub async fn run(args: Cli) -> anyhow::Result<()> {
+    crate::utils::trace::init(args.verbose, args.trace_rpc.as_deref())?;
+
     let near_rpc = parse_near_rpc(&args.network)?;
-    let client = crate::client::Client::new(&near_rpc, &args.engine, args.near_key_path);
-    let context = crate::client::Context::new(client, args.output_format, args.block_height);
+    let mut client = crate::client::Client::new(&near_rpc, &args.engine, args.near_key_path)?
+        .with_block_reference(args.block.block_reference());
+
+    if let Some(cassette) = parse_cassette(args.record, args.replay)? {
+        client = client.with_cassette(cassette);
+    }
+
+    let context = crate::client::Context::new(client, args.output_format);
+    let engines = match args.engines_file {
+        Some(path) => command::engines::read_engines(path)?,
+        None => args.engines,
+    };
+
+    if !engines.is_empty() {
+        let command = command::engines::EngineCommand::try_from(args.command)?;
+        return command::engines::run(context, engines, command, args.parallelism).await;
+    }
 
     match args.command {
         Command::GetChainId => command::get_chain_id(context).await?,
@@ -490,8 +937,9 @@ pub async fn run(args: Cli) -> anyhow::Result<()> {
             args,
             abi_path,
             value,
-            aurora_secret_key,
+            signer,
         } => {
+            let signer = signer.into_signer()?;
             command::submit(
                 context,
                 address,
@@ -499,7 +947,7 @@ pub async fn run(args: Cli) -> anyhow::Result<()> {
                 args,
                 abi_path,
                 value,
-                aurora_secret_key.as_deref(),
+                signer.as_ref(),
             )
             .await?;
         }
@@ -519,6 +967,14 @@ pub async fn run(args: Cli) -> anyhow::Result<()> {
         Command::PausePrecompiles { mask } => command::pause_precompiles(context, mask).await?,
         Command::ResumePrecompiles { mask } => command::resume_precompiles(context, mask).await?,
         Command::GetPausedPrecompiles => command::get_paused_precompiles(context).await?,
+        Command::Emergency { command } => match command {
+            EmergencyCommand::Pause { snapshot } => {
+                command::emergency::pause(context, snapshot).await?;
+            }
+            EmergencyCommand::Resume { snapshot } => {
+                command::emergency::resume(context, snapshot).await?;
+            }
+        },
         Command::GetUpgradeIndex => command::get_upgrade_index(context).await?,
         Command::FactoryUpdate { path } => command::factory_update(context, path).await?,
         Command::FactoryGetWnearAddress => command::factory_get_wnear_address(context).await?,
@@ -532,26 +988,155 @@ pub async fn run(args: Cli) -> anyhow::Result<()> {
         } => {
             command::fund_xcc_sub_account(context, target, wnear_account_id, deposit).await?;
         }
-        Command::Upgrade { path } => command::upgrade(context, path).await?,
+        Command::Upgrade { path, command } => match (path, command) {
+            (
+                _,
+                Some(UpgradeCommand::Run {
+                    wasm,
+                    migration,
+                    migration_args,
+                    state,
+                }),
+            ) => command::upgrade::run(context, wasm, migration, migration_args, state).await?,
+            (Some(path), None) => command::upgrade(context, path).await?,
+            (None, None) => {
+                return Err(Error::Usage("Path to the WASM file is required".to_string()).into());
+            }
+        },
         Command::StageUpgrade { path } => command::stage_upgrade(context, path).await?,
         Command::DeployUpgrade => command::deploy_upgrade(context).await?,
+        Command::GlobalContract { command } => match command {
+            GlobalContractCommand::Deploy {
+                path,
+                contract,
+                mode,
+            } => command::global_contract::deploy(context, path, contract, mode).await?,
+            GlobalContractCommand::Use {
+                code_hash,
+                account_id,
+            } => {
+                command::global_contract::use_global_contract(context, code_hash, account_id)
+                    .await?;
+            }
+            GlobalContractCommand::Get { account } => {
+                command::global_contract::get(context, account).await?;
+            }
+        },
         Command::GetStorageAt { address, key } => {
             command::get_storage_at(context, address, key).await?;
         }
+        Command::History {
+            from_block,
+            to_block,
+            step,
+            format,
+            rate_limit,
+            getter,
+        } => {
+            if args.block.block_height.is_some()
+                || args.block.block_hash.is_some()
+                || args.block.finality.is_some()
+            {
+                return Err(Error::Usage(
+                    "--block-height, --block-hash and --finality can't be used with history"
+                        .to_string(),
+                )
+                .into());
+            }
+
+            let range = command::history::BlockRange {
+                from: from_block,
+                to: to_block,
+                step,
+            };
+            command::history::run(
+                context,
+                getter,
+                range,
+                format,
+                args.parallelism,
+                rate_limit,
+                &mut std::io::stdout(),
+            )
+            .await?;
+        }
         Command::Deploy {
             code,
             abi_path,
             args,
-            aurora_secret_key,
+            create2_salt,
+            factory,
+            signer,
         } => {
-            command::deploy_evm_code(context, code, abi_path, args, aurora_secret_key.as_deref())
-                .await?;
+            let signer = signer.into_signer()?;
+            command::deploy_evm_code(
+                context,
+                code,
+                abi_path,
+                args,
+                create2_salt,
+                factory,
+                signer.as_ref(),
+            )
+            .await?;
         }
         Command::DeployAurora { path } => command::deploy_aurora(context, path).await?,
-        Command::CreateAccount { account, balance } => {
-            command::create_account(context, &account, balance).await?;
+        Command::CreateAccount {
+            account,
+            balance,
+            parent_key,
+        } => {
+            command::create_account(context, &account, balance, parent_key.as_deref()).await?;
         }
         Command::ViewAccount { account } => command::view_account(context, &account).await?,
+        Command::SendNear { receiver, amount } => {
+            command::account::send_near(context, receiver, amount).await?;
+        }
+        Command::DeleteAccount { beneficiary } => {
+            command::account::delete_account(context, beneficiary).await?;
+        }
+        Command::AddKey {
+            public_key,
+            receiver,
+            method_names,
+            allowance,
+        } => {
+            let permission =
+                receiver.map_or(command::account::KeyPermission::FullAccess, |receiver| {
+                    command::account::KeyPermission::FunctionCall {
+                        receiver,
+                        method_names,
+                        allowance,
+                    }
+                });
+            command::account::add_key(context, public_key, permission).await?;
+        }
+        Command::DeleteKey { public_key } => {
+            command::account::delete_key(context, public_key).await?;
+        }
+        Command::ListKeys { account } => command::account::list_keys(context, account).await?,
+        Command::Localnet { command } => match command {
+            LocalnetCommand::Bootstrap {
+                engine_wasm,
+                xcc_router_wasm,
+                config,
+            } => {
+                if !matches!(args.network, Network::Localnet) {
+                    return Err(Error::Usage(
+                        "localnet bootstrap works with --network localnet only".to_string(),
+                    )
+                    .into());
+                }
+
+                let config = command::localnet::Config::read(config)?;
+                command::localnet::bootstrap(context, engine_wasm, xcc_router_wasm, config).await?;
+            }
+        },
+        Command::Wasm { command } => match command {
+            WasmCommand::Inspect { path } => {
+                command::inspect_wasm(path, &context.output_format)?;
+            }
+        },
         Command::Init {
             chain_id,
             owner_id,
@@ -559,12 +1144,86 @@ pub async fn run(args: Cli) -> anyhow::Result<()> {
         } => {
             command::init(context, chain_id, owner_id, upgrade_delay_blocks).await?;
         }
-        Command::EncodeAddress { account } => command::encode_address(&account),
-        Command::KeyPair { random, seed } => command::key_pair(random, seed)?,
+        Command::EncodeAddress { account } => {
+            command::encode_address(&account, &context.output_format)?;
+        }
+        Command::Address { command } => match command {
+            AddressCommand::Checksum { address } => {
+                command::address::checksum(&address, &context.output_format)?;
+            }
+            AddressCommand::Validate { address } => {
+                command::address::validate(&address, &context.output_format)?;
+            }
+            AddressCommand::Create { sender, nonce } => {
+                command::address::create(&sender, nonce, &context.output_format)?;
+            }
+            AddressCommand::Create2 {
+                sender,
+                salt,
+                init_code,
+                init_code_hash,
+            } => command::address::create2(
+                &sender,
+                &salt,
+                init_code,
+                init_code_hash,
+                &context.output_format,
+            )?,
+            AddressCommand::XccRouter { address } => {
+                command::address::xcc_router(&args.engine, &address, &context.output_format)?;
+            }
+        },
+        Command::MetaTx { command } => match command {
+            MetaTxCommand::Create {
+                method,
+                args,
+                gas,
+                deposit,
+                ttl,
+            } => command::meta_tx::create(context, &method, args, gas, deposit, ttl).await?,
+            MetaTxCommand::Relay { delegate_action } => {
+                command::meta_tx::relay(context, &delegate_action).await?;
+            }
+        },
+        Command::KeyPair { random, seed } => {
+            command::key_pair(random, seed, &context.output_format)?;
+        }
         Command::GenerateNearKey {
             account_id,
             key_type,
-        } => command::gen_near_key(&account_id, key_type)?,
+        } => command::gen_near_key(&account_id, key_type, &context.output_format)?,
+        Command::SignMessage {
+            message,
+            hex,
+            aurora_secret_key,
+        } => command::sign::sign_message(
+            &message,
+            hex,
+            aurora_secret_key.as_deref(),
+            &context.output_format,
+        )?,
+        Command::SignTypedData {
+            data,
+            aurora_secret_key,
+        } => command::sign::sign_typed_data(
+            &data,
+            aurora_secret_key.as_deref(),
+            &context.output_format,
+        )?,
+        Command::VerifySignature {
+            signature,
+            message,
+            hex,
+            typed_data,
+            address,
+        } => command::sign::verify_signature(
+            &signature,
+            message,
+            hex,
+            typed_data,
+            address,
+            &context.output_format,
+        )?,
         // Silo Specific Methods
         Command::GetFixedGas => command::silo::get_fixed_gas_cost(context).await?,
         Command::SetFixedGas { cost } => {
@@ -607,6 +1266,13 @@ pub async fn run(args: Cli) -> anyhow::Result<()> {
         Command::RemoveRelayerKey { public_key } => {
             command::remove_relayer_key(context, public_key).await?;
         }
+        Command::RelayerKeys { command } => match command {
+            RelayerKeysCommand::List => command::relayer_keys::list(context).await?,
+            RelayerKeysCommand::Rotate {
+                key_file,
+                allowance,
+            } => command::relayer_keys::rotate(context, key_file, allowance).await?,
+        },
         Command::GetUpgradeDelayBlocks => {
             command::get_upgrade_delay_blocks(context).await?;
         }
@@ -695,6 +1361,21 @@ fn parse_account_id(arg: &str) -> anyhow::Result<AccountId> {
     arg.parse().map_err(|e| anyhow::anyhow!("{e}"))
 }
 
+fn parse_cassette(
+    record: Option<PathBuf>,
+    replay: Option<PathBuf>,
+) -> anyhow::Result<Option<Cassette>> {
+    let cassette = match (record, replay) {
+        (Some(dir), _) => Some(Cassette::record(&dir)),
+        (None, Some(dir)) => Some(Cassette::replay(&dir)),
+        (None, None) => None,
+    };
+
+    cassette
+        .transpose()
+        .map_err(|e| anyhow::anyhow!("Cassette error: {e}"))
+}
+
 fn parse_near_rpc(network: &Network) -> anyhow::Result<String> {
     std::env::var("NEAR_RPC_URL").or_else(|_| {
         let endpoint = match network {
@@ -4,13 +4,17 @@ use aurora_engine_types::{
     account_id::AccountId,
     types::{Address, Wei},
 };
-use libsecp256k1::SecretKey;
+use aurora_sdk_rs::aurora::signer::EvmSigner;
+use aurora_sdk_rs::near::trace::origin;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::fmt::Formatter;
+use std::time::Instant;
 use thiserror::Error;
 
+use crate::error::Error as CliError;
 use crate::eth_method::EthMethod;
+use crate::utils::trace::AURORA_RPC;
 
 use super::{ClientError, TransactionOutcome};
 
@@ -25,24 +29,43 @@ pub struct AuroraClient {
 
 impl AuroraClient {
     #[allow(clippy::used_underscore_binding)]
-    #[must_use]
-    pub fn new(url: &str, near_url: &str, engine_account: &str) -> Self {
-        let inner = reqwest::Client::new();
-        Self {
-            inner,
+    pub fn new(url: &str, near_url: &str, engine_account: &str) -> anyhow::Result<Self> {
+        let engine_account_id = engine_account.parse().map_err(|e| {
+            CliError::Usage(format!("Invalid engine account id {engine_account}: {e}"))
+        })?;
+
+        Ok(Self {
+            inner: reqwest::Client::new(),
             url: url.to_string(),
             near_url: near_url.to_string(),
-            engine_account_id: engine_account.parse().expect("couldn't parse engine id"),
-        }
+            engine_account_id,
+        })
     }
 
     pub async fn request<S: Serialize + Send + Sync>(
         &self,
         request: &Web3JsonRequest<'_, S>,
     ) -> anyhow::Result<Web3JsonResponse<Value>> {
-        let resp = self.inner.post(&self.url).json(request).send().await?;
-        // TODO: parse information from headers too (eg x-request-id)
-        let full = resp.bytes().await?;
+        let endpoint = origin(&self.url);
+        if tracing::enabled!(target: AURORA_RPC, tracing::Level::TRACE) {
+            let params = serde_json::to_string(&request.params)?;
+            tracing::trace!(target: AURORA_RPC, method = request.method, endpoint, %params, "request");
+        }
+
+        let started = Instant::now();
+        let result = async {
+            let resp = self.inner.post(&self.url).json(request).send().await?;
+            // TODO: parse information from headers too (eg x-request-id)
+            resp.bytes().await
+        }
+        .await;
+        let latency = started.elapsed();
+        let full = result.inspect_err(|error| {
+            tracing::debug!(target: AURORA_RPC, method = request.method, endpoint, ?latency, %error, "error");
+        })?;
+
+        tracing::debug!(target: AURORA_RPC, method = request.method, endpoint, ?latency, "ok");
+        tracing::trace!(target: AURORA_RPC, response = %String::from_utf8_lossy(&full), "response");
 
         serde_json::from_slice(&full).map_err(|_| {
             let text = match String::from_utf8_lossy(&full) {
@@ -62,8 +85,7 @@ impl AuroraClient {
             return Err(e.into());
         }
 
-        let value = response.result.as_ref().and_then(Value::as_str).unwrap();
-        Ok(U256::from_str_radix(value, 16).unwrap())
+        parse_quantity(result_str(&response)?)
     }
 
     #[cfg(feature = "simple")]
@@ -76,8 +98,7 @@ impl AuroraClient {
             return Err(e.into());
         }
 
-        let value = response.result.as_ref().and_then(Value::as_str).unwrap();
-        Ok(U256::from_str_radix(value, 16).unwrap())
+        parse_quantity(result_str(&response)?)
     }
 
     #[cfg(feature = "simple")]
@@ -90,12 +111,7 @@ impl AuroraClient {
             return Err(e.into());
         }
 
-        Ok(response
-            .result
-            .as_ref()
-            .and_then(Value::as_str)
-            .map(str::to_string)
-            .unwrap())
+        result_str(&response).map(str::to_string)
     }
 
     pub async fn get_chain_id(&self) -> anyhow::Result<u64> {
@@ -107,35 +123,36 @@ impl AuroraClient {
             return Err(e.into());
         }
 
-        let value = response.result.as_ref().and_then(Value::as_str).unwrap();
-        Ok(value.parse().unwrap())
+        parse_quantity(result_str(&response)?).map(|chain_id| chain_id.low_u64())
     }
 
     pub(crate) async fn send_and_wait_transaction(
         &self,
-        sk: &SecretKey,
+        signer: &dyn EvmSigner,
         to: Option<Address>,
         amount: Wei,
         input: Vec<u8>,
     ) -> anyhow::Result<()> {
-        let source = crate::utils::address_from_secret_key(sk)?;
+        let source = signer.address();
         println!("FROM {source:?}");
 
         let nonce = self.get_nonce(source).await?;
         let chain_id = self.get_chain_id().await?;
         let tx_hash = self
-            .send_eth_transaction(to, amount, sk, chain_id, nonce, input)
+            .send_eth_transaction(to, amount, signer, chain_id, nonce, input)
             .await?;
 
         // Wait for the RPC to pick up the transaction
-        loop {
+        for attempt in 1.. {
             match self.get_transaction_outcome(tx_hash).await {
                 Ok(result) => {
                     println!("{result:?}");
                     break;
                 }
                 Err(e) => match e.downcast_ref::<ClientError>() {
-                    Some(ClientError::AuroraTransactionNotFound(_)) => {}
+                    Some(ClientError::AuroraTransactionNotFound(_)) => {
+                        tracing::debug!(target: AURORA_RPC, ?tx_hash, attempt, "retry");
+                    }
                     _ => anyhow::bail!(e),
                 },
             }
@@ -149,7 +166,7 @@ impl AuroraClient {
         &self,
         target: Option<Address>,
         amount: Wei,
-        signer: &SecretKey,
+        signer: &dyn EvmSigner,
         chain_id: u64,
         nonce: U256,
         data: Vec<u8>,
@@ -162,8 +179,7 @@ impl AuroraClient {
             value: amount,
             data,
         };
-        let signed_tx =
-            EthTransactionKind::Legacy(crate::utils::sign_transaction(tx, chain_id, signer));
+        let signed_tx = EthTransactionKind::Legacy(signer.sign_transaction(tx, chain_id).await?);
         let method = EthMethod::SendRawTransaction(Box::new(signed_tx));
         let request = Web3JsonRequest::from_method(1, &method);
         let response = self.request(&request).await?;
@@ -172,12 +188,13 @@ impl AuroraClient {
             return Err(ClientError::AuroraRpc(e).into());
         }
 
-        let tx_hash = response.result.as_ref().and_then(Value::as_str).unwrap();
-        let tx_hash_bytes = tx_hash
+        let tx_hash = result_str(&response)?;
+        tx_hash
             .strip_prefix("0x")
             .and_then(|x| hex::decode(x).ok())
-            .unwrap();
-        Ok(H256::from_slice(&tx_hash_bytes))
+            .filter(|bytes| bytes.len() == 32)
+            .map(|bytes| H256::from_slice(&bytes))
+            .ok_or_else(|| CliError::Rpc(format!("Invalid transaction hash: {tx_hash}")).into())
     }
 
     pub async fn get_transaction_outcome(
@@ -205,16 +222,32 @@ impl AuroraClient {
             .as_str()
             .ok_or_else(|| ClientError::NotJsonString(near_tx_value.clone()))?;
         let near_rx_hex = near_tx_str.strip_prefix("0x").unwrap_or(near_tx_str);
-        let near_receipt_id = hex::decode(near_rx_hex)?;
+        let near_receipt_id: near_primitives::hash::CryptoHash = hex::decode(near_rx_hex)?
+            .as_slice()
+            .try_into()
+            .map_err(|_| CliError::Rpc(format!("Invalid NEAR receipt id: {near_tx_str}")))?;
         let near_client =
-            super::NearClient::new(&self.near_url, self.engine_account_id.as_ref(), None);
+            super::NearClient::new(&self.near_url, self.engine_account_id.as_ref(), None)?;
 
-        near_client
-            .get_receipt_outcome(near_receipt_id.as_slice().try_into().unwrap())
-            .await
+        near_client.get_receipt_outcome(near_receipt_id).await
     }
 }
 
+/// The string result of the successful response.
+fn result_str(response: &Web3JsonResponse<Value>) -> anyhow::Result<&str> {
+    response
+        .result
+        .as_ref()
+        .and_then(Value::as_str)
+        .ok_or_else(|| CliError::Rpc(format!("Unexpected result: {:?}", response.result)).into())
+}
+
+/// Number encoded in hex with the `0x` prefix.
+fn parse_quantity(value: &str) -> anyhow::Result<U256> {
+    U256::from_str_radix(value.strip_prefix("0x").unwrap_or(value), 16)
+        .map_err(|e| CliError::Rpc(format!("Invalid number {value}: {e}")).into())
+}
+
 #[derive(Debug, Serialize)]
 pub struct Web3JsonRequest<'a, T> {
     jsonrpc: &'static str,
@@ -1,7 +1,5 @@
 #[cfg(feature = "advanced")]
 use aurora_engine_types::H256;
-#[cfg(feature = "simple")]
-use aurora_engine_types::account_id::AccountId;
 #[cfg(feature = "advanced")]
 use aurora_engine_types::parameters::engine::SubmitResult;
 #[cfg(feature = "advanced")]
@@ -13,6 +11,12 @@ pub use near::NearClient;
 
 #[cfg(feature = "simple")]
 use crate::cli::simple::OutputFormat;
+#[cfg(feature = "simple")]
+use aurora_sdk_rs::near::cassette::Cassette;
+#[cfg(feature = "simple")]
+use near_primitives::types::{BlockReference, Finality};
+#[cfg(feature = "simple")]
+use std::sync::Arc;
 
 #[cfg(feature = "advanced")]
 mod aurora;
@@ -30,52 +34,73 @@ type NearCallError = near_jsonrpc_client::errors::JsonRpcError<
 pub struct Context {
     pub client: Client,
     pub output_format: OutputFormat,
-    pub block_height: Option<u64>,
 }
 
 #[cfg(feature = "simple")]
 impl Context {
     #[must_use]
-    pub const fn new(
-        client: Client,
-        output_format: OutputFormat,
-        block_height: Option<u64>,
-    ) -> Self {
+    pub const fn new(client: Client, output_format: OutputFormat) -> Self {
         Self {
             client,
             output_format,
-            block_height,
         }
     }
 }
 
 #[cfg(feature = "simple")]
 pub struct Client {
-    near_rpc: String,
+    near: NearClient,
     #[cfg(feature = "advanced")]
     aurora_rpc: String,
-    engine_account_id: AccountId,
-    signer_key_path: Option<String>,
+    cassette: Option<Arc<Cassette>>,
+    block_reference: BlockReference,
 }
 
 #[cfg(feature = "simple")]
 impl Client {
+    pub fn new(
+        near_rpc: &str,
+        engine_account: &str,
+        signer_key_path: Option<String>,
+    ) -> anyhow::Result<Self> {
+        Ok(Self {
+            near: NearClient::new(near_rpc, engine_account, signer_key_path)?,
+            cassette: None,
+            block_reference: Finality::Final.into(),
+        })
+    }
+
+    /// Record the NEAR RPC traffic into the cassette or replay it from the cassette.
+    #[must_use]
+    pub fn with_cassette(self, cassette: Cassette) -> Self {
+        Self {
+            cassette: Some(Arc::new(cassette)),
+            ..self
+        }
+    }
+
+    /// Read the state at the block instead of the latest final one.
     #[must_use]
-    pub fn new(near_rpc: &str, engine_account: &str, signer_key_path: Option<String>) -> Self {
+    pub fn with_block_reference(self, block_reference: BlockReference) -> Self {
         Self {
-            near_rpc: near_rpc.to_string(),
-            engine_account_id: engine_account.parse().expect("wrong engine account format"),
-            signer_key_path,
+            block_reference,
+            ..self
         }
     }
 
     #[must_use]
     pub fn near(&self) -> NearClient {
-        NearClient::new(
-            &self.near_rpc,
-            self.engine_account_id.as_ref(),
-            self.signer_key_path.clone(),
-        )
+        self.near
+            .clone()
+            .with_cassette(self.cassette.clone())
+            .with_block_reference(self.block_reference.clone())
+    }
+
+    /// The NEAR client of the workflows which send transactions. It ignores `--block-height`,
+    /// `--block-hash` and `--finality`, so the workflows act on the latest final state.
+    #[must_use]
+    pub fn near_latest(&self) -> NearClient {
+        self.near().with_block_reference(Finality::Final.into())
     }
 }
 
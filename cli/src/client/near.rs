@@ -6,27 +6,37 @@ use aurora_engine_types::{
     U256,
     types::{Address, Wei},
 };
+use aurora_sdk_rs::aurora::signer::EvmSigner;
+use aurora_sdk_rs::near::cassette::Cassette;
+use aurora_sdk_rs::near::trace;
+#[cfg(feature = "simple")]
+use aurora_sdk_rs::near::{client::Client, delegate};
 use near_crypto::InMemorySigner;
 use near_crypto::PublicKey;
+use near_jsonrpc_client::errors::{JsonRpcError, JsonRpcServerError};
 use near_jsonrpc_client::methods;
 #[cfg(feature = "simple")]
 use near_jsonrpc_client::methods::tx::{
     RpcTransactionResponse, RpcTransactionStatusRequest, TransactionInfo,
 };
 use near_jsonrpc_client::{
-    AsUrl, JsonRpcClient, methods::broadcast_tx_commit::RpcBroadcastTxCommitRequest,
+    AsUrl, JsonRpcClient, MethodCallResult,
+    methods::broadcast_tx_commit::RpcBroadcastTxCommitRequest,
 };
 use near_jsonrpc_primitives::types::query::QueryResponseKind;
+use near_jsonrpc_primitives::types::transactions::RpcTransactionError;
+#[cfg(feature = "simple")]
+use near_primitives::action::delegate::SignedDelegateAction;
+use near_primitives::errors::InvalidTxError;
 use near_primitives::gas::Gas;
 use near_primitives::transaction::{Action, SignedTransaction};
 use near_primitives::types::{Balance, BlockReference, Finality, Nonce};
 use near_primitives::views::BlockView;
 #[cfg(feature = "simple")]
-use near_primitives::views::FinalExecutionStatus;
-#[cfg(feature = "simple")]
 use near_primitives::views::TxExecutionStatus;
 #[cfg(feature = "simple")]
 use near_primitives::{
+    account::id::AccountIdRef,
     account::{AccessKey, AccessKeyPermission, FunctionCallPermission},
     action::{AddKeyAction, CreateAccountAction, TransferAction},
 };
@@ -43,21 +53,42 @@ use tokio::sync::Mutex;
 
 #[cfg(feature = "advanced")]
 use super::TransactionOutcome;
+use crate::error::Error;
 use crate::utils;
 
 // The maximum amount of prepaid NEAR gas required for paying for a transaction.
 const NEAR_GAS: Gas = Gas::from_teragas(300);
 const TIMEOUT: Duration = Duration::from_secs(20);
+/// How many times a transaction rejected for its nonce is signed and broadcast.
+const MAX_BROADCAST_ATTEMPTS: usize = 5;
+/// Accounts which create top-level accounts with their `create_account` method.
+#[cfg(feature = "simple")]
+const REGISTRARS: [&AccountIdRef; 2] = [
+    AccountIdRef::new_or_panic("near"),
+    AccountIdRef::new_or_panic("testnet"),
+];
 #[derive(Clone)]
 pub struct NearClient {
     client: JsonRpcClient,
+    cassette: Option<Arc<Cassette>>,
     pub engine_account_id: AccountId,
     signer_key_path: Option<String>,
     access_key_nonces: Arc<Mutex<HashMap<(AccountId, PublicKey), AtomicU64>>>,
+    /// Block the state is read at, the final one by default.
+    block_reference: BlockReference,
 }
 
 impl NearClient {
-    pub fn new<U: AsUrl>(url: U, engine_account_id: &str, signer_key_path: Option<String>) -> Self {
+    pub fn new<U: AsUrl>(
+        url: U,
+        engine_account_id: &str,
+        signer_key_path: Option<String>,
+    ) -> anyhow::Result<Self> {
+        let engine_account_id = engine_account_id.parse().map_err(|e| {
+            Error::Usage(format!(
+                "Invalid engine account id {engine_account_id}: {e}"
+            ))
+        })?;
         let mut headers = reqwest::header::HeaderMap::with_capacity(2);
         headers.insert(
             reqwest::header::CONTENT_TYPE,
@@ -68,15 +99,17 @@ impl NearClient {
             .connect_timeout(TIMEOUT)
             .default_headers(headers)
             .build()
-            .map(JsonRpcClient::with)
-            .expect("couldn't create json rpc client");
+            .map(JsonRpcClient::with)?;
         let client = client.connect(url);
-        Self {
+
+        Ok(Self {
             client,
-            engine_account_id: engine_account_id.parse().unwrap(),
+            cassette: None,
+            engine_account_id,
             signer_key_path,
             access_key_nonces: Arc::new(Mutex::new(HashMap::new())),
-        }
+            block_reference: Finality::Final.into(),
+        })
     }
 
     #[cfg(feature = "advanced")]
@@ -91,7 +124,7 @@ impl NearClient {
                 let request = methods::block::RpcBlockRequest {
                     block_reference: Finality::Final.into(),
                 };
-                let response = self.client.call(request).await?;
+                let response = self.call(request).await?;
                 response.header.hash
             };
             let request = methods::light_client_proof::RpcLightClientExecutionProofRequest {
@@ -101,7 +134,7 @@ impl NearClient {
                 },
                 light_client_head: block_hash,
             };
-            let response = self.client.call(request).await?;
+            let response = self.call(request).await?;
 
             match response.outcome_proof.outcome.status {
                 views::ExecutionStatusView::SuccessValue(result) => {
@@ -116,7 +149,9 @@ impl NearClient {
                     receipt_id = id;
                 }
                 views::ExecutionStatusView::Unknown => {
-                    panic!("Unknown receipt_id: {near_receipt_id:?}")
+                    break Err(
+                        Error::Other(format!("Unknown receipt_id: {near_receipt_id:?}")).into(),
+                    );
                 }
             }
         }
@@ -152,7 +187,6 @@ impl NearClient {
         target: Address,
         amount: Wei,
         input: Vec<u8>,
-        block_height: Option<u64>,
     ) -> anyhow::Result<TransactionStatus> {
         let args = aurora_engine_types::parameters::engine::ViewCallArgs {
             sender,
@@ -160,9 +194,7 @@ impl NearClient {
             amount: amount.to_bytes(),
             input,
         };
-        let result = self
-            .view_call_for_block("view", borsh::to_vec(&args)?, block_height)
-            .await?;
+        let result = self.view_call("view", borsh::to_vec(&args)?).await?;
         let status = TransactionStatus::try_from_slice(&result.result)?;
         Ok(status)
     }
@@ -172,20 +204,16 @@ impl NearClient {
         method_name: &str,
         args: Vec<u8>,
     ) -> anyhow::Result<views::CallResult> {
-        self.view_call_for_block(method_name, args, None).await
+        self.view_call_at(self.block_reference.clone(), method_name, args)
+            .await
     }
 
-    pub async fn view_call_for_block(
+    async fn view_call_at(
         &self,
+        block_reference: BlockReference,
         method_name: &str,
         args: Vec<u8>,
-        block_height: Option<u64>,
     ) -> anyhow::Result<views::CallResult> {
-        let block_reference = block_height.map_or_else(
-            || Finality::Final.into(),
-            |height| BlockReference::BlockId(near_primitives::types::BlockId::Height(height)),
-        );
-
         let request = methods::query::RpcQueryRequest {
             block_reference,
             request: views::QueryRequest::CallFunction {
@@ -194,7 +222,7 @@ impl NearClient {
                 args: args.into(),
             },
         };
-        let response = self.client.call(request).await?;
+        let response = self.call(request).await?;
 
         match response.kind {
             QueryResponseKind::CallResult(result) => Ok(result),
@@ -203,17 +231,64 @@ impl NearClient {
     }
 
     #[cfg(feature = "simple")]
-    pub async fn view_account(&self, account: &str) -> anyhow::Result<String> {
+    pub async fn view_account(&self, account: &str) -> anyhow::Result<views::AccountView> {
         let account_id: AccountId = account.parse()?;
         let request = methods::query::RpcQueryRequest {
-            block_reference: BlockReference::Finality(Finality::Final),
+            block_reference: self.block_reference.clone(),
             request: views::QueryRequest::ViewAccount { account_id },
         };
 
-        let response = self.client.call(request).await?;
+        let response = self.call(request).await?;
 
         match response.kind {
-            QueryResponseKind::ViewAccount(view) => Ok(serde_json::to_string_pretty(&view)?),
+            QueryResponseKind::ViewAccount(view) => Ok(view),
+            _ => anyhow::bail!("Wrong type response"),
+        }
+    }
+
+    /// Code of the engine at the final block.
+    #[cfg(feature = "simple")]
+    pub async fn view_code(&self) -> anyhow::Result<views::ContractCodeView> {
+        let request = methods::query::RpcQueryRequest {
+            block_reference: BlockReference::Finality(Finality::Final),
+            request: views::QueryRequest::ViewCode {
+                account_id: self.engine_account_id.clone(),
+            },
+        };
+
+        let response = self.call(request).await?;
+
+        match response.kind {
+            QueryResponseKind::ViewCode(view) => Ok(view),
+            _ => anyhow::bail!("Wrong type response"),
+        }
+    }
+
+    /// Height of the final block.
+    #[cfg(feature = "simple")]
+    pub async fn final_block_height(&self) -> anyhow::Result<u64> {
+        let block = self.view_block(Some(Finality::Final.into())).await?;
+
+        Ok(block.header.height)
+    }
+
+    /// Access keys of the account.
+    #[cfg(feature = "simple")]
+    pub async fn view_access_keys(
+        &self,
+        account_id: &AccountId,
+    ) -> anyhow::Result<Vec<views::AccessKeyInfoView>> {
+        let request = methods::query::RpcQueryRequest {
+            block_reference: self.block_reference.clone(),
+            request: views::QueryRequest::ViewAccessKeyList {
+                account_id: account_id.clone(),
+            },
+        };
+
+        let response = self.call(request).await?;
+
+        match response.kind {
+            QueryResponseKind::AccessKeyList(list) => Ok(list.keys),
             _ => anyhow::bail!("Wrong type response"),
         }
     }
@@ -327,6 +402,48 @@ impl NearClient {
         .await
     }
 
+    /// Signs the call of the engine's method without sending it, so the hash of the transaction
+    /// is known before it's broadcast.
+    #[cfg(feature = "simple")]
+    pub async fn sign_contract_call(
+        &self,
+        method_name: &str,
+        args: Vec<u8>,
+    ) -> anyhow::Result<SignedTransaction> {
+        let signer = self.signer()?;
+        let (block_hash, nonce) = self.get_nonce(&signer).await?;
+
+        Ok(SignedTransaction::from_actions(
+            nonce,
+            signer.account_id.clone(),
+            self.engine_account_id.as_str().parse()?,
+            &signer.into(),
+            vec![Action::FunctionCall(Box::new(
+                near_primitives::transaction::FunctionCallAction {
+                    method_name: method_name.to_string(),
+                    args,
+                    gas: NEAR_GAS,
+                    deposit: Balance::ZERO,
+                },
+            ))],
+            block_hash,
+            0,
+        ))
+    }
+
+    /// Sends the signed transaction and waits until it's executed.
+    #[cfg(feature = "simple")]
+    pub async fn broadcast_tx(
+        &self,
+        signed_transaction: SignedTransaction,
+    ) -> anyhow::Result<FinalExecutionOutcomeView> {
+        let response = self
+            .call(RpcBroadcastTxCommitRequest { signed_transaction })
+            .await?;
+
+        Ok(response)
+    }
+
     async fn near_broadcast_tx(
         &self,
         actions: Vec<Action>,
@@ -346,92 +463,156 @@ impl NearClient {
         let mut signer = self.signer()?;
         signer.account_id = from.clone();
 
-        let (block_hash, nonce) = self.get_nonce(&signer).await?;
-        let nonce = nonce_override.unwrap_or(nonce);
+        let receiver_id: AccountId = self.engine_account_id.as_str().parse()?;
+        let mut attempt = 1;
+
+        loop {
+            let (block_hash, nonce) = self.get_nonce(&signer).await?;
+            let nonce = nonce_override.unwrap_or(nonce);
+
+            let request = RpcBroadcastTxCommitRequest {
+                signed_transaction: SignedTransaction::from_actions(
+                    nonce,
+                    from.clone(),
+                    receiver_id.clone(),
+                    &signer.clone().into(),
+                    actions.clone(),
+                    block_hash,
+                    0,
+                ),
+            };
 
+            match self.try_call(request).await {
+                // Transactions signed concurrently by the same key may be included out of
+                // order. A rejected transaction isn't executed, so sign it again with a nonce
+                // above the one of the access key.
+                Err(JsonRpcError::ServerError(JsonRpcServerError::HandlerError(
+                    RpcTransactionError::InvalidTransaction {
+                        context: InvalidTxError::InvalidNonce { ak_nonce, .. },
+                        ..
+                    },
+                ))) if nonce_override.is_none() && attempt < MAX_BROADCAST_ATTEMPTS => {
+                    self.skip_nonces(&signer, ak_nonce).await;
+                    attempt += 1;
+                }
+                result => return result.map_err(|e| Error::rpc(&e).into()),
+            }
+        }
+    }
+
+    /// Signs the call of the engine's method as a delegate action (NEP-366), which is valid
+    /// during `block_height_ttl` blocks.
+    #[cfg(feature = "simple")]
+    pub async fn create_delegate_action(
+        &self,
+        method_name: &str,
+        args: Vec<u8>,
+        gas: Gas,
+        deposit: u128,
+        block_height_ttl: u64,
+    ) -> anyhow::Result<SignedDelegateAction> {
+        let signer = self.signer()?;
+        let (_, nonce) = self.get_nonce(&signer).await?;
+        let block = self.view_block(Some(Finality::Final.into())).await?;
+        let signer = signer.into();
+        let action = delegate::delegate_action(
+            &signer,
+            self.engine_account_id.clone(),
+            vec![Action::FunctionCall(Box::new(
+                near_primitives::transaction::FunctionCallAction {
+                    method_name: method_name.to_string(),
+                    args,
+                    gas,
+                    deposit: Balance::from_yoctonear(deposit),
+                },
+            ))],
+            nonce,
+            block.header.height + block_height_ttl,
+        )?;
+
+        Ok(delegate::sign(&signer, action))
+    }
+
+    /// Submits the delegate action signed by another account. The gas is paid by the signer
+    /// of the client.
+    #[cfg(feature = "simple")]
+    pub async fn relay_delegate_action(
+        &self,
+        delegate_action: SignedDelegateAction,
+    ) -> anyhow::Result<FinalExecutionOutcomeView> {
+        let signer = self.signer()?;
+        let (block_hash, nonce) = self.get_nonce(&signer).await?;
         let request = RpcBroadcastTxCommitRequest {
             signed_transaction: SignedTransaction::from_actions(
                 nonce,
-                from,
-                self.engine_account_id.as_str().parse()?,
+                signer.account_id.clone(),
+                delegate_action.delegate_action.sender_id.clone(),
                 &signer.into(),
-                actions,
+                vec![delegate_action.into()],
                 block_hash,
                 0,
             ),
         };
-        let response = self.client.call(request).await?;
+        let response = self.call(request).await?;
 
         Ok(response)
     }
 
-    /// Creates new NEAR account.
+    /// Create the account with a new random key, which is returned with the outcome.
+    ///
+    /// A direct sub-account is created by its parent, which signs the transaction with the key
+    /// from `parent_key_path` or with the key of the client. Top-level accounts of `near` and
+    /// `testnet` are created by calling `create_account` of the registrar.
     #[cfg(feature = "simple")]
-    pub async fn create_account(&self, account: &str, deposit: f64) -> anyhow::Result<String> {
-        let signer = self.signer()?;
+    pub async fn create_account(
+        &self,
+        account: &str,
+        deposit: f64,
+        parent_key_path: Option<&std::path::Path>,
+    ) -> anyhow::Result<(FinalExecutionOutcomeView, near_crypto::SecretKey)> {
+        let signer = match parent_key_path {
+            Some(path) => utils::read_key_file(path)
+                .map_err(|e| Error::Usage(format!("Couldn't read {}: {e}", path.display())))?,
+            None => self.signer()?,
+        };
         let new_account_id = AccountId::from_str(account)?;
-        let is_sub_account = new_account_id.is_sub_account_of(&signer.account_id);
         let new_key_pair = near_crypto::SecretKey::from_random(near_crypto::KeyType::ED25519);
-        let (block_hash, nonce) = self.get_nonce(&signer).await?;
         let initial_balance = Balance::from_yoctonear(utils::near_to_yocto(deposit));
-
-        let request = if is_sub_account {
-            RpcBroadcastTxCommitRequest {
-                signed_transaction: SignedTransaction::create_account(
-                    nonce,
-                    signer.account_id.clone(),
-                    new_account_id,
-                    initial_balance,
-                    new_key_pair.public_key(),
-                    &signer.into(),
-                    block_hash,
-                ),
-            }
+        // The parent key is enough to create the account, the key of the CLI isn't required.
+        let client = self.sdk_client_with_signer(signer.clone())?;
+
+        let outcome = if new_account_id.is_sub_account_of(&signer.account_id) {
+            client
+                .batch(&new_account_id)
+                .create_account()
+                .transfer(initial_balance)
+                .add_key(new_key_pair.public_key(), AccessKey::full_access())
+                .transact()
+                .await?
+        } else if let Some(registrar) = REGISTRARS
+            .iter()
+            .find(|registrar| new_account_id.is_sub_account_of(registrar))
+        {
+            client
+                .call(&(*registrar).to_owned(), "create_account")
+                .args_json(serde_json::json!({
+                    "new_account_id": new_account_id,
+                    "new_public_key": new_key_pair.public_key(),
+                }))?
+                .deposit(initial_balance)
+                .gas(NEAR_GAS.as_gas())
+                .transact()
+                .await?
         } else {
-            let contract_id = self.contract_id()?;
-            RpcBroadcastTxCommitRequest {
-                signed_transaction: SignedTransaction::call(
-                    nonce,
-                    signer.account_id.clone(),
-                    contract_id,
-                    &signer.into(),
-                    initial_balance,
-                    "create_account".to_string(),
-                    serde_json::json!({
-                        "new_account_id": new_account_id,
-                        "new_public_key": new_key_pair.public_key(),
-                    })
-                    .to_string()
-                    .into_bytes(),
-                    NEAR_GAS,
-                    block_hash,
-                ),
-            }
+            return Err(Error::Usage(format!(
+                "{new_account_id} isn't a sub-account of {}, pass the key file of its parent \
+                 with --parent-key",
+                signer.account_id
+            ))
+            .into());
         };
 
-        let response = self.client.call(request).await?;
-
-        match &response.status {
-            FinalExecutionStatus::NotStarted => {
-                anyhow::bail!("Transaction execution status: not started")
-            }
-            FinalExecutionStatus::Started => anyhow::bail!("Transaction execution status: started"),
-            FinalExecutionStatus::Failure(error) => anyhow::bail!(error.to_string()),
-            FinalExecutionStatus::SuccessValue(result) => {
-                if String::from_utf8_lossy(result) == "false" {
-                    anyhow::bail!(
-                        "Error while creating account, tx hash: {}",
-                        response.transaction.hash
-                    )
-                }
-
-                Ok(serde_json::to_string_pretty(&serde_json::json!({
-                    "account_id": account,
-                    "public_key": new_key_pair.public_key().to_string(),
-                    "private_key": new_key_pair.to_string(),
-                }))?)
-            }
-        }
+        Ok((outcome, new_key_pair))
     }
 
     /// Deploy WASM contract.
@@ -455,21 +636,27 @@ impl NearClient {
             ),
         };
 
-        self.client.call(request).await.map_err(Into::into)
+        self.call(request).await.map_err(Into::into)
     }
 
     /// Send Aurora EVM transaction via NEAR network.
     pub async fn send_aurora_transaction(
         &self,
-        sk: &libsecp256k1::SecretKey,
+        signer: &dyn EvmSigner,
         to: Option<Address>,
         amount: Wei,
         input: Vec<u8>,
     ) -> anyhow::Result<FinalExecutionOutcomeView> {
-        let sender_address = utils::address_from_secret_key(sk)?;
+        let sender_address = signer.address();
+        // The transaction is built from the latest final state regardless of the block reference
+        // of the view calls, otherwise it's signed with a stale nonce.
         let nonce = {
             let result = self
-                .view_call("get_nonce", sender_address.as_bytes().to_vec())
+                .view_call_at(
+                    Finality::Final.into(),
+                    "get_nonce",
+                    sender_address.as_bytes().to_vec(),
+                )
                 .await?;
             U256::from_big_endian(&result.result)
         };
@@ -483,12 +670,16 @@ impl NearClient {
         };
         let chain_id = {
             let result = self
-                .view_call("get_chain_id", sender_address.as_bytes().to_vec())
+                .view_call_at(
+                    Finality::Final.into(),
+                    "get_chain_id",
+                    sender_address.as_bytes().to_vec(),
+                )
                 .await?;
             U256::from_big_endian(&result.result).low_u64()
         };
         let signed_tx = aurora_engine_transactions::EthTransactionKind::Legacy(
-            utils::sign_transaction(tx, chain_id, sk),
+            signer.sign_transaction(tx, chain_id).await?,
         );
         let result = self.contract_call("submit", (&signed_tx).into()).await?;
 
@@ -519,10 +710,20 @@ impl NearClient {
         }
     }
 
+    /// Makes the next nonce of the signer's access key greater than `ak_nonce`.
+    async fn skip_nonces(&self, signer: &InMemorySigner, ak_nonce: Nonce) {
+        let cache_key = (signer.account_id.clone(), signer.secret_key.public_key());
+        self.access_key_nonces
+            .lock()
+            .await
+            .entry(cache_key)
+            .or_insert_with(|| AtomicU64::new(ak_nonce))
+            .fetch_max(ak_nonce, Ordering::SeqCst);
+    }
+
     async fn view_block(&self, block_ref: Option<BlockReference>) -> anyhow::Result<BlockView> {
         let block_reference = block_ref.unwrap_or_else(|| Finality::None.into());
         let block_view = self
-            .client
             .call(&methods::block::RpcBlockRequest { block_reference })
             .await?;
 
@@ -541,7 +742,7 @@ impl NearClient {
                 public_key,
             },
         };
-        let response = self.client.call(request).await?;
+        let response = self.call(request).await?;
         let block_hash = response.block_hash;
         let QueryResponseKind::AccessKey(access_key) = response.kind else {
             anyhow::bail!("Wrong response kind: {:?}", response.kind)
@@ -550,6 +751,32 @@ impl NearClient {
         Ok((block_hash, access_key.nonce))
     }
 
+    /// Call the RPC method through the cassette if the traffic is recorded or replayed.
+    async fn call<M>(&self, method: M) -> Result<M::Response, Error>
+    where
+        M: methods::RpcMethod + Send + Sync,
+        M::Response: std::fmt::Debug + Send,
+        M::Error: std::fmt::Debug + std::fmt::Display + Send,
+    {
+        self.try_call(method).await.map_err(|e| Error::rpc(&e))
+    }
+
+    /// Like `call`, but keeps the typed error of the RPC method.
+    async fn try_call<M>(&self, method: M) -> MethodCallResult<M::Response, M::Error>
+    where
+        M: methods::RpcMethod + Send + Sync,
+        M::Response: std::fmt::Debug + Send,
+        M::Error: std::fmt::Debug + std::fmt::Display + Send,
+    {
+        trace::call(self.client.server_addr(), &method, async {
+            match &self.cassette {
+                Some(cassette) => cassette.call(&self.client, &method).await,
+                None => self.client.call(&method).await,
+            }
+        })
+        .await
+    }
+
     fn signer(&self) -> anyhow::Result<InMemorySigner> {
         std::env::var("NEAR_KEY_PATH")
             .ok()
@@ -562,20 +789,30 @@ impl NearClient {
             .and_then(utils::read_key_file)
     }
 
+    /// URL of the NEAR RPC endpoint.
     #[cfg(feature = "simple")]
-    fn contract_id(&self) -> anyhow::Result<AccountId> {
-        let server_addr = self.client.server_addr();
+    #[must_use]
+    pub fn server_addr(&self) -> &str {
+        self.client.server_addr()
+    }
 
-        let account = if server_addr.contains("testnet.near.org") {
-            "testnet"
-        } else if server_addr.contains("mainnet.near.org") {
-            "near"
-        } else {
-            anyhow::bail!("Non-sub accounts could be created for mainnet or testnet only");
-        };
+    /// Client of the SDK, which signs transactions with the key of this client and shares its
+    /// cassette.
+    #[cfg(feature = "simple")]
+    pub fn sdk_client(&self) -> anyhow::Result<Client> {
+        self.sdk_client_with_signer(self.signer()?)
+    }
+
+    /// Client of the SDK, which signs transactions with the given key and shares the cassette of
+    /// this client.
+    #[cfg(feature = "simple")]
+    pub fn sdk_client_with_signer(&self, signer: InMemorySigner) -> anyhow::Result<Client> {
+        let client = Client::new(self.client.server_addr(), None, signer.into())?;
 
-        let account_id = account.parse()?;
-        Ok(account_id)
+        Ok(match &self.cassette {
+            Some(cassette) => client.with_cassette(cassette.clone()),
+            None => client,
+        })
     }
 
     #[cfg(feature = "simple")]
@@ -593,7 +830,7 @@ impl NearClient {
             wait_until,
         };
 
-        let rsp = self.client.call(req).await?;
+        let rsp = self.call(req).await?;
         Ok(rsp)
     }
 
@@ -649,6 +886,22 @@ impl NearClient {
         }
     }
 
+    #[cfg(feature = "simple")]
+    #[must_use]
+    pub fn with_cassette(self, cassette: Option<Arc<Cassette>>) -> Self {
+        Self { cassette, ..self }
+    }
+
+    /// Read the state at the block instead of the latest final one. The nonces of transactions
+    /// and the blocks which they refer to are always the latest final ones.
+    #[must_use]
+    pub fn with_block_reference(self, block_reference: BlockReference) -> Self {
+        Self {
+            block_reference,
+            ..self
+        }
+    }
+
     #[cfg(feature = "simple")]
     #[allow(dead_code)]
     #[must_use]
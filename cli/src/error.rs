@@ -0,0 +1,205 @@
+//! Errors of the CLI and the exit codes of the process, which allow scripts to tell why
+//! a command has failed.
+use std::fmt::{Debug, Display};
+use std::process::ExitCode;
+
+use aurora_sdk_rs::aurora::error::{EngineError, Error as AuroraError};
+use near_jsonrpc_client::errors::JsonRpcError;
+use serde::Serialize;
+
+/// Kind of the failure, which defines the exit code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorKind {
+    /// Unexpected failure, e.g. a file couldn't be read.
+    Other,
+    /// Wrong arguments or input of the command.
+    Usage,
+    /// The NEAR RPC endpoint is unreachable or responded with an error.
+    Rpc,
+    /// The NEAR transaction has failed.
+    NearTransaction,
+    /// The EVM transaction has been reverted or hasn't succeeded for another reason.
+    EvmRevert,
+    /// Aurora Engine has rejected the call, e.g. with `ERR_PAUSED`.
+    Engine,
+}
+
+impl ErrorKind {
+    /// Exit code of the process.
+    #[must_use]
+    pub const fn exit_code(self) -> u8 {
+        match self {
+            Self::Other => 1,
+            Self::Usage => 2,
+            Self::Rpc => 3,
+            Self::NearTransaction => 4,
+            Self::EvmRevert => 5,
+            Self::Engine => 6,
+        }
+    }
+
+    /// Kind of the error, defined by the first known error in the chain of its causes.
+    #[must_use]
+    pub fn of(error: &anyhow::Error) -> Self {
+        error
+            .chain()
+            .find_map(|cause| {
+                if let Some(Reported(e)) = cause.downcast_ref() {
+                    Some(e.kind())
+                } else if let Some(e) = cause.downcast_ref::<Error>() {
+                    Some(e.kind())
+                } else if let Some(e) = cause.downcast_ref::<AuroraError>() {
+                    match e {
+                        AuroraError::Engine(_) => Some(Self::Engine),
+                        AuroraError::Near(_) => Some(Self::Rpc),
+                        AuroraError::TransactionStatus(_) => Some(Self::EvmRevert),
+                        AuroraError::ExecutionNotStarted => Some(Self::NearTransaction),
+                        AuroraError::Io(_) | AuroraError::Abi(_) | AuroraError::Signer(_) => None,
+                    }
+                } else if cause.is::<aurora_sdk_rs::near::error::Error>() {
+                    Some(Self::Rpc)
+                } else if cause.is::<near_primitives::account::id::ParseAccountError>()
+                    || cause.is::<std::num::ParseIntError>()
+                    || cause.is::<std::num::ParseFloatError>()
+                    || cause.is::<hex::FromHexError>()
+                {
+                    Some(Self::Usage)
+                } else {
+                    None
+                }
+            })
+            .unwrap_or(Self::Other)
+    }
+}
+
+/// Error of the command with the kind of the failure.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("{0}")]
+    Other(String),
+    #[error("{0}")]
+    Usage(String),
+    #[error("{0}")]
+    Rpc(String),
+    #[error("{0}")]
+    NearTransaction(String),
+    #[error("{0}")]
+    EvmRevert(String),
+    #[error("{message}")]
+    Engine { message: String, error: EngineError },
+    /// Failure of the command on some of the engines, with the kind of the first failure.
+    #[cfg(feature = "simple")]
+    #[error("{message}")]
+    Engines { message: String, kind: ErrorKind },
+}
+
+impl Error {
+    /// Error returned by the NEAR RPC. A panic of the contract in a view call is an engine error.
+    pub fn rpc<E: Debug + Display>(error: &JsonRpcError<E>) -> Self {
+        let message = error.to_string();
+
+        match engine_error(&message) {
+            Some(error) => Self::Engine { message, error },
+            None => Self::Rpc(message),
+        }
+    }
+
+    /// Failure of the NEAR transaction. A panic of the contract is an engine error.
+    pub fn near_transaction<M: Into<String>>(message: M) -> Self {
+        let message = message.into();
+
+        match engine_error(&message) {
+            Some(error) => Self::Engine { message, error },
+            None => Self::NearTransaction(message),
+        }
+    }
+
+    #[must_use]
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Other(_) => ErrorKind::Other,
+            Self::Usage(_) => ErrorKind::Usage,
+            Self::Rpc(_) => ErrorKind::Rpc,
+            Self::NearTransaction(_) => ErrorKind::NearTransaction,
+            Self::EvmRevert(_) => ErrorKind::EvmRevert,
+            Self::Engine { .. } => ErrorKind::Engine,
+            #[cfg(feature = "simple")]
+            Self::Engines { kind, .. } => *kind,
+        }
+    }
+}
+
+/// The error which has already been printed as a part of the command output.
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct Reported(pub Error);
+
+/// Print the error in the plain format and return the exit code matching its kind.
+#[must_use]
+pub fn report(error: &anyhow::Error) -> ExitCode {
+    eprintln!("Error: {error:?}");
+    ExitCode::from(ErrorKind::of(error).exit_code())
+}
+
+/// Aurora Engine error from the panic message of the contract, e.g.
+/// `Smart contract panicked: ERR_PAUSED` of a transaction or `panic_msg: "ERR_PAUSED"` of a view.
+fn engine_error(message: &str) -> Option<EngineError> {
+    ["Smart contract panicked: ", "panic_msg: \""]
+        .iter()
+        .find_map(|prefix| {
+            let (_, rest) = message.split_once(prefix)?;
+            let end = rest
+                .find(|c: char| !(c.is_ascii_uppercase() || c.is_ascii_digit() || "_:".contains(c)))
+                .unwrap_or(rest.len());
+
+            rest[..end]
+                .starts_with("ERR_")
+                .then(|| EngineError::from(rest[..end].to_string()))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_engine_error() {
+        assert!(matches!(
+            engine_error("Action #0: Smart contract panicked: ERR_PAUSED"),
+            Some(EngineError::Paused)
+        ));
+        assert!(matches!(
+            engine_error(
+                r#"wasm execution failed with error: HostError(GuestPanic { panic_msg: "ERR_NOT_ALLOWED:TOO_EARLY" })"#
+            ),
+            Some(EngineError::NotAllowedTooEarly)
+        ));
+        assert!(engine_error("Smart contract panicked: ERC20_NOT_FOUND").is_none());
+        assert!(engine_error("Account doesn't exist").is_none());
+    }
+
+    #[test]
+    fn test_error_kind() {
+        let kind = |error: anyhow::Error| ErrorKind::of(&error);
+
+        assert_eq!(
+            kind(Error::near_transaction("Error: Smart contract panicked: ERR_PAUSED").into()),
+            ErrorKind::Engine
+        );
+        assert_eq!(
+            kind(Error::near_transaction("Error: Exceeded the prepaid gas").into()),
+            ErrorKind::NearTransaction
+        );
+        assert_eq!(
+            kind(Reported(Error::EvmRevert("reverted".to_string())).into()),
+            ErrorKind::EvmRevert
+        );
+        assert_eq!(
+            kind(anyhow::Error::from("x".parse::<u64>().unwrap_err()).context("Wrong height")),
+            ErrorKind::Usage
+        );
+        assert_eq!(kind(anyhow::anyhow!("Unexpected")), ErrorKind::Other);
+        assert_eq!(ErrorKind::Engine.exit_code(), 6);
+    }
+}
@@ -1,5 +1,6 @@
 pub mod cli;
 pub mod client;
+pub mod error;
 #[cfg(feature = "advanced")]
 pub mod eth_method;
 #[cfg(feature = "advanced")]
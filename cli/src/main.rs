@@ -1,7 +1,9 @@
 use clap::Parser;
+use std::process::ExitCode;
 
 mod cli;
 mod client;
+mod error;
 #[cfg(feature = "advanced")]
 mod eth_method;
 #[cfg(feature = "advanced")]
@@ -9,7 +11,16 @@ mod transaction_reader;
 mod utils;
 
 #[tokio::main]
-async fn main() -> anyhow::Result<()> {
+async fn main() -> ExitCode {
     let args = cli::Cli::parse();
-    cli::run(args).await
+    #[cfg(feature = "simple")]
+    let format = args.output_format.clone();
+
+    match cli::run(args).await {
+        Ok(()) => ExitCode::SUCCESS,
+        #[cfg(feature = "simple")]
+        Err(e) => cli::simple::report(&e, &format),
+        #[cfg(not(feature = "simple"))]
+        Err(e) => error::report(&e),
+    }
 }
@@ -0,0 +1,510 @@
+use aurora_engine_sdk::keccak;
+use aurora_engine_types::{H256, U256};
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+
+const DOMAIN_TYPE: &str = "EIP712Domain";
+/// Fields of the `EIP712Domain` struct in the order defined by the EIP-712 specification.
+const DOMAIN_FIELDS: [(&str, &str); 5] = [
+    ("name", "string"),
+    ("version", "string"),
+    ("chainId", "uint256"),
+    ("verifyingContract", "address"),
+    ("salt", "bytes32"),
+];
+
+/// Typed structured data in the format used by `eth_signTypedData_v4`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypedData {
+    pub types: BTreeMap<String, Vec<TypedDataField>>,
+    pub primary_type: String,
+    pub domain: Value,
+    pub message: Value,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct TypedDataField {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+}
+
+impl TypedData {
+    pub fn from_json(json: &str) -> anyhow::Result<Self> {
+        let mut data: Self = serde_json::from_str(json)
+            .map_err(|e| anyhow::anyhow!("Couldn't parse EIP-712 typed data: {e}"))?;
+
+        // The domain type could be omitted in the input, so derive it from the present fields.
+        if !data.types.contains_key(DOMAIN_TYPE) {
+            let domain = data
+                .domain
+                .as_object()
+                .ok_or_else(|| anyhow::anyhow!("EIP-712 domain must be a JSON object"))?;
+            let fields = DOMAIN_FIELDS
+                .iter()
+                .filter(|(name, _)| domain.contains_key(*name))
+                .map(|(name, kind)| TypedDataField {
+                    name: (*name).to_string(),
+                    kind: (*kind).to_string(),
+                })
+                .collect();
+            data.types.insert(DOMAIN_TYPE.to_string(), fields);
+        }
+
+        Ok(data)
+    }
+
+    /// Return the hash of the `EIP712Domain` struct.
+    pub fn domain_separator(&self) -> anyhow::Result<H256> {
+        hash_struct(&self.types, DOMAIN_TYPE, &self.domain)
+    }
+
+    /// Return the hash of the primary struct.
+    pub fn struct_hash(&self) -> anyhow::Result<H256> {
+        hash_struct(&self.types, &self.primary_type, &self.message)
+    }
+
+    /// Return the digest to be signed: `keccak256("\x19\x01" ‖ domainSeparator ‖ hashStruct(message))`.
+    pub fn signing_hash(&self) -> anyhow::Result<H256> {
+        let mut bytes = Vec::with_capacity(66);
+        bytes.extend_from_slice(&[0x19, 0x01]);
+        bytes.extend_from_slice(self.domain_separator()?.as_bytes());
+
+        // The message hash is omitted when the primary type is the domain itself.
+        if self.primary_type != DOMAIN_TYPE {
+            bytes.extend_from_slice(self.struct_hash()?.as_bytes());
+        }
+
+        Ok(keccak(&bytes))
+    }
+}
+
+/// Return the encoded type of the struct, e.g. `Mail(Person from,Person to,string contents)Person(string name,address wallet)`.
+pub fn encode_type(
+    types: &BTreeMap<String, Vec<TypedDataField>>,
+    primary_type: &str,
+) -> anyhow::Result<String> {
+    let mut deps = BTreeSet::new();
+    collect_dependencies(types, primary_type, &mut deps)?;
+    deps.remove(primary_type);
+
+    std::iter::once(primary_type)
+        .chain(deps.iter().map(String::as_str))
+        .map(|name| {
+            let fields = types
+                .get(name)
+                .ok_or_else(|| anyhow::anyhow!("Unknown EIP-712 type: {name}"))?
+                .iter()
+                .map(|field| format!("{} {}", field.kind, field.name))
+                .collect::<Vec<_>>()
+                .join(",");
+            Ok(format!("{name}({fields})"))
+        })
+        .collect()
+}
+
+fn collect_dependencies(
+    types: &BTreeMap<String, Vec<TypedDataField>>,
+    name: &str,
+    deps: &mut BTreeSet<String>,
+) -> anyhow::Result<()> {
+    if deps.contains(name) {
+        return Ok(());
+    }
+
+    let fields = types
+        .get(name)
+        .ok_or_else(|| anyhow::anyhow!("Unknown EIP-712 type: {name}"))?;
+    deps.insert(name.to_string());
+
+    for field in fields {
+        let base = base_type(&field.kind);
+        if types.contains_key(base) {
+            collect_dependencies(types, base, deps)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn hash_struct(
+    types: &BTreeMap<String, Vec<TypedDataField>>,
+    name: &str,
+    value: &Value,
+) -> anyhow::Result<H256> {
+    let type_hash = keccak(encode_type(types, name)?.as_bytes());
+    let object = value
+        .as_object()
+        .ok_or_else(|| anyhow::anyhow!("Expected JSON object for EIP-712 type: {name}"))?;
+    let fields = types
+        .get(name)
+        .ok_or_else(|| anyhow::anyhow!("Unknown EIP-712 type: {name}"))?;
+    let mut encoded = Vec::with_capacity(32 * (fields.len() + 1));
+    encoded.extend_from_slice(type_hash.as_bytes());
+
+    for field in fields {
+        let value = object.get(&field.name).ok_or_else(|| {
+            anyhow::anyhow!("Missing field: {} in EIP-712 type: {name}", field.name)
+        })?;
+        encoded.extend_from_slice(&encode_value(types, &field.kind, value)?);
+    }
+
+    Ok(keccak(&encoded))
+}
+
+fn encode_value(
+    types: &BTreeMap<String, Vec<TypedDataField>>,
+    kind: &str,
+    value: &Value,
+) -> anyhow::Result<[u8; 32]> {
+    if let Some((item_kind, length)) = split_array_type(kind)? {
+        let items = value
+            .as_array()
+            .ok_or_else(|| anyhow::anyhow!("Expected JSON array for type: {kind}"))?;
+        if let Some(length) = length
+            && items.len() != length
+        {
+            anyhow::bail!(
+                "Expected {length} items for type: {kind}, got {}",
+                items.len()
+            );
+        }
+
+        let mut encoded = Vec::with_capacity(32 * items.len());
+        for item in items {
+            encoded.extend_from_slice(&encode_value(types, item_kind, item)?);
+        }
+
+        return Ok(keccak(&encoded).0);
+    }
+
+    if types.contains_key(kind) {
+        return hash_struct(types, kind, value).map(|hash| hash.0);
+    }
+
+    match kind {
+        "string" => {
+            let string = value
+                .as_str()
+                .ok_or_else(|| anyhow::anyhow!("Expected string for type: {kind}"))?;
+            Ok(keccak(string.as_bytes()).0)
+        }
+        "bytes" => Ok(keccak(&value_to_bytes(value)?).0),
+        "bool" => {
+            let flag = match value {
+                Value::Bool(flag) => *flag,
+                Value::String(s) if s == "true" => true,
+                Value::String(s) if s == "false" => false,
+                _ => anyhow::bail!("Expected boolean for type: {kind}"),
+            };
+            Ok(u256_to_word(U256::from(u8::from(flag))))
+        }
+        "address" => {
+            let string = value
+                .as_str()
+                .ok_or_else(|| anyhow::anyhow!("Expected hex string for type: {kind}"))?;
+            let address = super::hex_to_address(string)?;
+            let mut word = [0; 32];
+            word[12..].copy_from_slice(address.as_bytes());
+            Ok(word)
+        }
+        _ if kind.starts_with("bytes") => {
+            let size: usize = kind["bytes".len()..]
+                .parse()
+                .map_err(|_| anyhow::anyhow!("Unsupported EIP-712 type: {kind}"))?;
+            let bytes = value_to_bytes(value)?;
+            if size == 0 || size > 32 || bytes.len() != size {
+                anyhow::bail!("Expected {size} bytes for type: {kind}");
+            }
+            let mut word = [0; 32];
+            word[..size].copy_from_slice(&bytes);
+            Ok(word)
+        }
+        _ if kind.starts_with("uint") => {
+            let bits = integer_bits(kind, "uint")?;
+            let number = value_to_u256(value)?;
+            if number.bits() > bits {
+                anyhow::bail!("Value {number} is out of range for type: {kind}");
+            }
+            Ok(u256_to_word(number))
+        }
+        _ if kind.starts_with("int") => {
+            value_to_i256(value, integer_bits(kind, "int")?).map(u256_to_word)
+        }
+        _ => anyhow::bail!("Unsupported EIP-712 type: {kind}"),
+    }
+}
+
+/// Split `Type[]` or `Type[N]` into the type of items and an optional fixed length.
+fn split_array_type(kind: &str) -> anyhow::Result<Option<(&str, Option<usize>)>> {
+    let Some((item_kind, length)) = kind.strip_suffix(']').and_then(|kind| {
+        kind.rfind('[')
+            .map(|open| (&kind[..open], &kind[open + 1..]))
+    }) else {
+        return Ok(None);
+    };
+    let length = if length.is_empty() {
+        None
+    } else {
+        Some(
+            length
+                .parse()
+                .map_err(|_| anyhow::anyhow!("Wrong array length in EIP-712 type: {kind}"))?,
+        )
+    };
+
+    Ok(Some((item_kind, length)))
+}
+
+/// Size in bits of `uintN` or `intN`, which is 256 if `N` is omitted.
+fn integer_bits(kind: &str, prefix: &str) -> anyhow::Result<usize> {
+    let size = &kind[prefix.len()..];
+
+    if size.is_empty() {
+        return Ok(256);
+    }
+
+    size.parse()
+        .ok()
+        .filter(|bits| bits % 8 == 0 && (8..=256).contains(bits))
+        .ok_or_else(|| anyhow::anyhow!("Unsupported EIP-712 type: {kind}"))
+}
+
+fn base_type(kind: &str) -> &str {
+    kind.find('[').map_or(kind, |pos| &kind[..pos])
+}
+
+fn value_to_bytes(value: &Value) -> anyhow::Result<Vec<u8>> {
+    value
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("Expected hex string, got: {value}"))
+        .and_then(super::hex_to_vec)
+}
+
+fn value_to_u256(value: &Value) -> anyhow::Result<U256> {
+    match value {
+        Value::Number(number) => number
+            .as_u64()
+            .map(U256::from)
+            .ok_or_else(|| anyhow::anyhow!("Expected unsigned integer, got: {number}")),
+        Value::String(s) => s.strip_prefix("0x").map_or_else(
+            || U256::from_dec_str(s).map_err(|e| anyhow::anyhow!("{e:?}")),
+            |hex| U256::from_str_radix(hex, 16).map_err(Into::into),
+        ),
+        _ => anyhow::bail!("Expected integer, got: {value}"),
+    }
+}
+
+fn value_to_i256(value: &Value, bits: usize) -> anyhow::Result<U256> {
+    let (negative, abs) = match value {
+        Value::Number(number) => number.as_i64().map_or_else(
+            || value_to_u256(value).map(|abs| (false, abs)),
+            |n| Ok((n < 0, U256::from(n.unsigned_abs()))),
+        )?,
+        Value::String(s) => s.strip_prefix('-').map_or_else(
+            || value_to_u256(value).map(|abs| (false, abs)),
+            |abs| value_to_u256(&Value::String(abs.to_string())).map(|abs| (true, abs)),
+        )?,
+        _ => anyhow::bail!("Expected integer, got: {value}"),
+    };
+    // The range of `intN` is from `-2^(N-1)` to `2^(N-1) - 1`.
+    let limit = U256::one() << (bits - 1);
+
+    if abs > limit || (!negative && abs == limit) {
+        anyhow::bail!("Value {value} is out of range for type: int{bits}");
+    }
+
+    // Two's complement representation of the negative value.
+    Ok(if negative {
+        U256::zero().overflowing_sub(abs).0
+    } else {
+        abs
+    })
+}
+
+fn u256_to_word(value: U256) -> [u8; 32] {
+    value.to_big_endian()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAIL: &str = r#"{
+        "types": {
+            "EIP712Domain": [
+                {"name": "name", "type": "string"},
+                {"name": "version", "type": "string"},
+                {"name": "chainId", "type": "uint256"},
+                {"name": "verifyingContract", "type": "address"}
+            ],
+            "Person": [
+                {"name": "name", "type": "string"},
+                {"name": "wallet", "type": "address"}
+            ],
+            "Mail": [
+                {"name": "from", "type": "Person"},
+                {"name": "to", "type": "Person"},
+                {"name": "contents", "type": "string"}
+            ]
+        },
+        "primaryType": "Mail",
+        "domain": {
+            "name": "Ether Mail",
+            "version": "1",
+            "chainId": 1,
+            "verifyingContract": "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"
+        },
+        "message": {
+            "from": {"name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"},
+            "to": {"name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB"},
+            "contents": "Hello, Bob!"
+        }
+    }"#;
+
+    fn h256(hex: &str) -> H256 {
+        H256::from_slice(&hex::decode(hex).unwrap())
+    }
+
+    #[test]
+    fn test_encode_type() {
+        let data = TypedData::from_json(MAIL).unwrap();
+
+        assert_eq!(
+            encode_type(&data.types, "Mail").unwrap(),
+            "Mail(Person from,Person to,string contents)Person(string name,address wallet)"
+        );
+    }
+
+    #[test]
+    fn test_spec_example_hashes() {
+        let data = TypedData::from_json(MAIL).unwrap();
+
+        assert_eq!(
+            data.domain_separator().unwrap(),
+            h256("f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f")
+        );
+        assert_eq!(
+            data.struct_hash().unwrap(),
+            h256("c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e")
+        );
+        assert_eq!(
+            data.signing_hash().unwrap(),
+            h256("be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2")
+        );
+    }
+
+    #[test]
+    fn test_implicit_domain_type() {
+        let mut json: Value = serde_json::from_str(MAIL).unwrap();
+        json["types"]
+            .as_object_mut()
+            .unwrap()
+            .remove("EIP712Domain");
+        let data = TypedData::from_json(&json.to_string()).unwrap();
+
+        assert_eq!(
+            data.domain_separator().unwrap(),
+            h256("f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f")
+        );
+    }
+
+    #[test]
+    fn test_arrays_and_nested_types() {
+        let json = r#"{
+            "types": {
+                "Person": [
+                    {"name": "name", "type": "string"},
+                    {"name": "wallets", "type": "address[]"}
+                ],
+                "Group": [
+                    {"name": "members", "type": "Person[]"},
+                    {"name": "scores", "type": "int8[2]"}
+                ]
+            },
+            "primaryType": "Group",
+            "domain": {"name": "Groups", "chainId": "0x4e454152"},
+            "message": {
+                "members": [
+                    {"name": "Cow", "wallets": ["0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"]},
+                    {"name": "Bob", "wallets": []}
+                ],
+                "scores": [-1, "2"]
+            }
+        }"#;
+        let data = TypedData::from_json(json).unwrap();
+
+        assert_eq!(
+            encode_type(&data.types, "Group").unwrap(),
+            "Group(Person[] members,int8[2] scores)Person(string name,address[] wallets)"
+        );
+
+        let person_hash = |name: &str, wallets: &[u8]| {
+            let type_hash = keccak(b"Person(string name,address[] wallets)");
+            keccak(
+                &[
+                    type_hash.as_bytes(),
+                    keccak(name.as_bytes()).as_bytes(),
+                    keccak(wallets).as_bytes(),
+                ]
+                .concat(),
+            )
+        };
+        let mut wallet = [0; 32];
+        wallet[12..]
+            .copy_from_slice(&hex::decode("CD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826").unwrap());
+        let members = [person_hash("Cow", &wallet).0, person_hash("Bob", &[]).0].concat();
+        let mut scores = [0xff; 64];
+        scores[32..].copy_from_slice(&u256_to_word(U256::from(2)));
+        let expected = keccak(
+            &[
+                keccak(encode_type(&data.types, "Group").unwrap().as_bytes()).as_bytes(),
+                keccak(&members).as_bytes(),
+                keccak(&scores).as_bytes(),
+            ]
+            .concat(),
+        );
+
+        assert_eq!(data.struct_hash().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_wrong_fixed_array_length() {
+        let json = r#"{
+            "types": {"Data": [{"name": "values", "type": "uint256[2]"}]},
+            "primaryType": "Data",
+            "domain": {"name": "Test"},
+            "message": {"values": [1]}
+        }"#;
+        let data = TypedData::from_json(json).unwrap();
+
+        assert!(data.struct_hash().is_err());
+    }
+
+    #[test]
+    fn test_wrong_array_length_and_integer_range() {
+        let data = |kind: &str, value: &str| {
+            TypedData::from_json(&format!(
+                r#"{{
+                    "types": {{"Data": [{{"name": "value", "type": "{kind}"}}]}},
+                    "primaryType": "Data",
+                    "domain": {{"name": "Test"}},
+                    "message": {{"value": {value}}}
+                }}"#
+            ))
+            .unwrap()
+            .struct_hash()
+        };
+
+        assert!(data("uint8[abc]", "[1]").is_err());
+        assert!(data("uint8", "255").is_ok());
+        assert!(data("uint8", "300").is_err());
+        assert!(data("int8", "-128").is_ok());
+        assert!(data("int8", "128").is_err());
+        assert!(data("int8", "-129").is_err());
+        assert!(data("uint7", "1").is_err());
+        assert!(data("uint", "\"0xff\"").is_ok());
+    }
+}
@@ -1,14 +1,76 @@
-use aurora_engine_transactions::legacy::{LegacyEthSignedTransaction, TransactionLegacy};
-use aurora_engine_types::{U256, types::Address};
+#[cfg(feature = "simple")]
+use aurora_engine_types::U256;
+use aurora_engine_types::types::Address;
+use aurora_sdk_rs::aurora::signer::{EvmSigner, LocalSigner, RemoteSigner};
+#[cfg(feature = "simple")]
 use libsecp256k1::{Message, PublicKey, SecretKey};
 use near_crypto::InMemorySigner;
+#[cfg(feature = "simple")]
 use rlp::RlpStream;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 
+use crate::error::Error;
+
 pub mod abi;
 #[cfg(feature = "simple")]
+pub mod eip712;
+#[cfg(feature = "simple")]
 pub mod ft_metadata;
+// The advanced CLI only sets and gets the paused flags of the ETH connector.
+#[cfg_attr(not(feature = "simple"), allow(dead_code))]
+pub mod pausable;
+pub mod trace;
+// The advanced CLI only deploys the engine, not the XCC router.
+#[cfg_attr(not(feature = "simple"), allow(dead_code))]
+pub mod wasm;
+
+/// Environment variable with the password of the EVM keystore file.
+pub const KEYSTORE_PASSWORD_ENV: &str = "AURORA_KEYSTORE_PASSWORD";
+
+/// Options selecting the signer of EVM transactions, given on the command line or in the config.
+#[derive(Debug, Default)]
+pub struct EvmSignerOptions {
+    /// Secret key in hex.
+    pub secret_key: Option<String>,
+    /// Path to the encrypted JSON keystore file.
+    pub keystore: Option<String>,
+    pub keystore_password: Option<String>,
+    /// URL of the remote signer: `http(s)://` or `unix://`.
+    pub remote_signer: Option<String>,
+    /// Address of the account managed by the remote signer.
+    pub signer_address: Option<Address>,
+}
+
+impl EvmSignerOptions {
+    /// Create the signer from the secret key, the keystore file or the remote signer, whichever
+    /// is given first in this order.
+    pub fn into_signer(self) -> anyhow::Result<Box<dyn EvmSigner>> {
+        if let Some(key) = self.secret_key {
+            Ok(Box::new(LocalSigner::from_hex(&key)?))
+        } else if let Some(path) = self.keystore {
+            let password = self.keystore_password.ok_or_else(|| {
+                Error::Usage(format!(
+                    "The keystore password must be given with --keystore-password or {KEYSTORE_PASSWORD_ENV}"
+                ))
+            })?;
+            Ok(Box::new(LocalSigner::from_keystore(path, &password)?))
+        } else if let Some(url) = self.remote_signer {
+            let address = self.signer_address.ok_or_else(|| {
+                Error::Usage(
+                    "The remote signer requires the EVM address of its account".to_string(),
+                )
+            })?;
+            Ok(Box::new(RemoteSigner::new(&url, address)?))
+        } else {
+            Err(Error::Usage(
+                "EVM transactions require a signer: a secret key, a keystore file or a remote signer"
+                    .to_string(),
+            )
+            .into())
+        }
+    }
+}
 
 #[allow(dead_code)]
 #[cfg(feature = "simple")]
@@ -22,6 +84,75 @@ pub fn hex_to_address(h: &str) -> anyhow::Result<Address> {
     hex_to_arr(h).map(Address::from_array)
 }
 
+/// Return the address in the mixed-case checksum encoding according to EIP-55.
+#[must_use]
+pub fn to_checksum_address(address: &Address) -> String {
+    let hex = address.encode();
+    let hash = aurora_engine_sdk::keccak(hex.as_bytes());
+    let checksum = hex
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = (hash[i / 2] >> (if i % 2 == 0 { 4 } else { 0 })) & 0x0f;
+            if nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect::<String>();
+
+    format!("0x{checksum}")
+}
+
+/// Check the EIP-55 checksum of the address. Addresses in a single case carry no checksum
+/// and are considered valid.
+#[must_use]
+pub fn is_valid_checksum(h: &str) -> bool {
+    let hex = h.strip_prefix("0x").unwrap_or(h);
+
+    if hex.chars().all(|c| !c.is_ascii_uppercase()) || hex.chars().all(|c| !c.is_ascii_lowercase())
+    {
+        return true;
+    }
+
+    hex_to_arr(hex)
+        .map(Address::from_array)
+        .is_ok_and(|address| to_checksum_address(&address)[2..] == *hex)
+}
+
+/// Return the address of the contract created by the sender with the nonce (`CREATE` opcode).
+#[cfg(feature = "simple")]
+#[must_use]
+pub fn create_address(sender: Address, nonce: U256) -> Address {
+    let mut stream = RlpStream::new_list(2);
+    stream.append(&sender.as_bytes());
+    stream.append(&nonce);
+    let hash = aurora_engine_sdk::keccak(&stream.out());
+
+    Address::try_from_slice(&hash[12..]).expect("the slice is 20 bytes long")
+}
+
+/// Return the address of the contract created by the sender with the salt and the hash of
+/// the init code (`CREATE2` opcode).
+#[cfg(feature = "simple")]
+#[must_use]
+pub fn create2_address(
+    sender: Address,
+    salt: aurora_engine_types::H256,
+    init_code_hash: aurora_engine_types::H256,
+) -> Address {
+    let mut bytes = Vec::with_capacity(85);
+    bytes.push(0xff);
+    bytes.extend_from_slice(sender.as_bytes());
+    bytes.extend_from_slice(salt.as_bytes());
+    bytes.extend_from_slice(init_code_hash.as_bytes());
+    let hash = aurora_engine_sdk::keccak(&bytes);
+
+    Address::try_from_slice(&hash[12..]).expect("the slice is 20 bytes long")
+}
+
+#[cfg(feature = "simple")]
 pub fn address_from_secret_key(sk: &SecretKey) -> anyhow::Result<Address> {
     let pk = PublicKey::from_secret_key(sk);
     let hash = aurora_engine_sdk::keccak(&pk.serialize()[1..]);
@@ -29,33 +160,55 @@ pub fn address_from_secret_key(sk: &SecretKey) -> anyhow::Result<Address> {
         .map_err(|e| anyhow::anyhow!("Couldn't create address from secret key: {e}"))
 }
 
+#[cfg(feature = "simple")]
 pub fn secret_key_from_hex(key: &str) -> anyhow::Result<SecretKey> {
     hex_to_arr(key.trim())
         .and_then(|bytes| SecretKey::parse(&bytes).map_err(Into::into))
-        .map_err(|e| anyhow::anyhow!("Couldn't create secret key from hex: {e}"))
+        .map_err(|e| Error::Usage(format!("Couldn't create secret key from hex: {e}")).into())
 }
 
+/// Hash a message according to EIP-191 (`personal_sign`).
+#[cfg(feature = "simple")]
 #[must_use]
-pub fn sign_transaction(
-    tx: TransactionLegacy,
-    chain_id: u64,
-    secret_key: &SecretKey,
-) -> LegacyEthSignedTransaction {
-    let mut rlp_stream = RlpStream::new();
-    tx.rlp_append_unsigned(&mut rlp_stream, Some(chain_id));
-    let message_hash = aurora_engine_sdk::keccak(rlp_stream.as_raw());
-    let message = Message::parse_slice(message_hash.as_bytes()).unwrap();
+pub fn hash_personal_message(message: &[u8]) -> aurora_engine_types::H256 {
+    let prefix = format!("\x19Ethereum Signed Message:\n{}", message.len());
+    aurora_engine_sdk::keccak(&[prefix.as_bytes(), message].concat())
+}
 
+/// Sign the hash and return the signature in the `r ‖ s ‖ v` format, where `v` is 27 or 28.
+#[cfg(feature = "simple")]
+#[must_use]
+pub fn sign_hash(hash: aurora_engine_types::H256, secret_key: &SecretKey) -> [u8; 65] {
+    let message = Message::parse(&hash.0);
     let (signature, recovery_id) = libsecp256k1::sign(&message, secret_key);
-    let v: u64 = (u64::from(recovery_id.serialize())) + 2 * chain_id + 35;
-    let r = U256::from_big_endian(&signature.r.b32());
-    let s = U256::from_big_endian(&signature.s.b32());
-    LegacyEthSignedTransaction {
-        transaction: tx,
-        v,
-        r,
-        s,
-    }
+    let mut output = [0; 65];
+
+    output[..64].copy_from_slice(&signature.serialize());
+    output[64] = recovery_id.serialize() + 27;
+    output
+}
+
+/// Recover the address of the signer from the hash and the signature in the `r ‖ s ‖ v` format.
+#[cfg(feature = "simple")]
+pub fn recover_address(
+    hash: aurora_engine_types::H256,
+    signature: &[u8],
+) -> anyhow::Result<Address> {
+    let signature: &[u8; 65] = signature
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("Signature must be 65 bytes long"))?;
+    let v = match signature[64] {
+        v @ (0 | 1) => v,
+        v @ (27 | 28) => v - 27,
+        v => anyhow::bail!("Invalid recovery id: {v}"),
+    };
+    let recovery_id = libsecp256k1::RecoveryId::parse(v)?;
+    let signature = libsecp256k1::Signature::parse_standard_slice(&signature[..64])?;
+    let public_key = libsecp256k1::recover(&Message::parse(&hash.0), &signature, &recovery_id)?;
+    let hash = aurora_engine_sdk::keccak(&public_key.serialize()[1..]);
+
+    Address::try_from_slice(&hash[12..])
+        .map_err(|e| anyhow::anyhow!("Couldn't create address from public key: {e}"))
 }
 
 pub fn read_key_file<P: AsRef<Path>>(path: P) -> anyhow::Result<InMemorySigner> {
@@ -77,8 +230,9 @@ pub fn read_key_file<P: AsRef<Path>>(path: P) -> anyhow::Result<InMemorySigner>
 }
 
 pub fn hex_to_vec(hex: &str) -> anyhow::Result<Vec<u8>> {
-    hex::decode(hex.trim_start_matches("0x"))
-        .map_err(|e| anyhow::anyhow!("Couldn't create vector from the hex: {hex}, {e}"))
+    hex::decode(hex.trim_start_matches("0x")).map_err(|e| {
+        Error::Usage(format!("Couldn't create vector from the hex: {hex}, {e}")).into()
+    })
 }
 
 pub fn hex_to_arr<const SIZE: usize>(hex: &str) -> anyhow::Result<[u8; SIZE]> {
@@ -86,7 +240,7 @@ pub fn hex_to_arr<const SIZE: usize>(hex: &str) -> anyhow::Result<[u8; SIZE]> {
 
     hex::decode_to_slice(hex.trim_start_matches("0x"), &mut output)
         .map(|()| output)
-        .map_err(|e| anyhow::anyhow!("Couldn't create array from the hex: {hex}, {e}"))
+        .map_err(|e| Error::Usage(format!("Couldn't create array from the hex: {hex}, {e}")).into())
 }
 
 #[derive(Serialize, Deserialize)]
@@ -123,7 +277,6 @@ struct KeyFileWithoutPublicKey {
 }
 
 /// Converts NEAR into yocto. 1NEAR == 10^24 yocto.
-#[cfg(feature = "simple")]
 #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
 #[must_use]
 pub fn near_to_yocto(near: f64) -> u128 {
@@ -160,6 +313,54 @@ fn test_address_from_hex() {
     assert!(hex_to_address("some_address").is_err());
 }
 
+#[test]
+fn test_checksum_address() {
+    // Test vectors from the EIP-55 specification.
+    for expected in [
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+        "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+        "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+        "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+    ] {
+        let address = hex_to_address(&expected.to_lowercase()).unwrap();
+        assert_eq!(to_checksum_address(&address), expected);
+        assert!(is_valid_checksum(expected));
+    }
+
+    assert!(is_valid_checksum(
+        "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"
+    ));
+    assert!(is_valid_checksum(
+        "0x5AAEB6053F3E94C9B9A09F33669435E7EF1BEAED"
+    ));
+    assert!(!is_valid_checksum(
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD"
+    ));
+}
+
+#[test]
+#[cfg(feature = "simple")]
+fn test_create_addresses() {
+    let sender = hex_to_address("0x6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0").unwrap();
+    assert_eq!(
+        create_address(sender, U256::zero()),
+        hex_to_address("0xcd234a471b72ba2f1ccf0a70fcaba648a5eecd8d").unwrap()
+    );
+    assert_eq!(
+        create_address(sender, U256::one()),
+        hex_to_address("0x343c43a37d37dff08ae8c4a11544c718abb4fcf8").unwrap()
+    );
+
+    // Example 5 from the EIP-1014 specification.
+    let sender = hex_to_address("0x00000000000000000000000000000000deadbeef").unwrap();
+    let salt = aurora_engine_types::H256::from_low_u64_be(0xcafe_babe);
+    let init_code = hex_to_vec("0xdeadbeef").unwrap();
+    assert_eq!(
+        create2_address(sender, salt, aurora_engine_sdk::keccak(&init_code)),
+        hex_to_address("0x60f3f640a8508fC6a86d45DF051962668E1e8AC7").unwrap()
+    );
+}
+
 #[test]
 fn test_parsing_key_file() {
     let file = std::env::temp_dir().join("key_file.json");
@@ -187,6 +388,38 @@ fn test_parsing_key_file() {
 
 #[test]
 #[cfg(feature = "simple")]
+fn test_evm_signer_options() {
+    let usage = |options: EvmSignerOptions| {
+        options
+            .into_signer()
+            .err()
+            .and_then(|e| e.downcast::<Error>().ok())
+            .is_some_and(|e| matches!(e, Error::Usage(_)))
+    };
+    let key = "0x".to_string() + &"01".repeat(32);
+    let address = address_from_secret_key(&secret_key_from_hex(&key).unwrap()).unwrap();
+
+    let signer = EvmSignerOptions {
+        secret_key: Some(key),
+        remote_signer: Some("http://localhost:1".to_string()),
+        ..Default::default()
+    }
+    .into_signer()
+    .unwrap();
+    assert_eq!(signer.address(), address);
+
+    assert!(usage(EvmSignerOptions::default()));
+    assert!(usage(EvmSignerOptions {
+        keystore: Some("keystore.json".to_string()),
+        ..Default::default()
+    }));
+    assert!(usage(EvmSignerOptions {
+        remote_signer: Some("http://localhost:1".to_string()),
+        ..Default::default()
+    }));
+}
+
+#[test]
 fn test_convert_near_to_yocto() {
     assert_eq!(near_to_yocto(1.0), 10_u128.pow(24));
     assert_eq!(near_to_yocto(1.125), 1125 * 10_u128.pow(21));
@@ -214,3 +447,40 @@ fn test_gen_key_pair() {
     assert_eq!(sk, expected);
     assert_eq!(address, address_from_secret_key(&expected).unwrap());
 }
+
+#[test]
+#[cfg(feature = "simple")]
+fn test_hash_personal_message() {
+    assert_eq!(
+        hash_personal_message(b"hello"),
+        aurora_engine_types::H256::from_slice(
+            &hex::decode("50b2c43fd39106bafbba0da34fc430e1f91e3c96ea2acee2bc34119f92b37750")
+                .unwrap()
+        )
+    );
+}
+
+#[test]
+#[cfg(feature = "simple")]
+fn test_sign_and_recover_typed_data() {
+    // The example from the EIP-712 specification, the key is `keccak256("cow")`.
+    let sk =
+        secret_key_from_hex("c85ef7d79691fe79573b1a7064c19c1a9819ebdbd1faaab1a8ec92344438aaf4")
+            .unwrap();
+    let hash = aurora_engine_types::H256::from_slice(
+        &hex::decode("be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2").unwrap(),
+    );
+    let signature = sign_hash(hash, &sk);
+
+    assert_eq!(
+        hex::encode(signature),
+        "4355c47d63924e8a72e509b65029052eb6c299d53a04e167c5775fd466751c9d\
+         07299936d304c153f6443dfa05f40ff007d72911b6f72307f996231605b91562\
+         1c"
+    );
+    assert_eq!(
+        recover_address(hash, &signature).unwrap(),
+        hex_to_address("0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826").unwrap()
+    );
+    assert!(recover_address(hash, &signature[..64]).is_err());
+}
@@ -0,0 +1,283 @@
+//! Names of the bits of the masks of paused precompiles and paused ETH connector features.
+use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use aurora_engine_precompiles::native::{exit_to_ethereum, exit_to_near};
+use aurora_engine_types::types::Address;
+use serde_json::{Value, json};
+
+use crate::error::Error;
+
+/// Pausable item with its bit in the mask.
+pub trait Flag: Copy + Display + 'static {
+    /// All the items, in the order of their bits.
+    const ALL: &'static [Self];
+    /// What the items are, used in error messages.
+    const KIND: &'static str;
+
+    fn name(self) -> &'static str;
+
+    fn bit(self) -> u32;
+
+    /// The item in the structured output.
+    fn to_value(self) -> Value {
+        self.name().into()
+    }
+}
+
+/// Precompile which could be paused, with its bit in `PrecompileFlags` of the engine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Precompile {
+    ExitToNear,
+    ExitToEthereum,
+}
+
+impl Precompile {
+    #[must_use]
+    pub const fn address(self) -> Address {
+        match self {
+            Self::ExitToNear => exit_to_near::ADDRESS,
+            Self::ExitToEthereum => exit_to_ethereum::ADDRESS,
+        }
+    }
+}
+
+impl Flag for Precompile {
+    const ALL: &'static [Self] = &[Self::ExitToNear, Self::ExitToEthereum];
+    const KIND: &'static str = "precompile";
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::ExitToNear => "exit-to-near",
+            Self::ExitToEthereum => "exit-to-ethereum",
+        }
+    }
+
+    fn bit(self) -> u32 {
+        match self {
+            Self::ExitToNear => 0b01,
+            Self::ExitToEthereum => 0b10,
+        }
+    }
+
+    fn to_value(self) -> Value {
+        json!({
+            "name": self.name(),
+            "address": format!("0x{}", self.address().encode()),
+        })
+    }
+}
+
+impl Display for Precompile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (0x{})", self.name(), self.address().encode())
+    }
+}
+
+/// Feature of the ETH connector which could be paused, with its bit in `PausedMask`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectorFlag {
+    Deposit,
+    Withdraw,
+}
+
+impl Flag for ConnectorFlag {
+    const ALL: &'static [Self] = &[Self::Deposit, Self::Withdraw];
+    const KIND: &'static str = "paused flag";
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Deposit => "deposit",
+            Self::Withdraw => "withdraw",
+        }
+    }
+
+    fn bit(self) -> u32 {
+        match self {
+            Self::Deposit => 0b01,
+            Self::Withdraw => 0b10,
+        }
+    }
+}
+
+impl Display for ConnectorFlag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Mask of the paused items. It is parsed from comma-separated names, `all`, `none` or a number
+/// without unknown bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Mask<F> {
+    bits: u32,
+    flag: PhantomData<F>,
+}
+
+pub type PrecompileMask = Mask<Precompile>;
+pub type PausedFlags = Mask<ConnectorFlag>;
+
+impl<F: Flag> Mask<F> {
+    /// The mask as is, with unknown bits if there are any.
+    #[must_use]
+    pub const fn from_bits(bits: u32) -> Self {
+        Self {
+            bits,
+            flag: PhantomData,
+        }
+    }
+
+    #[must_use]
+    pub fn all() -> Self {
+        Self::from_bits(F::ALL.iter().fold(0, |bits, flag| bits | flag.bit()))
+    }
+
+    #[must_use]
+    pub const fn bits(self) -> u32 {
+        self.bits
+    }
+
+    /// The items whose bits are set.
+    pub fn flags(self) -> impl Iterator<Item = F> {
+        F::ALL
+            .iter()
+            .copied()
+            .filter(move |flag| self.bits & flag.bit() != 0)
+    }
+
+    /// The set bits which don't match any known item.
+    #[must_use]
+    pub fn unknown_bits(self) -> u32 {
+        self.bits & !Self::all().bits
+    }
+
+    /// The mask with the names of the set items in the structured output.
+    #[must_use]
+    pub fn to_value(self) -> Value {
+        json!({
+            "mask": self.bits,
+            "paused": self.flags().map(Flag::to_value).collect::<Vec<_>>(),
+        })
+    }
+
+    fn names() -> String {
+        F::ALL
+            .iter()
+            .map(|flag| flag.name())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+impl<F: Flag> FromStr for Mask<F> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "all" => return Ok(Self::all()),
+            "none" => return Ok(Self::from_bits(0)),
+            _ => {}
+        }
+
+        if let Ok(bits) = s.trim().parse() {
+            let mask = Self::from_bits(bits);
+
+            return if mask.unknown_bits() == 0 {
+                Ok(mask)
+            } else {
+                Err(Error::Usage(format!(
+                    "Unknown bits 0b{:b} in the {} mask {bits}, known ones are: {}",
+                    mask.unknown_bits(),
+                    F::KIND,
+                    Self::names()
+                )))
+            };
+        }
+
+        s.split(',')
+            .map(str::trim)
+            .try_fold(Self::from_bits(0), |mask, name| {
+                F::ALL
+                    .iter()
+                    .find(|flag| flag.name() == name)
+                    .map(|flag| Self::from_bits(mask.bits | flag.bit()))
+                    .ok_or_else(|| {
+                        Error::Usage(format!(
+                            "Unknown {}: {name}, expected one of: {}, all, none",
+                            F::KIND,
+                            Self::names()
+                        ))
+                    })
+            })
+    }
+}
+
+impl<F: Flag> Display for Mask<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut items = self
+            .flags()
+            .map(|flag| flag.to_string())
+            .collect::<Vec<_>>();
+
+        if self.unknown_bits() != 0 {
+            items.push(format!("unknown bits 0b{:b}", self.unknown_bits()));
+        }
+
+        if items.is_empty() {
+            f.write_str("none")
+        } else {
+            f.write_str(&items.join(", "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_mask() {
+        assert_eq!(
+            "exit-to-near".parse::<PrecompileMask>().unwrap().bits(),
+            0b01
+        );
+        assert_eq!(
+            "exit-to-near, exit-to-ethereum"
+                .parse::<PrecompileMask>()
+                .unwrap(),
+            PrecompileMask::all()
+        );
+        assert_eq!("2".parse::<PrecompileMask>().unwrap().bits(), 0b10);
+        assert_eq!("none".parse::<PausedFlags>().unwrap().bits(), 0);
+        assert_eq!("all".parse::<PausedFlags>().unwrap().bits(), 0b11);
+        assert_eq!("withdraw".parse::<PausedFlags>().unwrap().bits(), 0b10);
+
+        assert!(
+            "4".parse::<PrecompileMask>()
+                .unwrap_err()
+                .to_string()
+                .starts_with("Unknown bits 0b100")
+        );
+        assert!(
+            "cross-contract-call"
+                .parse::<PrecompileMask>()
+                .unwrap_err()
+                .to_string()
+                .contains("expected one of: exit-to-near, exit-to-ethereum")
+        );
+    }
+
+    #[test]
+    fn test_display_mask() {
+        assert_eq!(
+            PrecompileMask::from_bits(0b01).to_string(),
+            "exit-to-near (0xe9217bc70b7ed1f598ddd3199e80b093fa71124f)"
+        );
+        assert_eq!(
+            PausedFlags::from_bits(0b111).to_string(),
+            "deposit, withdraw, unknown bits 0b100"
+        );
+        assert_eq!(PausedFlags::from_bits(0).to_string(), "none");
+    }
+}
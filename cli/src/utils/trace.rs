@@ -0,0 +1,49 @@
+//! Logging of the CLI and its RPC traffic to stderr or a file.
+use std::fs::OpenOptions;
+use std::path::Path;
+use std::sync::Mutex;
+
+use aurora_sdk_rs::near::trace::TARGET as NEAR_RPC;
+use tracing_subscriber::EnvFilter;
+
+/// Target of the events with the Aurora (Web3) JSON-RPC traffic.
+pub const AURORA_RPC: &str = "aurora_rpc";
+
+/// Install the global subscriber of the `tracing` events.
+///
+/// `-v` logs every RPC call with its method, endpoint and latency, or the error, and the retries
+/// of the polled ones, `-vv` and `--trace-rpc` log their params and responses as well. The log is
+/// written to stderr unless `--trace-rpc` is given a file other than `-`, which the log is
+/// appended to. The `RUST_LOG` environment
+/// variable overrides the filter.
+pub fn init(verbose: u8, trace_rpc: Option<&Path>) -> anyhow::Result<()> {
+    let level = match verbose {
+        0 => "warn",
+        1 => "info",
+        _ => "debug",
+    };
+    let rpc_level = match verbose {
+        _ if trace_rpc.is_some() => "trace",
+        0 => "warn",
+        1 => "debug",
+        _ => "trace",
+    };
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| {
+        EnvFilter::new(format!(
+            "warn,aurora_cli={level},aurora_sdk_rs={level},{NEAR_RPC}={rpc_level},{AURORA_RPC}={rpc_level}"
+        ))
+    });
+    let builder = tracing_subscriber::fmt().with_env_filter(filter);
+
+    match trace_rpc.filter(|path| *path != Path::new("-")) {
+        Some(path) => {
+            let file = OpenOptions::new().create(true).append(true).open(path)?;
+            builder
+                .with_ansi(false)
+                .with_writer(Mutex::new(file))
+                .try_init()
+        }
+        None => builder.with_writer(std::io::stderr).try_init(),
+    }
+    .map_err(|e| anyhow::anyhow!("Couldn't initialize logging: {e}"))
+}
@@ -0,0 +1,356 @@
+//! Inspection of the WASM code before it's deployed: its exports, the embedded engine version and
+//! the hashes, and whether it looks like the contract the command expects.
+use near_primitives::hash::CryptoHash;
+use serde::Serialize;
+
+use crate::error::Error;
+
+const MAGIC: &[u8] = b"\0asm";
+const VERSION: &[u8] = &[1, 0, 0, 0];
+const EXPORT_SECTION: u8 = 7;
+const DATA_SECTION: u8 = 11;
+const FUNCTION_EXPORT: u8 = 0;
+
+/// Methods every engine release exports.
+const ENGINE_EXPORTS: &[&str] = &[
+    "new",
+    "submit",
+    "call",
+    "deploy_code",
+    "view",
+    "get_version",
+    "get_owner",
+    "stage_upgrade",
+    "deploy_upgrade",
+];
+/// Methods of the XCC router, which the engine doesn't export.
+const ROUTER_EXPORTS: &[&str] = &["initialize", "execute", "schedule", "execute_scheduled"];
+
+/// Contract the code is expected to be.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Contract {
+    /// Aurora EVM
+    Engine,
+    /// Router of the cross-contract calls deployed to the XCC sub-accounts
+    XccRouter,
+}
+
+/// Summary of the WASM module.
+#[derive(Debug, Serialize)]
+pub struct WasmInfo {
+    pub size: usize,
+    /// SHA-256 of the code in hex.
+    pub sha256: String,
+    /// Hash of the code as NEAR reports it in `view_account` and `view_code`.
+    pub code_hash: CryptoHash,
+    /// Engine version embedded in the data, as returned by `get_version`.
+    pub version: Option<String>,
+    /// Exported functions, which are the methods of the contract.
+    pub exports: Vec<String>,
+}
+
+impl WasmInfo {
+    /// Parse the module. The code which isn't a WASM module is a usage error.
+    pub fn parse(code: &[u8]) -> anyhow::Result<Self> {
+        let (exports, data) = sections(code)
+            .map_err(|e| Error::Usage(format!("The file isn't a valid WASM module: {e}")))?;
+        let code_hash = CryptoHash::hash_bytes(code);
+
+        Ok(Self {
+            size: code.len(),
+            sha256: hex::encode(code_hash.0),
+            code_hash,
+            version: data.iter().find_map(|segment| find_version(segment)),
+            exports,
+        })
+    }
+
+    /// Engine methods which the module doesn't export.
+    #[must_use]
+    pub fn missing_engine_exports(&self) -> Vec<&'static str> {
+        ENGINE_EXPORTS
+            .iter()
+            .copied()
+            .filter(|name| !self.exports.iter().any(|export| export == name))
+            .collect()
+    }
+
+    /// Whether the module exports the methods of the XCC router and isn't the engine.
+    #[must_use]
+    pub fn is_xcc_router(&self) -> bool {
+        !self.exports.iter().any(|export| export == "submit")
+            && ROUTER_EXPORTS
+                .iter()
+                .all(|name| self.exports.iter().any(|export| export == name))
+    }
+
+    /// Warnings about the module if it doesn't look like the expected contract.
+    #[must_use]
+    pub fn warnings(&self, expected: Contract) -> Vec<String> {
+        let mut warnings = vec![];
+
+        match expected {
+            Contract::Engine => {
+                if self.is_xcc_router() {
+                    warnings.push("the file looks like the XCC router, not the engine".to_string());
+                }
+
+                let missing = self.missing_engine_exports();
+                if !missing.is_empty() {
+                    warnings.push(format!(
+                        "the engine exports are missing: {}",
+                        missing.join(", ")
+                    ));
+                }
+            }
+            Contract::XccRouter => {
+                if self.missing_engine_exports().is_empty() {
+                    warnings.push("the file looks like the engine, not the XCC router".to_string());
+                } else if !self.is_xcc_router() {
+                    warnings.push("the file doesn't look like the XCC router".to_string());
+                }
+            }
+        }
+
+        warnings
+    }
+}
+
+/// Check the code before it's deployed: the code which isn't a WASM module is rejected, and
+/// the warnings are printed if it doesn't look like the expected contract.
+pub fn check(code: &[u8], expected: Contract) -> anyhow::Result<()> {
+    for warning in WasmInfo::parse(code)?.warnings(expected) {
+        eprintln!("Warning: {warning}");
+    }
+
+    Ok(())
+}
+
+/// Names of the exported functions and the contents of the data segments.
+fn sections(code: &[u8]) -> Result<(Vec<String>, Vec<&[u8]>), String> {
+    let mut reader = Reader::new(code);
+
+    if reader.bytes(4)? != MAGIC || reader.bytes(4)? != VERSION {
+        return Err("wrong magic number or version".to_string());
+    }
+
+    let mut exports = vec![];
+    let mut data = vec![];
+
+    while !reader.is_empty() {
+        let id = reader.byte()?;
+        let size = reader.u32()? as usize;
+        let mut section = Reader::new(reader.bytes(size)?);
+
+        match id {
+            EXPORT_SECTION => {
+                for _ in 0..section.u32()? {
+                    let name = section.name()?;
+                    let kind = section.byte()?;
+                    section.u32()?;
+
+                    if kind == FUNCTION_EXPORT {
+                        exports.push(name);
+                    }
+                }
+            }
+            DATA_SECTION => {
+                for _ in 0..section.u32()? {
+                    match section.u32()? {
+                        0 => section.const_expr()?,
+                        1 => {}
+                        2 => {
+                            section.u32()?;
+                            section.const_expr()?;
+                        }
+                        flags => return Err(format!("unknown data segment flags {flags}")),
+                    }
+                    let size = section.u32()? as usize;
+                    data.push(section.bytes(size)?);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok((exports, data))
+}
+
+/// Version of the engine is a semantic version followed by the line break, which is how it's
+/// stored in the `VERSION` file embedded into the code. Versions of crates in the paths of panic
+/// messages are preceded by the dash.
+fn find_version(data: &[u8]) -> Option<String> {
+    data.iter()
+        .enumerate()
+        .filter(|(_, byte)| **byte == b'\n')
+        .find_map(|(end, _)| {
+            let start = data[..end]
+                .iter()
+                .rposition(|byte| !byte.is_ascii_digit() && *byte != b'.')
+                .map_or(0, |position| position + 1);
+            let preceding = start.checked_sub(1).map(|position| data[position]);
+            let version = std::str::from_utf8(&data[start..end]).ok()?;
+            let parts = version.split('.').collect::<Vec<_>>();
+
+            (parts.len() == 3
+                && parts.iter().all(|part| !part.is_empty())
+                && preceding != Some(b'-'))
+            .then(|| version.to_string())
+        })
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    const fn new(data: &'a [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    const fn is_empty(&self) -> bool {
+        self.position >= self.data.len()
+    }
+
+    fn byte(&mut self) -> Result<u8, String> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], String> {
+        let end = self
+            .position
+            .checked_add(len)
+            .filter(|end| *end <= self.data.len())
+            .ok_or_else(|| "unexpected end of the module".to_string())?;
+        let bytes = &self.data[self.position..end];
+        self.position = end;
+
+        Ok(bytes)
+    }
+
+    /// Unsigned LEB128 integer.
+    fn u32(&mut self) -> Result<u32, String> {
+        let mut result = 0u32;
+
+        for shift in (0..35).step_by(7) {
+            let byte = self.byte()?;
+            result |= u32::from(byte & 0x7f) << shift;
+
+            if byte & 0x80 == 0 {
+                return Ok(result);
+            }
+        }
+
+        Err("too long integer".to_string())
+    }
+
+    fn name(&mut self) -> Result<String, String> {
+        let len = self.u32()? as usize;
+
+        String::from_utf8(self.bytes(len)?.to_vec()).map_err(|e| e.to_string())
+    }
+
+    /// Skip the constant expression of the offset of the data segment.
+    fn const_expr(&mut self) -> Result<(), String> {
+        loop {
+            match self.byte()? {
+                0x0b => return Ok(()),
+                // `i32.const`, `i64.const` and `global.get` with their LEB128 immediates.
+                0x41 | 0x42 | 0x23 => while self.byte()? & 0x80 != 0 {},
+                opcode => return Err(format!("unsupported opcode {opcode:#x} in the offset")),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vec(items: &[Vec<u8>]) -> Vec<u8> {
+        let mut bytes = vec![u8::try_from(items.len()).unwrap()];
+        bytes.extend(items.concat());
+        bytes
+    }
+
+    fn section(id: u8, contents: &[u8]) -> Vec<u8> {
+        [&[id, u8::try_from(contents.len()).unwrap()], contents].concat()
+    }
+
+    fn module(exports: &[&str], data: &[u8]) -> Vec<u8> {
+        let exports = exports
+            .iter()
+            .enumerate()
+            .map(|(index, name)| {
+                let index = u8::try_from(index).unwrap();
+                [
+                    &[u8::try_from(name.len()).unwrap()],
+                    name.as_bytes(),
+                    &[0, index],
+                ]
+                .concat()
+            })
+            .collect::<Vec<_>>();
+        // An active segment at the offset `i32.const 1024`.
+        let segment = [
+            &[0, 0x41, 0x80, 0x08, 0x0b, u8::try_from(data.len()).unwrap()],
+            data,
+        ]
+        .concat();
+
+        [
+            MAGIC,
+            VERSION,
+            &section(0, b"\x04name"),
+            &section(EXPORT_SECTION, &vec(&exports)),
+            &section(DATA_SECTION, &vec(&[segment])),
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn test_parse_engine() {
+        let code = module(ENGINE_EXPORTS, b"serde-1.0.1\nERR_PAUSED3.9.0\n");
+        let info = WasmInfo::parse(&code).unwrap();
+
+        assert_eq!(info.exports, ENGINE_EXPORTS);
+        assert_eq!(info.version.as_deref(), Some("3.9.0"));
+        assert_eq!(info.sha256, hex::encode(CryptoHash::hash_bytes(&code).0));
+        assert!(info.warnings(Contract::Engine).is_empty());
+        assert_eq!(info.warnings(Contract::XccRouter).len(), 1);
+    }
+
+    #[test]
+    fn test_router_as_engine() {
+        let info = WasmInfo::parse(&module(
+            &[
+                "initialize",
+                "get_version",
+                "execute",
+                "schedule",
+                "execute_scheduled",
+            ],
+            b"",
+        ))
+        .unwrap();
+
+        assert!(info.is_xcc_router());
+        assert_eq!(info.version, None);
+        assert_eq!(
+            info.warnings(Contract::Engine),
+            [
+                "the file looks like the XCC router, not the engine",
+                "the engine exports are missing: new, submit, call, deploy_code, view, get_owner, \
+                 stage_upgrade, deploy_upgrade"
+            ]
+        );
+        assert!(info.warnings(Contract::XccRouter).is_empty());
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(WasmInfo::parse(b"engine").is_err());
+        assert!(WasmInfo::parse(&module(&["new"], b"")[..20]).is_err());
+    }
+}
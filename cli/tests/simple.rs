@@ -0,0 +1,1219 @@
+#![cfg(feature = "simple")]
+use std::num::{NonZeroU32, NonZeroU64, NonZeroUsize};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use aurora_cli::cli::command;
+use aurora_cli::cli::command::global_contract::DeployMode;
+use aurora_cli::cli::simple::OutputFormat;
+use aurora_cli::client::{Client, Context};
+use aurora_cli::error::ErrorKind;
+use aurora_cli::utils::wasm::Contract;
+use aurora_engine_transactions::EthTransactionKind;
+use aurora_engine_types::U256;
+use aurora_engine_types::parameters::engine::{
+    PausePrecompilesCallArgs, RelayerKeyArgs, SetOwnerArgs, SubmitResult, TransactionStatus,
+};
+use aurora_engine_types::types::Address;
+use aurora_mock_rpc::MockRpc;
+use aurora_sdk_rs::aurora::signer::{EvmSigner, LocalSigner};
+use aurora_sdk_rs::near::cassette::Cassette;
+use aurora_sdk_rs::near::delegate;
+use near_crypto::{InMemorySigner, KeyType, Signer};
+use near_primitives::account::{AccessKey, AccessKeyPermission, FunctionCallPermission};
+use near_primitives::action::{GlobalContractDeployMode, GlobalContractIdentifier};
+use near_primitives::gas::Gas;
+use near_primitives::hash::CryptoHash;
+use near_primitives::transaction::Action;
+use near_primitives::types::{Balance, BlockId, BlockReference, Finality};
+use near_primitives::views::{AccessKeyInfoView, AccountView, FinalExecutionStatus};
+use tempfile::{TempDir, tempdir};
+
+static KEY_FILE_ID: AtomicUsize = AtomicUsize::new(0);
+
+const EVM_SECRET_KEY: &str = "3fac6dca1c6fc056b971a4e9090afbbfbdf3bc443e9cda595facb653cb1c01e1";
+
+/// Write the key file of the account to the directory of the test and return the context using it
+/// and the mock server.
+fn context(rpc: &MockRpc, dir: &TempDir, account_id: &str) -> Context {
+    context_with_url(dir, rpc.url(), account_id, None)
+}
+
+fn context_with_url(
+    dir: &TempDir,
+    url: &str,
+    account_id: &str,
+    cassette: Option<Cassette>,
+) -> Context {
+    let signer =
+        InMemorySigner::from_seed(account_id.parse().unwrap(), KeyType::ED25519, account_id);
+    let Signer::InMemory(signer) = signer else {
+        unreachable!()
+    };
+    let path = dir.path().join(format!(
+        "{account_id}-{}.json",
+        KEY_FILE_ID.fetch_add(1, Ordering::Relaxed)
+    ));
+    let key = serde_json::json!({
+        "account_id": signer.account_id,
+        "public_key": signer.public_key,
+        "secret_key": signer.secret_key,
+    });
+    std::fs::write(&path, key.to_string()).unwrap();
+
+    let mut client = Client::new(url, "aurora", Some(path.display().to_string())).unwrap();
+    if let Some(cassette) = cassette {
+        client = client.with_cassette(cassette);
+    }
+    Context::new(client, OutputFormat::Plain)
+}
+
+/// WASM module without code, told apart by the name of its custom section.
+fn wasm(name: &str) -> Vec<u8> {
+    let len = u8::try_from(name.len()).unwrap();
+    [&b"\0asm\x01\0\0\0"[..], &[0, len + 1, len], name.as_bytes()].concat()
+}
+
+fn u256_bytes(value: u64) -> Vec<u8> {
+    U256::from(value).to_big_endian().to_vec()
+}
+
+#[test]
+fn test_invalid_engine_account() {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_aurora-cli"))
+        .args([
+            "--engine",
+            "BAD!!",
+            "--output-format",
+            "json",
+            "get-version",
+        ])
+        .output()
+        .unwrap();
+    let envelope: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(envelope["status"], "failure");
+    assert_eq!(envelope["error"]["kind"], "usage");
+    assert_eq!(envelope["error"]["exit_code"], 2);
+    assert!(
+        envelope["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("Invalid engine account id BAD!!")
+    );
+}
+
+#[tokio::test]
+async fn test_get_chain_id() {
+    let dir = tempdir().unwrap();
+    let rpc = MockRpc::start().await;
+    rpc.on_call_function("get_chain_id", u256_bytes(1_313_161_556));
+
+    command::get_chain_id(context(&rpc, &dir, "owner.near"))
+        .await
+        .unwrap();
+
+    let calls = rpc.view_calls();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].account_id.as_str(), "aurora");
+    assert_eq!(calls[0].method_name, "get_chain_id");
+}
+
+#[tokio::test]
+async fn test_reads_at_block() {
+    let dir = tempdir().unwrap();
+    let rpc = MockRpc::start().await;
+    rpc.on_call_function("get_chain_id", u256_bytes(1_313_161_556));
+    rpc.on_view_access_key_list(&"owner.near".parse().unwrap(), vec![]);
+    let block = BlockReference::BlockId(BlockId::Hash(CryptoHash::hash_bytes(b"block")));
+    let context = || {
+        let mut context = context(&rpc, &dir, "owner.near");
+        context.client = context.client.with_block_reference(block.clone());
+        context
+    };
+
+    command::get_chain_id(context()).await.unwrap();
+    command::account::list_keys(context(), "owner.near".parse().unwrap())
+        .await
+        .unwrap();
+
+    let requests = rpc.requests_of("query");
+    assert_eq!(requests.len(), 2);
+    assert!(
+        requests
+            .iter()
+            .all(|request| request.block_reference() == Some(block.clone()))
+    );
+}
+
+#[tokio::test]
+async fn test_history_samples_blocks() {
+    let dir = tempdir().unwrap();
+    let rpc = MockRpc::start().await;
+    rpc.on_call_function("get_version", b"3.9.0\n".to_vec());
+    rpc.on_missing_block(102);
+    let range = command::history::BlockRange {
+        from: 100,
+        to: 105,
+        step: NonZeroU64::new(2).unwrap(),
+    };
+    let mut output = vec![];
+
+    command::history::run(
+        context(&rpc, &dir, "owner.near"),
+        command::history::Getter::Version,
+        range,
+        command::history::Format::Csv,
+        NonZeroUsize::new(2).unwrap(),
+        None,
+        &mut output,
+    )
+    .await
+    .unwrap();
+
+    let output = String::from_utf8(output).unwrap();
+    let lines = output.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 4);
+    assert_eq!(lines[0], "block_height,value,error");
+    assert_eq!(lines[1], "100,3.9.0,");
+    assert!(lines[2].starts_with("102,,"));
+    assert!(lines[2].contains("BlockId(Height(102))"));
+    assert_eq!(lines[3], "104,3.9.0,");
+
+    let mut heights = rpc
+        .requests_of("query")
+        .iter()
+        .filter_map(aurora_mock_rpc::Request::block_reference)
+        .collect::<Vec<_>>();
+    heights.sort_by_key(|block| match block {
+        BlockReference::BlockId(BlockId::Height(height)) => *height,
+        _ => u64::MAX,
+    });
+    assert_eq!(
+        heights,
+        [100, 102, 104].map(|height| BlockReference::BlockId(BlockId::Height(height)))
+    );
+}
+
+#[tokio::test]
+async fn test_history_jsonl_with_rate_limit() {
+    let dir = tempdir().unwrap();
+    let rpc = MockRpc::start().await;
+    rpc.on_call_function("get_nonce", u256_bytes(7));
+    rpc.on_missing_block(101);
+    let range = command::history::BlockRange {
+        from: 100,
+        to: 103,
+        step: NonZeroU64::new(1).unwrap(),
+    };
+    let mut output = vec![];
+    let started = std::time::Instant::now();
+
+    command::history::run(
+        context(&rpc, &dir, "owner.near"),
+        command::history::Getter::Nonce {
+            address: "0x1111111111111111111111111111111111111111".to_string(),
+        },
+        range,
+        command::history::Format::Jsonl,
+        NonZeroUsize::new(4).unwrap(),
+        NonZeroU32::new(10),
+        &mut output,
+    )
+    .await
+    .unwrap();
+
+    // The first request is sent at once and the next ones 100 ms apart despite the parallelism.
+    assert!(started.elapsed() >= Duration::from_millis(300));
+
+    let lines = String::from_utf8(output)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(lines.len(), 4);
+    assert_eq!(
+        lines[0],
+        serde_json::json!({ "block_height": 100, "value": "7" })
+    );
+    assert_eq!(lines[1]["block_height"], 101);
+    assert!(lines[1]["error"].is_string());
+    assert!(lines[1].get("value").is_none());
+    assert_eq!(
+        lines[3],
+        serde_json::json!({ "block_height": 103, "value": "7" })
+    );
+}
+
+#[tokio::test]
+async fn test_history_rejects_reversed_range() {
+    let dir = tempdir().unwrap();
+    let rpc = MockRpc::start().await;
+    let range = command::history::BlockRange {
+        from: 105,
+        to: 100,
+        step: NonZeroU64::new(1).unwrap(),
+    };
+
+    let result = command::history::run(
+        context(&rpc, &dir, "owner.near"),
+        command::history::Getter::Version,
+        range,
+        command::history::Format::Jsonl,
+        NonZeroUsize::new(1).unwrap(),
+        None,
+        &mut vec![],
+    )
+    .await;
+
+    assert!(result.is_err());
+    assert!(rpc.requests_of("query").is_empty());
+}
+
+#[tokio::test]
+async fn test_get_balance_sends_address() {
+    let dir = tempdir().unwrap();
+    let rpc = MockRpc::start().await;
+    rpc.on_call_function("get_balance", u256_bytes(10));
+    let address = "0x1111111111111111111111111111111111111111";
+
+    command::get_balance(context(&rpc, &dir, "owner.near"), address.to_string())
+        .await
+        .unwrap();
+
+    assert_eq!(rpc.view_calls()[0].args, [0x11; 20]);
+}
+
+#[tokio::test]
+async fn test_view_call_error() {
+    let dir = tempdir().unwrap();
+    let rpc = MockRpc::start().await;
+
+    let result = command::get_owner(context(&rpc, &dir, "owner.near")).await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_set_owner() {
+    let dir = tempdir().unwrap();
+    let rpc = MockRpc::start().await;
+
+    command::set_owner(
+        context(&rpc, &dir, "owner.near"),
+        "new-owner.near".to_string(),
+    )
+    .await
+    .unwrap();
+
+    let calls = rpc.function_calls();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].signer_id.as_str(), "owner.near");
+    assert_eq!(calls[0].receiver_id.as_str(), "aurora");
+    assert_eq!(calls[0].method_name, "set_owner");
+    assert_eq!(
+        calls[0].args_borsh::<SetOwnerArgs>().unwrap(),
+        SetOwnerArgs {
+            new_owner: "new-owner.near".parse().unwrap()
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_set_owner_failure() {
+    let dir = tempdir().unwrap();
+    let rpc = MockRpc::start().await;
+    rpc.on_transaction_failure("set_owner", "Smart contract panicked: ERR_NOT_ALLOWED");
+
+    let error = command::set_owner(
+        context(&rpc, &dir, "owner.near"),
+        "new-owner.near".to_string(),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(
+        error
+            .to_string()
+            .starts_with("Error while setting a new owner")
+    );
+    assert!(error.to_string().contains("ERR_NOT_ALLOWED"));
+    assert_eq!(ErrorKind::of(&error), ErrorKind::Engine);
+    assert_eq!(ErrorKind::of(&error).exit_code(), 6);
+}
+
+#[tokio::test]
+async fn test_register_relayer() {
+    let dir = tempdir().unwrap();
+    let rpc = MockRpc::start().await;
+
+    command::register_relayer(
+        context(&rpc, &dir, "owner.near"),
+        "0x2222222222222222222222222222222222222222".to_string(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(rpc.function_calls()[0].args, [0x22; 20]);
+}
+
+#[tokio::test]
+async fn test_create_account_with_parent_key_only() {
+    let dir = tempdir().unwrap();
+    let rpc = MockRpc::start().await;
+    let Signer::InMemory(parent) =
+        InMemorySigner::from_seed("parent.near".parse().unwrap(), KeyType::ED25519, "parent")
+    else {
+        unreachable!()
+    };
+    let parent_key = dir.path().join("parent.json");
+    near_crypto::KeyFile {
+        account_id: parent.account_id.clone(),
+        public_key: parent.public_key(),
+        secret_key: parent.secret_key.clone(),
+    }
+    .write_to_file(&parent_key)
+    .unwrap();
+    // Neither `--near-key-path` nor `NEAR_KEY_PATH` is given.
+    let context = Context::new(
+        Client::new(rpc.url(), "aurora", None).unwrap(),
+        OutputFormat::Plain,
+    );
+
+    command::create_account(context, "child.parent.near", 1.0, Some(&parent_key))
+        .await
+        .unwrap();
+
+    let transactions = rpc.transactions();
+    assert_eq!(transactions.len(), 1);
+    assert_eq!(
+        transactions[0].transaction.signer_id().as_str(),
+        "parent.near"
+    );
+}
+
+#[tokio::test]
+async fn test_create_account_with_parent_key() {
+    let dir = tempdir().unwrap();
+    let rpc = MockRpc::start().await;
+    let Signer::InMemory(parent) =
+        InMemorySigner::from_seed("parent.near".parse().unwrap(), KeyType::ED25519, "parent")
+    else {
+        unreachable!()
+    };
+    let parent_key = dir.path().join("parent.json");
+    near_crypto::KeyFile {
+        account_id: parent.account_id.clone(),
+        public_key: parent.public_key(),
+        secret_key: parent.secret_key.clone(),
+    }
+    .write_to_file(&parent_key)
+    .unwrap();
+
+    command::create_account(
+        context(&rpc, &dir, "owner.near"),
+        "child.parent.near",
+        1.5,
+        Some(&parent_key),
+    )
+    .await
+    .unwrap();
+
+    let tx = &rpc.transactions()[0].transaction;
+    assert_eq!(tx.signer_id().as_str(), "parent.near");
+    assert_eq!(tx.receiver_id().as_str(), "child.parent.near");
+    assert!(matches!(
+        tx.actions(),
+        [
+            Action::CreateAccount(_),
+            Action::Transfer(transfer),
+            Action::AddKey(_),
+        ] if transfer.deposit == Balance::from_millinear(1500)
+    ));
+
+    let error = command::create_account(
+        context(&rpc, &dir, "owner.near"),
+        "child.other.near",
+        1.0,
+        None,
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(ErrorKind::of(&error), ErrorKind::Usage);
+}
+
+#[tokio::test]
+async fn test_add_function_call_key() {
+    let dir = tempdir().unwrap();
+    let rpc = MockRpc::start().await;
+    let public_key: near_crypto::PublicKey = "ed25519:DcA2MzgpJbrUATQLLceocVckhhAqrkingax4oJ9kZ847"
+        .parse()
+        .unwrap();
+
+    command::account::add_key(
+        context(&rpc, &dir, "owner.near"),
+        public_key.clone(),
+        command::account::KeyPermission::FunctionCall {
+            receiver: "aurora".parse().unwrap(),
+            method_names: vec!["submit".to_string()],
+            allowance: Some(0.25),
+        },
+    )
+    .await
+    .unwrap();
+
+    let tx = &rpc.transactions()[0].transaction;
+    assert_eq!(tx.receiver_id().as_str(), "owner.near");
+    let [Action::AddKey(add_key)] = tx.actions() else {
+        panic!("unexpected actions: {:?}", tx.actions());
+    };
+    assert_eq!(add_key.public_key, public_key);
+    assert_eq!(
+        add_key.access_key.permission,
+        AccessKeyPermission::FunctionCall(FunctionCallPermission {
+            allowance: Some(Balance::from_millinear(250)),
+            receiver_id: "aurora".to_string(),
+            method_names: vec!["submit".to_string()],
+        })
+    );
+}
+
+const fn account_view(code_hash: CryptoHash) -> AccountView {
+    AccountView {
+        amount: Balance::from_near(100),
+        locked: Balance::ZERO,
+        code_hash,
+        storage_usage: 0,
+        storage_paid_at: 0,
+        global_contract_hash: None,
+        global_contract_account_id: None,
+    }
+}
+
+#[tokio::test]
+async fn test_localnet_bootstrap() {
+    let rpc = MockRpc::start().await;
+    let dir = tempdir().unwrap();
+    let engine_wasm = dir.path().join("aurora-engine.wasm");
+    let xcc_router_wasm = dir.path().join("aurora-xcc-router.wasm");
+    std::fs::write(&engine_wasm, wasm("engine")).unwrap();
+    std::fs::write(&xcc_router_wasm, wasm("router")).unwrap();
+    let config = || command::localnet::Config {
+        relayer_address: Some("0x2222222222222222222222222222222222222222".to_string()),
+        out_dir: dir.path().join("out"),
+        ..command::localnet::Config::default()
+    };
+
+    command::localnet::bootstrap(
+        context(&rpc, &dir, "node0"),
+        &engine_wasm,
+        Some(&xcc_router_wasm),
+        config(),
+    )
+    .await
+    .unwrap();
+
+    let transactions = rpc.transactions();
+    let calls = rpc.function_calls();
+    let methods = calls
+        .iter()
+        .map(|call| call.method_name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(transactions.len(), 8);
+    assert_eq!(transactions[0].transaction.signer_id().as_str(), "node0");
+    assert_eq!(
+        transactions[0].transaction.receiver_id().as_str(),
+        "aurora.node0"
+    );
+    assert!(matches!(
+        transactions[1].transaction.actions(),
+        [Action::DeployContract(deploy)] if deploy.code == wasm("engine")
+    ));
+    assert_eq!(
+        transactions[4].transaction.receiver_id().as_str(),
+        "key-manager.aurora.node0"
+    );
+    assert_eq!(
+        methods,
+        [
+            "new",
+            "factory_update",
+            "set_key_manager",
+            "add_relayer_key",
+            "register_relayer"
+        ]
+    );
+    assert_eq!(calls[3].signer_id.as_str(), "key-manager.aurora.node0");
+    assert_eq!(calls[3].deposit, Balance::from_millinear(500));
+
+    let out_dir = dir.path().join("out");
+    let engine_key = aurora_cli::utils::read_key_file(out_dir.join("aurora_key.json")).unwrap();
+    let relayer = aurora_cli::utils::read_key_file(out_dir.join("relayer_key.json")).unwrap();
+    let profile = std::fs::read_to_string(out_dir.join("profile.env")).unwrap();
+    assert_eq!(engine_key.account_id.as_str(), "aurora.node0");
+    assert!(profile.contains("export AURORA_ENGINE=aurora.node0"));
+
+    // The repeated bootstrap skips every step which has been done.
+    rpc.on_view_account(
+        &"aurora.node0".parse().unwrap(),
+        account_view(CryptoHash::hash_bytes(&wasm("engine"))),
+    );
+    rpc.on_view_account(
+        &"key-manager.aurora.node0".parse().unwrap(),
+        account_view(CryptoHash::default()),
+    );
+    rpc.on_call_function_json("get_owner", &"aurora.node0");
+    rpc.on_view_access_key_list(
+        &"aurora.node0".parse().unwrap(),
+        vec![relayer_key(relayer.public_key, &["submit"])],
+    );
+
+    command::localnet::bootstrap(
+        context(&rpc, &dir, "node0"),
+        &engine_wasm,
+        Some(&xcc_router_wasm),
+        config(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(rpc.transactions().len(), 8);
+}
+
+#[tokio::test]
+async fn test_pause_contract_on_engines() {
+    let dir = tempdir().unwrap();
+    let rpc = MockRpc::start().await;
+    let engines = ["a.aurora.near", "b.aurora.near", "c.aurora.near"];
+
+    command::engines::run(
+        context(&rpc, &dir, "owner.near"),
+        engines.iter().map(|e| e.parse().unwrap()).collect(),
+        command::engines::EngineCommand::PauseContract,
+        NonZeroUsize::new(2).unwrap(),
+    )
+    .await
+    .unwrap();
+
+    let mut calls = rpc.function_calls();
+    calls.sort_by(|a, b| a.receiver_id.cmp(&b.receiver_id));
+    assert_eq!(
+        calls
+            .iter()
+            .map(|c| c.receiver_id.as_str())
+            .collect::<Vec<_>>(),
+        engines
+    );
+    assert!(calls.iter().all(|c| c.method_name == "pause_contract"));
+
+    let mut nonces = rpc
+        .transactions()
+        .iter()
+        .map(|tx| tx.transaction.nonce())
+        .collect::<Vec<_>>();
+    nonces.sort_unstable();
+    nonces.dedup();
+    assert_eq!(nonces.len(), engines.len());
+}
+
+#[tokio::test]
+async fn test_pause_contract_on_engines_with_stale_nonces() {
+    let dir = tempdir().unwrap();
+    let rpc = MockRpc::start().await;
+    let engines = [
+        "a.aurora.near",
+        "b.aurora.near",
+        "c.aurora.near",
+        "d.aurora.near",
+        "e.aurora.near",
+        "f.aurora.near",
+    ];
+    rpc.on_stale_nonce(3);
+
+    command::engines::run(
+        context(&rpc, &dir, "owner.near"),
+        engines.iter().map(|e| e.parse().unwrap()).collect(),
+        command::engines::EngineCommand::PauseContract,
+        NonZeroUsize::new(4).unwrap(),
+    )
+    .await
+    .unwrap();
+
+    // The rejected transactions are signed again, each time with a new nonce.
+    let transactions = rpc.transactions();
+    assert_eq!(transactions.len(), engines.len() + 3);
+
+    let mut receivers = transactions
+        .iter()
+        .map(|tx| tx.transaction.receiver_id().as_str())
+        .collect::<Vec<_>>();
+    receivers.sort_unstable();
+    receivers.dedup();
+    assert_eq!(receivers, engines);
+
+    let mut nonces = transactions
+        .iter()
+        .map(|tx| tx.transaction.nonce())
+        .collect::<Vec<_>>();
+    nonces.sort_unstable();
+    nonces.dedup();
+    assert_eq!(nonces.len(), transactions.len());
+}
+
+#[tokio::test]
+async fn test_set_fixed_gas_on_engines_failure() {
+    let dir = tempdir().unwrap();
+    let rpc = MockRpc::start().await;
+    rpc.on_transaction_failure("set_fixed_gas", "Smart contract panicked: ERR_NOT_ALLOWED");
+
+    let error = command::engines::run(
+        context(&rpc, &dir, "owner.near"),
+        vec![
+            "a.aurora.near".parse().unwrap(),
+            "b.aurora.near".parse().unwrap(),
+        ],
+        command::engines::EngineCommand::SetFixedGas { cost: 1 },
+        NonZeroUsize::new(4).unwrap(),
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "The command has failed on 2 of 2 engines"
+    );
+    assert_eq!(ErrorKind::of(&error), ErrorKind::Engine);
+    assert_eq!(rpc.function_calls().len(), 2);
+}
+
+#[test]
+fn test_read_engines() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("engines.txt");
+
+    std::fs::write(&path, "# silos\na.aurora.near\n\n  b.aurora.near\n").unwrap();
+    assert_eq!(
+        command::engines::read_engines(&path)
+            .unwrap()
+            .iter()
+            .map(AsRef::as_ref)
+            .collect::<Vec<&str>>(),
+        ["a.aurora.near", "b.aurora.near"]
+    );
+
+    // The default engine isn't used instead of the empty list.
+    std::fs::write(&path, "# silos\n\n").unwrap();
+    let error = command::engines::read_engines(&path).unwrap_err();
+    assert_eq!(ErrorKind::of(&error), ErrorKind::Usage);
+}
+
+#[tokio::test]
+async fn test_pause_precompiles() {
+    let dir = tempdir().unwrap();
+    let rpc = MockRpc::start().await;
+
+    command::pause_precompiles(
+        context(&rpc, &dir, "owner.near"),
+        "exit-to-near,exit-to-ethereum".parse().unwrap(),
+    )
+    .await
+    .unwrap();
+
+    let call = &rpc.function_calls()[0];
+    assert_eq!(call.method_name, "pause_precompiles");
+    assert_eq!(
+        call.args_borsh::<PausePrecompilesCallArgs>().unwrap(),
+        PausePrecompilesCallArgs { paused_mask: 3 }
+    );
+}
+
+#[tokio::test]
+async fn test_emergency_pause_and_resume() {
+    let dir = tempdir().unwrap();
+    let snapshot = dir.path().join("snapshot.json");
+    let rpc = MockRpc::start().await;
+    rpc.on_call_function("get_paused_precompiles", 1u32.to_le_bytes().to_vec());
+    rpc.on_call_function("get_paused_flags", vec![2]);
+    rpc.on_call_function("is_paused", vec![0]);
+
+    command::emergency::pause(context(&rpc, &dir, "owner.near"), &snapshot)
+        .await
+        .unwrap();
+
+    let calls = rpc.function_calls();
+    assert_eq!(
+        calls
+            .iter()
+            .map(|c| c.method_name.as_str())
+            .collect::<Vec<_>>(),
+        ["pause_precompiles", "set_paused_flags", "pause_contract"]
+    );
+    assert_eq!(
+        calls[0].args_borsh::<PausePrecompilesCallArgs>().unwrap(),
+        PausePrecompilesCallArgs { paused_mask: 0b11 }
+    );
+    assert_eq!(calls[1].args, [0b11]);
+
+    let rpc = MockRpc::start().await;
+
+    command::emergency::resume(context(&rpc, &dir, "owner.near"), &snapshot)
+        .await
+        .unwrap();
+
+    let calls = rpc.function_calls();
+    assert_eq!(
+        calls
+            .iter()
+            .map(|c| c.method_name.as_str())
+            .collect::<Vec<_>>(),
+        ["resume_contract", "resume_precompiles", "set_paused_flags"]
+    );
+    assert_eq!(
+        calls[1].args_borsh::<PausePrecompilesCallArgs>().unwrap(),
+        PausePrecompilesCallArgs { paused_mask: 0b10 }
+    );
+    assert_eq!(calls[2].args, [2]);
+    assert!(!snapshot.exists());
+}
+
+#[tokio::test]
+async fn test_emergency_resume_keeps_paused_contract() {
+    let dir = tempdir().unwrap();
+    let snapshot = dir.path().join("snapshot.json");
+    let rpc = MockRpc::start().await;
+    rpc.on_call_function("get_paused_precompiles", 0u32.to_le_bytes().to_vec());
+    rpc.on_call_function("get_paused_flags", vec![0]);
+    rpc.on_call_function("is_paused", vec![1]);
+    // The contract is already paused, so the call is skipped.
+    rpc.on_transaction_failure("pause_contract", "Smart contract panicked: ERR_PAUSED");
+
+    command::emergency::pause(context(&rpc, &dir, "owner.near"), &snapshot)
+        .await
+        .unwrap();
+
+    let saved: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&snapshot).unwrap()).unwrap();
+    assert_eq!(saved["contract_paused"], true);
+
+    let rpc = MockRpc::start().await;
+
+    command::emergency::resume(context(&rpc, &dir, "owner.near"), &snapshot)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        rpc.function_calls()
+            .iter()
+            .map(|c| c.method_name.as_str())
+            .collect::<Vec<_>>(),
+        ["resume_precompiles", "set_paused_flags"]
+    );
+    assert!(!snapshot.exists());
+}
+
+#[tokio::test]
+async fn test_emergency_pause_reads_latest_block() {
+    let dir = tempdir().unwrap();
+    let rpc = MockRpc::start().await;
+    rpc.on_call_function("get_paused_precompiles", 0u32.to_le_bytes().to_vec());
+    rpc.on_call_function("get_paused_flags", vec![0]);
+    rpc.on_call_function("is_paused", vec![0]);
+    let mut context = context(&rpc, &dir, "owner.near");
+    context.client = context
+        .client
+        .with_block_reference(BlockReference::BlockId(BlockId::Height(100)));
+
+    command::emergency::pause(context, dir.path().join("snapshot.json"))
+        .await
+        .unwrap();
+
+    let requests = rpc.requests_of("query");
+    assert!(!requests.is_empty());
+    assert!(
+        requests
+            .iter()
+            .all(|request| request.block_reference() == Some(Finality::Final.into()))
+    );
+}
+
+#[tokio::test]
+async fn test_upgrade_run_and_resume() {
+    let dir = tempdir().unwrap();
+    let wasm_path = dir.path().join("engine.wasm");
+    let state = dir.path().join("upgrade-state.json");
+    std::fs::write(&wasm_path, wasm("new engine")).unwrap();
+    let engine = "aurora".parse().unwrap();
+    let rpc = MockRpc::start().await;
+    rpc.on_view_code(&engine, wasm("old engine"));
+    rpc.on_call_function("get_version", b"3.9.0\n".to_vec());
+    rpc.on_call_function("get_upgrade_index", 99u64.to_le_bytes().to_vec());
+
+    // The version isn't changed by the mock, so the upgrade stops before the verification.
+    let error = command::upgrade::run(
+        context(&rpc, &dir, "owner.near"),
+        wasm_path.clone(),
+        Some("migrate".to_string()),
+        Some(r#"{"step":1}"#.to_string()),
+        state.clone(),
+    )
+    .await
+    .unwrap_err();
+    assert!(error.to_string().contains("hasn't changed"));
+    assert!(state.exists());
+
+    let calls = rpc.function_calls();
+    assert_eq!(
+        calls
+            .iter()
+            .map(|c| c.method_name.as_str())
+            .collect::<Vec<_>>(),
+        ["stage_upgrade", "deploy_upgrade", "migrate"]
+    );
+    assert_eq!(calls[0].args, wasm("new engine"));
+    assert_eq!(calls[2].args, br#"{"step":1}"#);
+    // The state is read after every transaction is final.
+    assert_eq!(rpc.requests_of("tx").len(), 3);
+
+    // The run is interrupted after sending the migration, but before recording it as done.
+    let migration_hash = rpc.transactions()[2].get_hash();
+    let mut saved: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&state).unwrap()).unwrap();
+    assert_eq!(saved["migration_tx"], migration_hash.to_string());
+    saved["migrated"] = false.into();
+    std::fs::write(&state, saved.to_string()).unwrap();
+
+    rpc.on_view_code(&engine, wasm("new engine"));
+    rpc.on_call_function("get_version", b"3.10.0\n".to_vec());
+
+    command::upgrade::run(
+        context(&rpc, &dir, "owner.near"),
+        wasm_path,
+        Some("migrate".to_string()),
+        None,
+        state.clone(),
+    )
+    .await
+    .unwrap();
+
+    // The status of the sent migration is checked instead of calling it again.
+    assert_eq!(rpc.function_calls().len(), 3);
+    assert!(
+        rpc.requests_of("tx")[3..]
+            .iter()
+            .all(|request| request.params["tx_hash"] == migration_hash.to_string())
+    );
+    assert!(!state.exists());
+}
+
+#[tokio::test]
+async fn test_deploy_and_use_global_contract() {
+    let rpc = MockRpc::start().await;
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("engine.wasm");
+    let code = wasm("engine");
+    std::fs::write(&path, &code).unwrap();
+
+    command::global_contract::deploy(
+        context(&rpc, &dir, "global.near"),
+        &path,
+        Contract::Engine,
+        DeployMode::AccountId,
+    )
+    .await
+    .unwrap();
+    command::global_contract::use_global_contract(
+        context(&rpc, &dir, "global.near"),
+        None,
+        Some("global.near".parse().unwrap()),
+    )
+    .await
+    .unwrap();
+
+    let transactions = rpc.transactions();
+    let deploy = &transactions[0].transaction;
+    assert_eq!(deploy.receiver_id().as_str(), "global.near");
+    let [Action::DeployGlobalContract(action)] = deploy.actions() else {
+        panic!("unexpected actions: {:?}", deploy.actions());
+    };
+    assert_eq!(action.code.as_ref(), code.as_slice());
+    assert_eq!(action.deploy_mode, GlobalContractDeployMode::AccountId);
+
+    let use_global = &transactions[1].transaction;
+    assert_eq!(use_global.signer_id().as_str(), "aurora");
+    assert_eq!(use_global.receiver_id().as_str(), "aurora");
+    let [Action::UseGlobalContract(action)] = use_global.actions() else {
+        panic!("unexpected actions: {:?}", use_global.actions());
+    };
+    assert_eq!(
+        action.contract_identifier,
+        GlobalContractIdentifier::AccountId("global.near".parse().unwrap())
+    );
+}
+
+#[tokio::test]
+async fn test_add_relayer_key() {
+    let dir = tempdir().unwrap();
+    let rpc = MockRpc::start().await;
+    let public_key = "ed25519:DcA2MzgpJbrUATQLLceocVckhhAqrkingax4oJ9kZ847"
+        .parse()
+        .unwrap();
+
+    command::add_relayer_key(context(&rpc, &dir, "manager.near"), public_key, 0.5)
+        .await
+        .unwrap();
+
+    let call = &rpc.function_calls()[0];
+    assert_eq!(call.method_name, "add_relayer_key");
+    assert_eq!(
+        call.args_json::<RelayerKeyArgs>().unwrap(),
+        RelayerKeyArgs { public_key }
+    );
+    assert_eq!(call.deposit, Balance::from_millinear(500));
+}
+
+fn relayer_key(public_key: near_crypto::PublicKey, method_names: &[&str]) -> AccessKeyInfoView {
+    AccessKeyInfoView {
+        public_key,
+        access_key: AccessKey {
+            nonce: 3,
+            permission: AccessKeyPermission::FunctionCall(FunctionCallPermission {
+                allowance: Some(Balance::from_millinear(250)),
+                receiver_id: "aurora".to_string(),
+                method_names: method_names.iter().map(ToString::to_string).collect(),
+            }),
+        }
+        .into(),
+    }
+}
+
+#[tokio::test]
+async fn test_rotate_relayer_key() {
+    let dir = tempdir().unwrap();
+    let rpc = MockRpc::start().await;
+    let old = InMemorySigner::from_seed("aurora".parse().unwrap(), KeyType::ED25519, "relayer");
+    let other = InMemorySigner::from_seed("aurora".parse().unwrap(), KeyType::ED25519, "other");
+    rpc.on_view_access_key_list(
+        &"aurora".parse().unwrap(),
+        vec![
+            relayer_key(old.public_key(), &["submit", "submit_with_args", "call"]),
+            relayer_key(other.public_key(), &["ft_transfer"]),
+        ],
+    );
+    let Signer::InMemory(old) = old else {
+        unreachable!()
+    };
+    let key_file = dir.path().join("relayer.json");
+    near_crypto::KeyFile {
+        account_id: old.account_id.clone(),
+        public_key: old.public_key(),
+        secret_key: old.secret_key.clone(),
+    }
+    .write_to_file(&key_file)
+    .unwrap();
+
+    command::relayer_keys::rotate(context(&rpc, &dir, "owner.near"), &key_file, 0.5)
+        .await
+        .unwrap();
+
+    let new = aurora_cli::utils::read_key_file(&key_file).unwrap();
+    let calls = rpc.function_calls();
+    assert_ne!(new.public_key, old.public_key);
+    assert_eq!(new.account_id.as_str(), "aurora");
+    assert_eq!(calls.len(), 2);
+    assert_eq!(calls[0].method_name, "add_relayer_key");
+    assert_eq!(calls[0].deposit, Balance::from_millinear(500));
+    assert_eq!(
+        calls[0].args_json::<RelayerKeyArgs>().unwrap().public_key,
+        new.public_key.to_string().parse().unwrap()
+    );
+    assert_eq!(calls[1].method_name, "remove_relayer_key");
+    assert_eq!(
+        calls[1].args_json::<RelayerKeyArgs>().unwrap().public_key,
+        old.public_key.to_string().parse().unwrap()
+    );
+    assert_eq!(rpc.requests_of("tx").len(), 1);
+
+    let error = command::relayer_keys::rotate(
+        context(&rpc, &dir, "owner.near"),
+        &dir.path().join("missing.json"),
+        0.5,
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(ErrorKind::of(&error), ErrorKind::Usage);
+}
+
+#[tokio::test]
+async fn test_deploy_evm_code() {
+    let dir = tempdir().unwrap();
+    let rpc = MockRpc::start().await;
+    let contract = Address::from_array([0x33; 20]);
+    rpc.on_call_function("get_nonce", u256_bytes(5));
+    rpc.on_call_function("get_chain_id", u256_bytes(1_313_161_556));
+    rpc.on_transaction(
+        "submit",
+        FinalExecutionStatus::SuccessValue(
+            borsh::to_vec(&SubmitResult::new(
+                TransactionStatus::Succeed(contract.as_bytes().to_vec()),
+                100,
+                vec![],
+            ))
+            .unwrap(),
+        ),
+    );
+    let signer = LocalSigner::from_hex(EVM_SECRET_KEY).unwrap();
+
+    command::deploy_evm_code(
+        context(&rpc, &dir, "user.near"),
+        "600160005500".to_string(),
+        None,
+        None,
+        None,
+        None,
+        &signer,
+    )
+    .await
+    .unwrap();
+
+    let call = &rpc.function_calls()[0];
+    assert_eq!(call.method_name, "submit");
+    let EthTransactionKind::Legacy(tx) =
+        EthTransactionKind::try_from(call.args.as_slice()).unwrap()
+    else {
+        panic!("expected legacy transaction");
+    };
+    assert_eq!(tx.sender().unwrap(), signer.address());
+    assert_eq!(tx.chain_id(), Some(1_313_161_556));
+    assert_eq!(tx.transaction.nonce, U256::from(5));
+    assert_eq!(tx.transaction.to, None);
+    assert_eq!(tx.transaction.data, hex::decode("600160005500").unwrap());
+}
+
+#[tokio::test]
+async fn test_deploy_evm_code_reads_latest_block() {
+    let dir = tempdir().unwrap();
+    let rpc = MockRpc::start().await;
+    rpc.on_call_function("get_nonce", u256_bytes(5));
+    rpc.on_call_function("get_chain_id", u256_bytes(1_313_161_556));
+    rpc.on_transaction(
+        "submit",
+        FinalExecutionStatus::SuccessValue(
+            borsh::to_vec(&SubmitResult::new(
+                TransactionStatus::Succeed(vec![0x33; 20]),
+                100,
+                vec![],
+            ))
+            .unwrap(),
+        ),
+    );
+    let mut context = context(&rpc, &dir, "user.near");
+    context.client = context
+        .client
+        .with_block_reference(BlockReference::BlockId(BlockId::Height(100)));
+    let signer = LocalSigner::from_hex(EVM_SECRET_KEY).unwrap();
+
+    command::deploy_evm_code(
+        context,
+        "600160005500".to_string(),
+        None,
+        None,
+        None,
+        None,
+        &signer,
+    )
+    .await
+    .unwrap();
+
+    // The nonce and chain id of the signed transaction are read from the latest final block.
+    let requests = rpc.requests_of("query");
+    let methods = requests
+        .iter()
+        .filter_map(|request| request.view_call().map(|call| call.method_name))
+        .collect::<Vec<_>>();
+    assert_eq!(methods, ["get_nonce", "get_chain_id"]);
+    assert!(
+        requests
+            .iter()
+            .all(|request| request.block_reference() == Some(Finality::Final.into()))
+    );
+}
+
+#[tokio::test]
+async fn test_meta_tx_relay() {
+    let dir = tempdir().unwrap();
+    let rpc = MockRpc::start().await;
+    rpc.set_block_height(500);
+    let signed = context(&rpc, &dir, "cold.near")
+        .client
+        .near()
+        .create_delegate_action("pause_contract", vec![], Gas::from_teragas(50), 0, 100)
+        .await
+        .unwrap();
+    assert_eq!(signed.delegate_action.max_block_height, 600);
+    assert!(rpc.transactions().is_empty());
+
+    command::meta_tx::relay(
+        context(&rpc, &dir, "relayer.near"),
+        &delegate::encode(&signed),
+    )
+    .await
+    .unwrap();
+
+    let txs = rpc.transactions();
+    assert_eq!(txs.len(), 1);
+    assert_eq!(txs[0].transaction.signer_id().as_str(), "relayer.near");
+    assert_eq!(txs[0].transaction.receiver_id().as_str(), "cold.near");
+
+    let calls = rpc.function_calls();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].signer_id.as_str(), "cold.near");
+    assert_eq!(calls[0].receiver_id.as_str(), "aurora");
+    assert_eq!(calls[0].method_name, "pause_contract");
+    assert_eq!(calls[0].gas, Gas::from_teragas(50));
+}
+
+#[tokio::test]
+async fn test_record_and_replay() {
+    let dir = tempdir().unwrap();
+    let cassette = dir.path().join("cassette");
+    let rpc = MockRpc::start().await;
+    rpc.on_call_function("get_chain_id", u256_bytes(1_313_161_556));
+
+    let record = || Some(Cassette::record(&cassette).unwrap());
+    command::get_chain_id(context_with_url(&dir, rpc.url(), "owner.near", record()))
+        .await
+        .unwrap();
+    command::set_owner(
+        context_with_url(&dir, rpc.url(), "owner.near", record()),
+        "new-owner.near".to_string(),
+    )
+    .await
+    .unwrap();
+    let requests = rpc.requests().len();
+    drop(rpc);
+
+    let replay = || Some(Cassette::replay(&cassette).unwrap());
+    command::get_chain_id(context_with_url(
+        &dir,
+        "http://127.0.0.1:1",
+        "owner.near",
+        replay(),
+    ))
+    .await
+    .unwrap();
+    command::set_owner(
+        context_with_url(&dir, "http://127.0.0.1:1", "owner.near", replay()),
+        "new-owner.near".to_string(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(std::fs::read_dir(&cassette).unwrap().count(), requests);
+
+    let result = command::set_owner(
+        context_with_url(&dir, "http://127.0.0.1:1", "owner.near", replay()),
+        "other-owner.near".to_string(),
+    )
+    .await;
+    assert!(result.is_err());
+}
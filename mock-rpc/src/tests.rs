@@ -0,0 +1,318 @@
+use near_crypto::{InMemorySigner, KeyType, Signer};
+use near_jsonrpc_client::JsonRpcClient;
+use near_jsonrpc_client::errors::{JsonRpcError, JsonRpcServerError};
+use near_jsonrpc_client::methods;
+use near_jsonrpc_primitives::types::query::{QueryResponseKind, RpcQueryError};
+use near_primitives::action::FunctionCallAction;
+use near_primitives::action::delegate::{DelegateAction, NonDelegateAction, SignedDelegateAction};
+use near_primitives::types::{BlockReference, Finality, FunctionArgs};
+use near_primitives::views::{FinalExecutionOutcomeViewEnum, QueryRequest};
+
+use super::*;
+
+fn signer() -> Signer {
+    InMemorySigner::from_seed("relayer.near".parse().unwrap(), KeyType::ED25519, "relayer")
+}
+
+fn call_action(method_name: &str, args: Vec<u8>) -> Action {
+    FunctionCallAction {
+        method_name: method_name.to_string(),
+        args,
+        gas: Gas::from_teragas(10),
+        deposit: Balance::from_yoctonear(1),
+    }
+    .into()
+}
+
+fn transaction(actions: Vec<Action>) -> SignedTransaction {
+    let signer = signer();
+    SignedTransaction::from_actions(
+        1,
+        signer.get_account_id(),
+        "aurora".parse().unwrap(),
+        &signer,
+        actions,
+        CryptoHash::default(),
+        0,
+    )
+}
+
+fn call_function(method_name: &str, args: &[u8]) -> methods::query::RpcQueryRequest {
+    methods::query::RpcQueryRequest {
+        block_reference: BlockReference::Finality(Finality::Final),
+        request: QueryRequest::CallFunction {
+            account_id: "aurora".parse().unwrap(),
+            method_name: method_name.to_string(),
+            args: FunctionArgs::from(args.to_vec()),
+        },
+    }
+}
+
+#[tokio::test]
+async fn test_call_function() {
+    let rpc = MockRpc::start().await;
+    let client = JsonRpcClient::connect(rpc.url());
+    rpc.on_call_function("get_version", b"3.9.0".to_vec());
+    rpc.on_call_function_error("get_owner", "Smart contract panicked");
+
+    let response = client
+        .call(call_function("get_version", &[1, 2]))
+        .await
+        .unwrap();
+    let QueryResponseKind::CallResult(result) = response.kind else {
+        panic!("unexpected response kind");
+    };
+    assert_eq!(result.result, b"3.9.0");
+    assert_eq!(response.block_height, DEFAULT_BLOCK_HEIGHT);
+    assert_eq!(
+        rpc.requests_of("query")[0].block_reference(),
+        Some(BlockReference::Finality(Finality::Final))
+    );
+
+    let error = client
+        .call(call_function("get_owner", &[]))
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        JsonRpcError::ServerError(JsonRpcServerError::HandlerError(
+            RpcQueryError::ContractExecutionError { vm_error, .. }
+        )) if vm_error == "Smart contract panicked"
+    ));
+
+    assert_eq!(
+        rpc.view_calls(),
+        vec![
+            ViewCall {
+                account_id: "aurora".parse().unwrap(),
+                method_name: "get_version".to_string(),
+                args: vec![1, 2],
+            },
+            ViewCall {
+                account_id: "aurora".parse().unwrap(),
+                method_name: "get_owner".to_string(),
+                args: vec![],
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_view_account_and_access_key() {
+    let rpc = MockRpc::start().await;
+    let client = JsonRpcClient::connect(rpc.url());
+    let account_id: AccountId = "relayer.near".parse().unwrap();
+    rpc.on_view_access_key(
+        &account_id,
+        AccessKey {
+            nonce: 7,
+            permission: AccessKeyPermission::FullAccess,
+        }
+        .into(),
+    );
+
+    let response = client
+        .call(methods::query::RpcQueryRequest {
+            block_reference: Finality::Final.into(),
+            request: QueryRequest::ViewAccessKey {
+                account_id: account_id.clone(),
+                public_key: signer().public_key(),
+            },
+        })
+        .await
+        .unwrap();
+    assert!(matches!(response.kind, QueryResponseKind::AccessKey(key) if key.nonce == 7));
+
+    let error = client
+        .call(methods::query::RpcQueryRequest {
+            block_reference: Finality::Final.into(),
+            request: QueryRequest::ViewAccount {
+                account_id: account_id.clone(),
+            },
+        })
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        JsonRpcError::ServerError(JsonRpcServerError::HandlerError(
+            RpcQueryError::UnknownAccount { .. }
+        ))
+    ));
+
+    rpc.on_view_account(
+        &account_id,
+        AccountView {
+            amount: Balance::from_near(5),
+            locked: Balance::from_yoctonear(0),
+            code_hash: CryptoHash::default(),
+            storage_usage: 100,
+            storage_paid_at: 0,
+            global_contract_hash: None,
+            global_contract_account_id: None,
+        },
+    );
+    let response = client
+        .call(methods::query::RpcQueryRequest {
+            block_reference: Finality::Final.into(),
+            request: QueryRequest::ViewAccount {
+                account_id: account_id.clone(),
+            },
+        })
+        .await
+        .unwrap();
+    assert!(
+        matches!(response.kind, QueryResponseKind::ViewAccount(view) if view.amount == Balance::from_near(5))
+    );
+
+    rpc.on_view_access_key_list(
+        &account_id,
+        vec![AccessKeyInfoView {
+            public_key: signer().public_key(),
+            access_key: AccessKey::full_access().into(),
+        }],
+    );
+    let response = client
+        .call(methods::query::RpcQueryRequest {
+            block_reference: Finality::Final.into(),
+            request: QueryRequest::ViewAccessKeyList { account_id },
+        })
+        .await
+        .unwrap();
+    assert!(
+        matches!(response.kind, QueryResponseKind::AccessKeyList(list) if list.keys.len() == 1)
+    );
+}
+
+#[tokio::test]
+async fn test_view_code() {
+    let rpc = MockRpc::start().await;
+    let client = JsonRpcClient::connect(rpc.url());
+    let account_id: AccountId = "aurora".parse().unwrap();
+    let request = methods::query::RpcQueryRequest {
+        block_reference: Finality::Final.into(),
+        request: QueryRequest::ViewCode {
+            account_id: account_id.clone(),
+        },
+    };
+
+    let error = client.call(&request).await.unwrap_err();
+    assert!(matches!(
+        error,
+        JsonRpcError::ServerError(JsonRpcServerError::HandlerError(
+            RpcQueryError::NoContractCode { .. }
+        ))
+    ));
+
+    rpc.on_view_code(&account_id, b"engine".to_vec());
+    let response = client.call(&request).await.unwrap();
+    assert!(matches!(
+        response.kind,
+        QueryResponseKind::ViewCode(view)
+            if view.code == b"engine" && view.hash == CryptoHash::hash_bytes(b"engine")
+    ));
+}
+
+#[tokio::test]
+async fn test_block() {
+    let rpc = MockRpc::start().await;
+    let client = JsonRpcClient::connect(rpc.url());
+    rpc.set_block_height(42);
+
+    let block = client
+        .call(methods::block::RpcBlockRequest {
+            block_reference: Finality::Final.into(),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(block.header.height, 42);
+    assert_eq!(rpc.requests_of("block").len(), 1);
+}
+
+#[tokio::test]
+async fn test_broadcast_and_tx_status() {
+    let rpc = MockRpc::start().await;
+    let client = JsonRpcClient::connect(rpc.url());
+    rpc.on_transaction(
+        "get_chain_id",
+        FinalExecutionStatus::SuccessValue(vec![1; 32]),
+    );
+    rpc.on_transaction_failure("pause_contract", "ERR_NOT_ALLOWED");
+
+    let tx = transaction(vec![call_action("get_chain_id", vec![5])]);
+    let outcome = client
+        .call(methods::broadcast_tx_commit::RpcBroadcastTxCommitRequest {
+            signed_transaction: tx.clone(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        outcome.status,
+        FinalExecutionStatus::SuccessValue(vec![1; 32])
+    );
+    assert_eq!(outcome.transaction.hash, tx.get_hash());
+
+    let failed = transaction(vec![call_action("pause_contract", vec![])]);
+    let hash = client
+        .call(methods::broadcast_tx_async::RpcBroadcastTxAsyncRequest {
+            signed_transaction: failed,
+        })
+        .await
+        .unwrap();
+    let status = client
+        .call(methods::tx::RpcTransactionStatusRequest {
+            transaction_info: methods::tx::TransactionInfo::TransactionId {
+                tx_hash: hash,
+                sender_account_id: signer().get_account_id(),
+            },
+            wait_until: TxExecutionStatus::Final,
+        })
+        .await
+        .unwrap();
+    let Some(FinalExecutionOutcomeViewEnum::FinalExecutionOutcome(outcome)) =
+        status.final_execution_outcome
+    else {
+        panic!("unexpected transaction status");
+    };
+    assert!(matches!(outcome.status, FinalExecutionStatus::Failure(_)));
+
+    let calls = rpc.function_calls();
+    assert_eq!(calls.len(), 2);
+    assert_eq!(calls[0].method_name, "get_chain_id");
+    assert_eq!(calls[0].args_borsh::<u8>().unwrap(), 5);
+    assert_eq!(calls[0].deposit, Balance::from_yoctonear(1));
+    assert_eq!(calls[1].signer_id.as_str(), "relayer.near");
+}
+
+#[tokio::test]
+async fn test_function_calls_of_delegate_action() {
+    let rpc = MockRpc::start().await;
+    let client = JsonRpcClient::connect(rpc.url());
+    let cold = InMemorySigner::from_seed("cold.near".parse().unwrap(), KeyType::ED25519, "cold");
+    let delegate_action = DelegateAction {
+        sender_id: cold.get_account_id(),
+        receiver_id: "aurora".parse().unwrap(),
+        actions: vec![
+            NonDelegateAction::try_from(call_action("set_owner", b"{}".to_vec())).unwrap(),
+        ],
+        nonce: 1,
+        max_block_height: 1000,
+        public_key: cold.public_key(),
+    };
+    let tx = transaction(vec![
+        SignedDelegateAction::sign(&cold, delegate_action).into(),
+    ]);
+
+    client
+        .call(methods::broadcast_tx_commit::RpcBroadcastTxCommitRequest {
+            signed_transaction: tx,
+        })
+        .await
+        .unwrap();
+
+    let calls = rpc.function_calls();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].signer_id.as_str(), "cold.near");
+    assert_eq!(calls[0].method_name, "set_owner");
+    assert_eq!(calls[0].args_json::<Value>().unwrap(), json!({}));
+}
@@ -0,0 +1,622 @@
+use ethabi::{Contract, Event, Function, Param, ParamType, StateMutability};
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::{Ident, LitStr, Token};
+
+/// Names which are used by the generated methods and can't be used for the parameters.
+const RESERVED_NAMES: &[&str] = &["signer", "value"];
+/// Inherent methods of the generated struct, which the functions of the contract can't shadow.
+const RESERVED_METHODS: &[&str] = &["new", "with_sender", "address"];
+
+pub struct AbigenInput {
+    name: Ident,
+    path: LitStr,
+}
+
+impl Parse for AbigenInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let path = input.parse()?;
+        // Allow trailing comma.
+        let _ = input.parse::<Option<Token![,]>>()?;
+
+        Ok(Self { name, path })
+    }
+}
+
+pub fn expand(input: AbigenInput) -> syn::Result<TokenStream> {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let path = std::path::Path::new(&manifest_dir).join(input.path.value());
+    let file = std::fs::File::open(&path).map_err(|e| {
+        syn::Error::new(
+            input.path.span(),
+            format!("Couldn't open ABI file: {}: {e}", path.display()),
+        )
+    })?;
+    let contract = Contract::load(file)
+        .map_err(|e| syn::Error::new(input.path.span(), format!("Couldn't parse ABI: {e}")))?;
+    let path = path.to_string_lossy().into_owned();
+
+    let sdk = sdk_path();
+    let name = &input.name;
+    let module = format_ident!("{}", to_snake_case(&name.to_string()));
+
+    let mut methods = Vec::new();
+    let mut functions = Vec::new();
+
+    for (fn_name, overloads) in &contract.functions {
+        for (i, function) in overloads.iter().enumerate() {
+            let suffix = overload_suffix(i);
+            let method = method_ident(&format!("{}{suffix}", to_snake_case(fn_name)));
+            let ty = format_ident!("{}{}", to_pascal_case(fn_name), suffix);
+
+            functions.push(function_struct(&sdk, &ty, function));
+            methods.push(function_method(&sdk, &module, &method, &ty, function));
+        }
+    }
+
+    let events = contract.events.iter().flat_map(|(event_name, overloads)| {
+        overloads.iter().enumerate().map(|(i, event)| {
+            let ty = format_ident!("{}{}", to_pascal_case(event_name), overload_suffix(i));
+            event_struct(&sdk, &ty, event)
+        })
+    });
+
+    let constructor = contract.constructor.as_ref().map(|constructor| {
+        let (args, tokens) = params_to_args(&sdk, &constructor.inputs);
+        quote! {
+            /// Return the code of the contract followed by the encoded constructor arguments.
+            #[must_use]
+            pub fn encode_constructor(code: &[u8], #(#args),*) -> Vec<u8> {
+                [code, &#sdk::aurora::abi::ethabi::encode(&[#(#tokens),*])].concat()
+            }
+        }
+    });
+
+    Ok(quote! {
+        /// Bindings to the contract generated from the ABI.
+        pub struct #name<'a> {
+            client: &'a #sdk::aurora::client::Client,
+            engine: #sdk::near::primitives::types::AccountId,
+            address: #sdk::aurora::types::Address,
+            sender: #sdk::aurora::types::Address,
+        }
+
+        #[allow(clippy::all, clippy::pedantic, clippy::nursery)]
+        impl<'a> #name<'a> {
+            #[must_use]
+            pub fn new(
+                client: &'a #sdk::aurora::client::Client,
+                engine: #sdk::near::primitives::types::AccountId,
+                address: #sdk::aurora::types::Address,
+            ) -> Self {
+                Self {
+                    client,
+                    engine,
+                    address,
+                    sender: #sdk::aurora::types::Address::zero(),
+                }
+            }
+
+            /// Set the sender of the view calls.
+            #[must_use]
+            pub const fn with_sender(mut self, sender: #sdk::aurora::types::Address) -> Self {
+                self.sender = sender;
+                self
+            }
+
+            #[must_use]
+            pub const fn address(&self) -> #sdk::aurora::types::Address {
+                self.address
+            }
+
+            #(#methods)*
+        }
+
+        #[allow(clippy::all, clippy::pedantic, clippy::nursery)]
+        pub mod #module {
+            // Rebuild the bindings if the ABI file changes.
+            const _: &[u8] = include_bytes!(#path);
+
+            #constructor
+
+            pub mod functions {
+                #(#functions)*
+            }
+
+            pub mod events {
+                #(#events)*
+            }
+        }
+    })
+}
+
+fn function_struct(sdk: &TokenStream, ty: &Ident, function: &Function) -> TokenStream {
+    let selector = function.short_signature();
+    let signature = function.signature();
+    let (args, tokens) = params_to_args(sdk, &function.inputs);
+    let output_type = output_type(sdk, &function.outputs);
+    let output_kinds = function.outputs.iter().map(|p| param_kind(sdk, &p.kind));
+    let output = decode_outputs(sdk, &function.outputs);
+
+    quote! {
+        #[doc = concat!("Function `", #signature, "`.")]
+        pub struct #ty;
+
+        impl #ty {
+            pub const SELECTOR: [u8; 4] = [#(#selector),*];
+
+            /// Return the calldata for the function call.
+            #[must_use]
+            pub fn encode_input(#(#args),*) -> Vec<u8> {
+                [
+                    &Self::SELECTOR[..],
+                    &#sdk::aurora::abi::ethabi::encode(&[#(#tokens),*]),
+                ]
+                .concat()
+            }
+
+            /// Decode the output of the function call.
+            pub fn decode_output(
+                data: &[u8],
+            ) -> Result<#output_type, #sdk::aurora::error::Error> {
+                let tokens = #sdk::aurora::abi::ethabi::decode(&[#(#output_kinds),*], data)?;
+                let mut tokens = tokens.into_iter();
+                Ok(#output)
+            }
+        }
+    }
+}
+
+fn function_method(
+    sdk: &TokenStream,
+    module: &Ident,
+    method: &Ident,
+    ty: &Ident,
+    function: &Function,
+) -> TokenStream {
+    let (args, _) = params_to_args(sdk, &function.inputs);
+    let names = param_names(&function.inputs);
+    let output_type = output_type(sdk, &function.outputs);
+    #[allow(deprecated)]
+    let is_view = matches!(
+        function.state_mutability,
+        StateMutability::View | StateMutability::Pure
+    ) || function.constant == Some(true);
+
+    if is_view {
+        quote! {
+            pub async fn #method(
+                &self,
+                #(#args),*
+            ) -> Result<#output_type, #sdk::aurora::error::Error> {
+                let status = self
+                    .client
+                    .view_contract_call(
+                        &self.engine,
+                        self.sender,
+                        self.address,
+                        #sdk::aurora::types::Wei::zero(),
+                        #module::functions::#ty::encode_input(#(#names),*),
+                    )
+                    .await?;
+                #module::functions::#ty::decode_output(&#sdk::aurora::abi::into_output(status)?)
+            }
+        }
+    } else {
+        let (value_arg, value) = if function.state_mutability == StateMutability::Payable {
+            (
+                quote! { value: #sdk::aurora::types::Wei, },
+                quote! { value },
+            )
+        } else {
+            (quote! {}, quote! { #sdk::aurora::types::Wei::zero() })
+        };
+
+        quote! {
+            pub async fn #method(
+                &self,
+                signer: &dyn #sdk::aurora::signer::EvmSigner,
+                #value_arg
+                #(#args),*
+            ) -> Result<
+                #sdk::aurora::abi::TransactionOutcome<#output_type>,
+                #sdk::aurora::error::Error,
+            > {
+                let result = self
+                    .client
+                    .submit(
+                        &self.engine,
+                        signer,
+                        Some(self.address),
+                        #value,
+                        #module::functions::#ty::encode_input(#(#names),*),
+                    )
+                    .await?;
+                #sdk::aurora::abi::into_outcome(result, #module::functions::#ty::decode_output)
+            }
+        }
+    }
+}
+
+fn event_struct(sdk: &TokenStream, ty: &Ident, event: &Event) -> TokenStream {
+    let name = &event.name;
+    let signature = event.signature().0;
+    let anonymous = event.anonymous;
+    let names = event
+        .inputs
+        .iter()
+        .enumerate()
+        .map(|(i, input)| field_ident(&input.name, i))
+        .collect::<Vec<_>>();
+    // Indexed parameters of dynamic types are stored in topics as hashes.
+    let kinds = event
+        .inputs
+        .iter()
+        .map(|input| {
+            if input.indexed && is_dynamic(&input.kind) {
+                ParamType::FixedBytes(32)
+            } else {
+                input.kind.clone()
+            }
+        })
+        .collect::<Vec<_>>();
+    let types = kinds.iter().map(|kind| rust_type(sdk, kind));
+    let decoded = kinds.iter().map(|kind| {
+        from_token(
+            sdk,
+            &quote! { params.next().ok_or_else(|| #sdk::aurora::abi::invalid_token(#name))? },
+            kind,
+        )
+    });
+    let params = event.inputs.iter().map(|input| {
+        let param_name = &input.name;
+        let kind = param_kind(sdk, &input.kind);
+        let indexed = input.indexed;
+        quote! {
+            #sdk::aurora::abi::ethabi::EventParam {
+                name: #param_name.to_string(),
+                kind: #kind,
+                indexed: #indexed,
+            }
+        }
+    });
+
+    quote! {
+        #[doc = concat!("Event `", #name, "`.")]
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub struct #ty {
+            #(pub #names: #types,)*
+        }
+
+        impl #ty {
+            pub const NAME: &'static str = #name;
+            pub const SIGNATURE: [u8; 32] = [#(#signature),*];
+
+            fn event() -> #sdk::aurora::abi::ethabi::Event {
+                #sdk::aurora::abi::ethabi::Event {
+                    name: Self::NAME.to_string(),
+                    inputs: vec![#(#params),*],
+                    anonymous: #anonymous,
+                }
+            }
+
+            /// Decode the event from the log of the EVM transaction.
+            pub fn decode_log(
+                log: &#sdk::aurora::parameters::engine::ResultLog,
+            ) -> Result<Self, #sdk::aurora::error::Error> {
+                let log = Self::event().parse_log(#sdk::aurora::abi::raw_log(log))?;
+                let mut params = log.params.into_iter().map(|param| param.value);
+
+                Ok(Self {
+                    #(#names: #decoded,)*
+                })
+            }
+
+            /// Return all events of this kind from the logs.
+            #[must_use]
+            pub fn filter(logs: &[#sdk::aurora::parameters::engine::ResultLog]) -> Vec<Self> {
+                logs.iter().filter_map(|log| Self::decode_log(log).ok()).collect()
+            }
+        }
+    }
+}
+
+/// Overloads get a numeric suffix in the order of the ABI, except the first one.
+fn overload_suffix(index: usize) -> String {
+    if index == 0 {
+        String::new()
+    } else {
+        index.to_string()
+    }
+}
+
+fn method_ident(name: &str) -> Ident {
+    if RESERVED_METHODS.contains(&name) {
+        format_ident!("{}_", name)
+    } else {
+        format_ident!("{}", name)
+    }
+}
+
+fn params_to_args(sdk: &TokenStream, params: &[Param]) -> (Vec<TokenStream>, Vec<TokenStream>) {
+    params
+        .iter()
+        .zip(param_names(params))
+        .map(|(param, name)| {
+            let ty = rust_type(sdk, &param.kind);
+            let token = into_token(sdk, &quote! { #name }, &param.kind, 0);
+            (quote! { #name: #ty }, token)
+        })
+        .unzip()
+}
+
+fn param_names(params: &[Param]) -> Vec<Ident> {
+    params
+        .iter()
+        .enumerate()
+        .map(|(i, param)| param_ident(&param.name, i))
+        .collect()
+}
+
+fn param_ident(name: &str, index: usize) -> Ident {
+    let ident = field_ident(name, index);
+
+    if RESERVED_NAMES.contains(&ident.to_string().as_str()) {
+        format_ident!("{}_", ident)
+    } else {
+        ident
+    }
+}
+
+fn field_ident(name: &str, index: usize) -> Ident {
+    let name = to_snake_case(name.trim_matches('_'));
+
+    if name.is_empty() {
+        format_ident!("arg{}", index)
+    } else if syn::parse_str::<Ident>(&name).is_err() {
+        format_ident!("{}_", name)
+    } else {
+        Ident::new(&name, Span::call_site())
+    }
+}
+
+fn output_type(sdk: &TokenStream, outputs: &[Param]) -> TokenStream {
+    match outputs {
+        [output] => rust_type(sdk, &output.kind),
+        outputs => {
+            let types = outputs.iter().map(|output| rust_type(sdk, &output.kind));
+            quote! { (#(#types),*) }
+        }
+    }
+}
+
+fn decode_outputs(sdk: &TokenStream, outputs: &[Param]) -> TokenStream {
+    let next = quote! {
+        tokens.next().ok_or_else(|| #sdk::aurora::abi::invalid_token("output"))?
+    };
+    let values = outputs
+        .iter()
+        .map(|output| from_token(sdk, &next, &output.kind))
+        .collect::<Vec<_>>();
+
+    match values.as_slice() {
+        [value] => value.clone(),
+        values => quote! { (#(#values),*) },
+    }
+}
+
+fn rust_type(sdk: &TokenStream, kind: &ParamType) -> TokenStream {
+    match kind {
+        ParamType::Address => quote! { #sdk::aurora::abi::ethabi::Address },
+        ParamType::Uint(_) => quote! { #sdk::aurora::abi::ethabi::Uint },
+        ParamType::Int(_) => quote! { #sdk::aurora::abi::ethabi::Int },
+        ParamType::Bool => quote! { bool },
+        ParamType::String => quote! { String },
+        ParamType::Bytes => quote! { Vec<u8> },
+        ParamType::FixedBytes(size) => quote! { [u8; #size] },
+        ParamType::Array(kind) => {
+            let ty = rust_type(sdk, kind);
+            quote! { Vec<#ty> }
+        }
+        ParamType::FixedArray(kind, size) => {
+            let ty = rust_type(sdk, kind);
+            quote! { [#ty; #size] }
+        }
+        ParamType::Tuple(kinds) => {
+            let types = kinds.iter().map(|kind| rust_type(sdk, kind));
+            quote! { (#(#types,)*) }
+        }
+    }
+}
+
+fn param_kind(sdk: &TokenStream, kind: &ParamType) -> TokenStream {
+    let param_type = quote! { #sdk::aurora::abi::ethabi::ParamType };
+
+    match kind {
+        ParamType::Address => quote! { #param_type::Address },
+        ParamType::Uint(size) => quote! { #param_type::Uint(#size) },
+        ParamType::Int(size) => quote! { #param_type::Int(#size) },
+        ParamType::Bool => quote! { #param_type::Bool },
+        ParamType::String => quote! { #param_type::String },
+        ParamType::Bytes => quote! { #param_type::Bytes },
+        ParamType::FixedBytes(size) => quote! { #param_type::FixedBytes(#size) },
+        ParamType::Array(kind) => {
+            let kind = param_kind(sdk, kind);
+            quote! { #param_type::Array(Box::new(#kind)) }
+        }
+        ParamType::FixedArray(kind, size) => {
+            let kind = param_kind(sdk, kind);
+            quote! { #param_type::FixedArray(Box::new(#kind), #size) }
+        }
+        ParamType::Tuple(kinds) => {
+            let kinds = kinds.iter().map(|kind| param_kind(sdk, kind));
+            quote! { #param_type::Tuple(vec![#(#kinds),*]) }
+        }
+    }
+}
+
+/// Generate an expression converting the value of the Rust type into `ethabi::Token`.
+fn into_token(
+    sdk: &TokenStream,
+    value: &TokenStream,
+    kind: &ParamType,
+    depth: usize,
+) -> TokenStream {
+    let token = quote! { #sdk::aurora::abi::ethabi::Token };
+
+    match kind {
+        ParamType::Address => quote! { #token::Address(#value) },
+        ParamType::Uint(_) => quote! { #token::Uint(#value) },
+        ParamType::Int(_) => quote! { #token::Int(#value) },
+        ParamType::Bool => quote! { #token::Bool(#value) },
+        ParamType::String => quote! { #token::String(#value) },
+        ParamType::Bytes => quote! { #token::Bytes(#value) },
+        ParamType::FixedBytes(_) => quote! { #token::FixedBytes(#value.to_vec()) },
+        ParamType::Array(item_kind) | ParamType::FixedArray(item_kind, _) => {
+            let variant = if matches!(kind, ParamType::Array(_)) {
+                quote! { Array }
+            } else {
+                quote! { FixedArray }
+            };
+            let item = format_ident!("item{}", depth);
+            let item_token = into_token(sdk, &quote! { #item }, item_kind, depth + 1);
+            quote! {
+                #token::#variant(#value.into_iter().map(|#item| #item_token).collect())
+            }
+        }
+        ParamType::Tuple(kinds) => {
+            let items = (0..kinds.len())
+                .map(|i| format_ident!("item{}_{}", depth, i))
+                .collect::<Vec<_>>();
+            let tokens = items
+                .iter()
+                .zip(kinds)
+                .map(|(item, kind)| into_token(sdk, &quote! { #item }, kind, depth + 1));
+            quote! {
+                {
+                    let (#(#items,)*) = #value;
+                    #token::Tuple(vec![#(#tokens),*])
+                }
+            }
+        }
+    }
+}
+
+/// Generate an expression converting `ethabi::Token` into the value of the Rust type.
+fn from_token(sdk: &TokenStream, token: &TokenStream, kind: &ParamType) -> TokenStream {
+    let kind_name = kind.to_string();
+    let invalid = quote! { || #sdk::aurora::abi::invalid_token(#kind_name) };
+
+    match kind {
+        ParamType::Address => quote! { #token.into_address().ok_or_else(#invalid)? },
+        ParamType::Uint(_) => quote! { #token.into_uint().ok_or_else(#invalid)? },
+        ParamType::Int(_) => quote! { #token.into_int().ok_or_else(#invalid)? },
+        ParamType::Bool => quote! { #token.into_bool().ok_or_else(#invalid)? },
+        ParamType::String => quote! { #token.into_string().ok_or_else(#invalid)? },
+        ParamType::Bytes => quote! { #token.into_bytes().ok_or_else(#invalid)? },
+        ParamType::FixedBytes(_) => quote! {
+            #token
+                .into_fixed_bytes()
+                .and_then(|bytes| bytes.try_into().ok())
+                .ok_or_else(#invalid)?
+        },
+        ParamType::Array(item_kind) => {
+            let item = from_token(sdk, &quote! { token }, item_kind);
+            quote! {
+                #token
+                    .into_array()
+                    .ok_or_else(#invalid)?
+                    .into_iter()
+                    .map(|token| Ok(#item))
+                    .collect::<Result<Vec<_>, #sdk::aurora::error::Error>>()?
+            }
+        }
+        ParamType::FixedArray(item_kind, _) => {
+            let item = from_token(sdk, &quote! { token }, item_kind);
+            quote! {
+                #token
+                    .into_fixed_array()
+                    .ok_or_else(#invalid)?
+                    .into_iter()
+                    .map(|token| Ok(#item))
+                    .collect::<Result<Vec<_>, #sdk::aurora::error::Error>>()?
+                    .try_into()
+                    .map_err(|_| (#invalid)())?
+            }
+        }
+        ParamType::Tuple(kinds) => {
+            let next = quote! { tokens.next().ok_or_else(#invalid)? };
+            let items = kinds.iter().map(|kind| from_token(sdk, &next, kind));
+            quote! {
+                {
+                    let mut tokens = #token.into_tuple().ok_or_else(#invalid)?.into_iter();
+                    (#(#items,)*)
+                }
+            }
+        }
+    }
+}
+
+fn is_dynamic(kind: &ParamType) -> bool {
+    matches!(
+        kind,
+        ParamType::String
+            | ParamType::Bytes
+            | ParamType::Array(_)
+            | ParamType::FixedArray(_, _)
+            | ParamType::Tuple(_)
+    )
+}
+
+fn sdk_path() -> TokenStream {
+    let crate_name = std::env::var("CARGO_PKG_NAME").unwrap_or_default();
+    let target_name = std::env::var("CARGO_BIN_NAME")
+        .or_else(|_| std::env::var("CARGO_EXAMPLE_NAME"))
+        .unwrap_or_default();
+
+    if crate_name == "aurora-sdk-rs" && target_name.is_empty() {
+        quote! { crate }
+    } else {
+        quote! { ::aurora_sdk_rs }
+    }
+}
+
+/// Convert `camelCase` or `PascalCase` names from the ABI into `snake_case`.
+fn to_snake_case(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut result = String::with_capacity(name.len() + 4);
+
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(char::is_ascii_lowercase);
+
+            if prev.is_ascii_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_ascii_uppercase() && next_is_lower)
+            {
+                result.push('_');
+            }
+        }
+
+        result.push(c.to_ascii_lowercase());
+    }
+
+    result
+}
+
+fn to_pascal_case(name: &str) -> String {
+    to_snake_case(name)
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_ascii_uppercase().to_string() + chars.as_str()
+            })
+        })
+        .collect()
+}
@@ -2,6 +2,38 @@ use proc_macro::TokenStream;
 use quote::quote;
 use syn::{parse_macro_input, DeriveInput, Fields, Meta, Type};
 
+mod abigen;
+
+/// Generate typed bindings to the EVM contract from the ABI JSON file
+///
+/// The path to the file is relative to the root of the crate (`CARGO_MANIFEST_DIR`).
+///
+/// Usage:
+/// ```ignore
+/// use aurora_sdk_rs::abigen;
+///
+/// abigen!(Erc20, "abi/erc20.json");
+///
+/// let token = Erc20::new(&client, engine_account_id, token_address);
+/// let balance = token.balance_of(owner).await?; // Executed via the `view` method
+/// let outcome = token.transfer(&signer, receiver, amount).await?; // Signed with the `EvmSigner`
+/// let transfers = erc20::events::Transfer::filter(&outcome.logs);
+/// ```
+///
+/// Besides the `Erc20` struct, the macro generates the `erc20` module with a struct per function
+/// in `erc20::functions` for encoding calldata and decoding outputs and a struct per event in
+/// `erc20::events` for decoding logs. Overloaded functions and events get a numeric suffix, e.g.
+/// `safe_transfer_from1`, and functions named `new`, `with_sender` or `address` get the `_`
+/// suffix, e.g. `address_`, not to clash with the methods of the struct.
+#[proc_macro]
+pub fn abigen(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as abigen::AbigenInput);
+
+    abigen::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
 /// Derive macro for implementing the ContractMethod trait
 ///
 /// Usage:
@@ -0,0 +1,65 @@
+//! Runtime support of the contract bindings generated by the [`abigen!`](crate::abigen) macro.
+pub use ethabi;
+
+use aurora_engine_types::parameters::engine::{ResultLog, SubmitResult, TransactionStatus};
+use aurora_engine_types::types::Address;
+
+use super::error::Error;
+
+/// Outcome of the transaction sent via the generated bindings.
+#[derive(Debug, Clone)]
+pub struct TransactionOutcome<T> {
+    /// Decoded output of the called function.
+    pub output: T,
+    /// Amount of EVM gas used by the transaction.
+    pub gas_used: u64,
+    /// Logs emitted during the transaction execution.
+    pub logs: Vec<ResultLog>,
+}
+
+/// Return the output of the successful EVM execution or the status as an error otherwise.
+pub fn into_output(status: TransactionStatus) -> Result<Vec<u8>, Error> {
+    match status {
+        TransactionStatus::Succeed(output) => Ok(output),
+        status => Err(Error::TransactionStatus(status)),
+    }
+}
+
+/// Decode the output of the successful transaction.
+pub fn into_outcome<T, F>(result: SubmitResult, decode: F) -> Result<TransactionOutcome<T>, Error>
+where
+    F: FnOnce(&[u8]) -> Result<T, Error>,
+{
+    let output = into_output(result.status)?;
+
+    Ok(TransactionOutcome {
+        output: decode(&output)?,
+        gas_used: result.gas_used,
+        logs: result.logs,
+    })
+}
+
+/// Return the error about the token which doesn't correspond to the type from the ABI.
+#[must_use]
+pub fn invalid_token(expected: &str) -> Error {
+    ethabi::Error::Other(format!("Invalid token, expected: {expected}").into()).into()
+}
+
+/// Convert the log of the EVM transaction into the format expected by `ethabi`.
+#[must_use]
+pub fn raw_log(log: &ResultLog) -> ethabi::RawLog {
+    ethabi::RawLog {
+        topics: log.topics.iter().copied().map(ethabi::Hash::from).collect(),
+        data: log.data.clone(),
+    }
+}
+
+#[must_use]
+pub fn to_abi_address(address: Address) -> ethabi::Address {
+    ethabi::Address::from_slice(address.as_bytes())
+}
+
+#[must_use]
+pub const fn from_abi_address(address: ethabi::Address) -> Address {
+    Address::from_array(address.0)
+}
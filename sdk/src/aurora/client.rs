@@ -1,9 +1,18 @@
+use aurora_engine_transactions::EthTransactionKind;
+use aurora_engine_transactions::legacy::TransactionLegacy;
+use aurora_engine_types::U256;
+use aurora_engine_types::parameters::engine::{SubmitResult, TransactionStatus, ViewCallArgs};
+use aurora_engine_types::types::{Address, Wei};
 use near_jsonrpc_client::errors::{JsonRpcError, JsonRpcServerError};
-use near_primitives::{errors::TxExecutionError, hash::CryptoHash, types::AccountId};
+use near_primitives::errors::TxExecutionError;
+use near_primitives::hash::CryptoHash;
+use near_primitives::types::{AccountId, BlockReference};
 use near_token::NearToken;
 
 use crate::near;
 
+use super::contract::{read, write};
+use super::signer::EvmSigner;
 use super::{ContractMethod, ContractMethodResponse, error::Error};
 
 pub struct Client {
@@ -65,6 +74,21 @@ impl Client {
     }
 
     pub async fn view<M>(&self, account_id: &AccountId, method: M) -> Result<M::Response, Error>
+    where
+        M: ContractMethod,
+        M::Response: ContractMethodResponse,
+    {
+        self.view_at(account_id, method, BlockReference::latest())
+            .await
+    }
+
+    /// Call the view method at the block given by its height, hash or finality.
+    pub async fn view_at<M>(
+        &self,
+        account_id: &AccountId,
+        method: M,
+        block_ref: BlockReference,
+    ) -> Result<M::Response, Error>
     where
         M: ContractMethod,
         M::Response: ContractMethodResponse,
@@ -72,7 +96,12 @@ impl Client {
         let method_name = method.method_name();
         let params = method.params()?;
 
-        let view_result = self.near.view(account_id, method_name).args(params).await;
+        let view_result = self
+            .near
+            .view(account_id, method_name)
+            .args(params)
+            .block_reference(block_ref)
+            .await;
         match view_result {
             Ok(call_result) => Ok(M::parse_response(call_result.result)?),
 
@@ -83,6 +112,57 @@ impl Client {
         }
     }
 
+    /// Execute the EVM call without committing the state changes via the `view` method.
+    pub async fn view_contract_call(
+        &self,
+        account_id: &AccountId,
+        sender: Address,
+        address: Address,
+        amount: Wei,
+        input: Vec<u8>,
+    ) -> Result<TransactionStatus, Error> {
+        let args = ViewCallArgs {
+            sender,
+            address,
+            amount: amount.to_bytes(),
+            input,
+        };
+
+        self.view(account_id, read::ViewCall { args }).await
+    }
+
+    /// Sign the EVM transaction with the signer and execute it via the `submit` method.
+    /// The nonce and the chain id are fetched from the engine.
+    pub async fn submit(
+        &self,
+        account_id: &AccountId,
+        signer: &dyn EvmSigner,
+        to: Option<Address>,
+        value: Wei,
+        data: Vec<u8>,
+    ) -> Result<SubmitResult, Error> {
+        let address = signer.address();
+        let nonce = self.view(account_id, read::GetNonce { address }).await?;
+        let chain_id = self.view(account_id, read::GetChainId).await?.low_u64();
+        let tx = TransactionLegacy {
+            nonce,
+            gas_price: U256::zero(),
+            gas_limit: U256::from(u64::MAX),
+            to,
+            value,
+            data,
+        };
+        let signed_tx = EthTransactionKind::Legacy(signer.sign_transaction(tx, chain_id).await?);
+
+        self.call(
+            account_id,
+            write::Submit {
+                transaction: (&signed_tx).into(),
+            },
+        )
+        .await
+    }
+
     pub const fn near(&self) -> &near::client::Client {
         &self.near
     }
@@ -1,7 +1,13 @@
-use aurora_engine_types::parameters::engine::SubmitResult;
+use aurora_engine_types::parameters::connector::{
+    Erc20Metadata, FungibleTokenMetadata, WithdrawResult,
+};
+use aurora_engine_types::parameters::engine::{StorageBalance, SubmitResult, TransactionStatus};
+use aurora_engine_types::parameters::silo::{SiloParamsArgs, WhitelistStatusArgs};
 use aurora_engine_types::types::{Address, EthGas};
-use borsh::BorshDeserialize;
+use aurora_engine_types::{H256, U256};
 use near_primitives::types::AccountId;
+use near_sdk::json_types::U128;
+use std::io;
 
 use super::{ContractMethodResponse, error::Error};
 
@@ -11,56 +17,97 @@ pub mod write;
 #[cfg(test)]
 mod test_macro;
 
-impl ContractMethodResponse for () {
-    fn parse(_rsp: Vec<u8>) -> Result<Self, Error> {
-        Ok(())
-    }
+/// Implement `ContractMethodResponse` for types returned by the engine in the Borsh format.
+macro_rules! borsh_response {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl ContractMethodResponse for $ty {
+                fn parse(rsp: Vec<u8>) -> Result<Self, Error> {
+                    borsh::from_slice(&rsp).map_err(Into::into)
+                }
+            }
+        )*
+    };
 }
 
-impl ContractMethodResponse for SubmitResult {
-    fn parse(rsp: Vec<u8>) -> Result<Self, Error> {
-        Self::try_from_slice(&rsp).map_err(Into::into)
-    }
-}
+borsh_response!(
+    SubmitResult,
+    TransactionStatus,
+    WithdrawResult,
+    Option<EthGas>,
+    Option<Address>,
+    Option<SiloParamsArgs>,
+    WhitelistStatusArgs,
+    u128,
+    u64,
+    u32,
+    u8,
+    bool,
+);
 
-impl ContractMethodResponse for AccountId {
-    fn parse(rsp: Vec<u8>) -> Result<Self, Error> {
-        Self::try_from_slice(&rsp).map_err(Into::into)
-    }
+/// Implement `ContractMethodResponse` for types returned by the engine in the JSON format.
+macro_rules! json_response {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl ContractMethodResponse for $ty {
+                fn parse(rsp: Vec<u8>) -> Result<Self, Error> {
+                    serde_json::from_slice(&rsp)
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e).into())
+                }
+            }
+        )*
+    };
 }
 
-impl ContractMethodResponse for Address {
-    fn parse(rsp: Vec<u8>) -> Result<Self, Error> {
-        Self::try_from_slice(&rsp).map_err(Into::into)
-    }
-}
+// The NEP-141 amounts are returned as JSON strings.
+json_response!(
+    Erc20Metadata,
+    FungibleTokenMetadata,
+    StorageBalance,
+    U128,
+    serde_json::Value,
+);
 
-impl ContractMethodResponse for Option<EthGas> {
-    fn parse(rsp: Vec<u8>) -> Result<Self, Error> {
-        borsh::from_slice(&rsp).map_err(Into::into)
+impl ContractMethodResponse for () {
+    fn parse(_rsp: Vec<u8>) -> Result<Self, Error> {
+        Ok(())
     }
 }
 
-impl ContractMethodResponse for Option<Address> {
+/// The engine returns account ids as raw UTF-8 bytes.
+impl ContractMethodResponse for AccountId {
     fn parse(rsp: Vec<u8>) -> Result<Self, Error> {
-        borsh::from_slice(&rsp).map_err(Into::into)
+        String::from_utf8(rsp)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e).into())
     }
 }
 
-impl ContractMethodResponse for u128 {
+/// The engine returns addresses as raw 20 bytes.
+impl ContractMethodResponse for Address {
     fn parse(rsp: Vec<u8>) -> Result<Self, Error> {
-        borsh::from_slice(&rsp).map_err(Into::into)
+        Self::try_from_slice(&rsp).map_err(Into::into)
     }
 }
 
-impl ContractMethodResponse for u64 {
+/// The engine returns 256-bit numbers (balances, nonces, chain id) as 32 bytes in big-endian.
+impl ContractMethodResponse for U256 {
     fn parse(rsp: Vec<u8>) -> Result<Self, Error> {
-        borsh::from_slice(&rsp).map_err(Into::into)
+        if rsp.len() == 32 {
+            Ok(Self::from_big_endian(&rsp))
+        } else {
+            Err(io::Error::new(io::ErrorKind::InvalidData, "Expected 32 bytes for U256").into())
+        }
     }
 }
 
-impl ContractMethodResponse for u32 {
+impl ContractMethodResponse for H256 {
     fn parse(rsp: Vec<u8>) -> Result<Self, Error> {
-        borsh::from_slice(&rsp).map_err(Into::into)
+        if rsp.len() == 32 {
+            Ok(Self::from_slice(&rsp))
+        } else {
+            Err(io::Error::new(io::ErrorKind::InvalidData, "Expected 32 bytes for H256").into())
+        }
     }
 }
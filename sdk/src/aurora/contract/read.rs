@@ -1,6 +1,18 @@
 use crate::aurora::ContractMethod;
+use aurora_engine_types::parameters::connector::{
+    Erc20Identifier, Erc20Metadata, FungibleTokenMetadata, PausedMask,
+};
+use aurora_engine_types::parameters::engine::{
+    GetStorageAtArgs, StorageBalance, TransactionStatus, ViewCallArgs,
+};
+use aurora_engine_types::parameters::silo::{
+    SiloParamsArgs, WhitelistKindArgs, WhitelistStatusArgs,
+};
 use aurora_engine_types::types::{Address, EthGas};
+use aurora_engine_types::{H256, U256};
 use near_primitives::types::AccountId;
+use near_sdk::json_types::U128;
+use serde_json::json;
 
 macro_rules! view_method {
     ($name:ident, $method:literal, $response:ty) => {
@@ -21,19 +33,52 @@ macro_rules! view_method {
 }
 
 view_method!(GetOwner, "get_owner", AccountId);
+view_method!(GetVersion, "get_version", String);
+view_method!(GetBridgeProver, "get_bridge_prover", String);
+view_method!(GetChainId, "get_chain_id", U256);
+view_method!(GetUpgradeIndex, "get_upgrade_index", u64);
+view_method!(GetUpgradeDelayBlocks, "get_upgrade_delay_blocks", u64);
+view_method!(GetPausedPrecompiles, "get_paused_precompiles", u32);
+view_method!(GetPausedFlags, "get_paused_flags", PausedMask);
+view_method!(
+    GetEthConnectorContractAccount,
+    "get_eth_connector_contract_account",
+    String
+);
+view_method!(FactoryGetWnearAddress, "factory_get_wnear_address", Address);
 view_method!(GetFixedGas, "get_fixed_gas", Option<EthGas>);
 view_method!(
     GetFallbackAddress,
     "get_erc20_fallback_address",
     Option<Address>
 );
+view_method!(GetSiloParams, "get_silo_params", Option<SiloParamsArgs>);
+view_method!(GetAccountsCounter, "get_accounts_counter", u64);
+view_method!(FtTotalSupply, "ft_total_supply", U128);
+view_method!(FtTotalEthSupplyOnNear, "ft_total_eth_supply_on_near", U128);
+view_method!(
+    FtTotalEthSupplyOnAurora,
+    "ft_total_eth_supply_on_aurora",
+    U128
+);
+view_method!(FtMetadata, "ft_metadata", FungibleTokenMetadata);
+// The layout of the hashchain differs between the engine versions, so it's kept as JSON.
+view_method!(
+    GetLatestHashchain,
+    "get_latest_hashchain",
+    serde_json::Value
+);
 
 pub struct GetBalance {
     pub address: Address,
 }
 
 impl ContractMethod for GetBalance {
-    type Response = Vec<u8>;
+    type Response = U256;
+
+    fn method_type() -> crate::aurora::MethodType {
+        crate::aurora::MethodType::View
+    }
 
     fn method_name(&self) -> &'static str {
         "get_balance"
@@ -43,3 +88,266 @@ impl ContractMethod for GetBalance {
         Ok(self.address.as_bytes().to_vec())
     }
 }
+
+pub struct GetNonce {
+    pub address: Address,
+}
+
+impl ContractMethod for GetNonce {
+    type Response = U256;
+
+    fn method_type() -> crate::aurora::MethodType {
+        crate::aurora::MethodType::View
+    }
+
+    fn method_name(&self) -> &'static str {
+        "get_nonce"
+    }
+
+    fn params(&self) -> Result<Vec<u8>, std::io::Error> {
+        Ok(self.address.as_bytes().to_vec())
+    }
+}
+
+pub struct GetCode {
+    pub address: Address,
+}
+
+impl ContractMethod for GetCode {
+    type Response = Vec<u8>;
+
+    fn method_type() -> crate::aurora::MethodType {
+        crate::aurora::MethodType::View
+    }
+
+    fn method_name(&self) -> &'static str {
+        "get_code"
+    }
+
+    fn params(&self) -> Result<Vec<u8>, std::io::Error> {
+        Ok(self.address.as_bytes().to_vec())
+    }
+}
+
+pub struct GetStorageAt {
+    pub args: GetStorageAtArgs,
+}
+
+impl ContractMethod for GetStorageAt {
+    type Response = H256;
+
+    fn method_type() -> crate::aurora::MethodType {
+        crate::aurora::MethodType::View
+    }
+
+    fn method_name(&self) -> &'static str {
+        "get_storage_at"
+    }
+
+    fn params(&self) -> Result<Vec<u8>, std::io::Error> {
+        borsh::to_vec(&self.args)
+    }
+}
+
+pub struct GetBlockHash {
+    pub height: u64,
+}
+
+impl ContractMethod for GetBlockHash {
+    type Response = H256;
+
+    fn method_type() -> crate::aurora::MethodType {
+        crate::aurora::MethodType::View
+    }
+
+    fn method_name(&self) -> &'static str {
+        "get_block_hash"
+    }
+
+    fn params(&self) -> Result<Vec<u8>, std::io::Error> {
+        Ok(self.height.to_le_bytes().to_vec())
+    }
+}
+
+pub struct GetErc20FromNep141 {
+    pub nep141: AccountId,
+}
+
+impl ContractMethod for GetErc20FromNep141 {
+    type Response = Address;
+
+    fn method_type() -> crate::aurora::MethodType {
+        crate::aurora::MethodType::View
+    }
+
+    fn method_name(&self) -> &'static str {
+        "get_erc20_from_nep141"
+    }
+
+    fn params(&self) -> Result<Vec<u8>, std::io::Error> {
+        borsh::to_vec(self.nep141.as_str())
+    }
+}
+
+pub struct GetNep141FromErc20 {
+    pub address: Address,
+}
+
+impl ContractMethod for GetNep141FromErc20 {
+    type Response = AccountId;
+
+    fn method_type() -> crate::aurora::MethodType {
+        crate::aurora::MethodType::View
+    }
+
+    fn method_name(&self) -> &'static str {
+        "get_nep141_from_erc20"
+    }
+
+    fn params(&self) -> Result<Vec<u8>, std::io::Error> {
+        Ok(self.address.as_bytes().to_vec())
+    }
+}
+
+pub struct GetErc20Metadata {
+    pub erc20_identifier: Erc20Identifier,
+}
+
+impl ContractMethod for GetErc20Metadata {
+    type Response = Erc20Metadata;
+
+    fn method_type() -> crate::aurora::MethodType {
+        crate::aurora::MethodType::View
+    }
+
+    fn method_name(&self) -> &'static str {
+        "get_erc20_metadata"
+    }
+
+    fn params(&self) -> Result<Vec<u8>, std::io::Error> {
+        serde_json::to_vec(&self.erc20_identifier).map_err(Into::into)
+    }
+}
+
+pub struct GetWhitelistStatus {
+    pub args: WhitelistKindArgs,
+}
+
+impl ContractMethod for GetWhitelistStatus {
+    type Response = WhitelistStatusArgs;
+
+    fn method_type() -> crate::aurora::MethodType {
+        crate::aurora::MethodType::View
+    }
+
+    fn method_name(&self) -> &'static str {
+        "get_whitelist_status"
+    }
+
+    fn params(&self) -> Result<Vec<u8>, std::io::Error> {
+        borsh::to_vec(&self.args)
+    }
+}
+
+pub struct ViewCall {
+    pub args: ViewCallArgs,
+}
+
+impl ContractMethod for ViewCall {
+    type Response = TransactionStatus;
+
+    fn method_type() -> crate::aurora::MethodType {
+        crate::aurora::MethodType::View
+    }
+
+    fn method_name(&self) -> &'static str {
+        "view"
+    }
+
+    fn params(&self) -> Result<Vec<u8>, std::io::Error> {
+        borsh::to_vec(&self.args)
+    }
+}
+
+/// NEP-141 balance of ETH of the NEAR account.
+pub struct FtBalanceOf {
+    pub account_id: AccountId,
+}
+
+impl ContractMethod for FtBalanceOf {
+    type Response = U128;
+
+    fn method_type() -> crate::aurora::MethodType {
+        crate::aurora::MethodType::View
+    }
+
+    fn method_name(&self) -> &'static str {
+        "ft_balance_of"
+    }
+
+    fn params(&self) -> Result<Vec<u8>, std::io::Error> {
+        serde_json::to_vec(&json!({ "account_id": self.account_id })).map_err(Into::into)
+    }
+}
+
+/// Balance of ETH of the EVM address.
+pub struct FtBalanceOfEth {
+    pub address: Address,
+}
+
+impl ContractMethod for FtBalanceOfEth {
+    type Response = U128;
+
+    fn method_type() -> crate::aurora::MethodType {
+        crate::aurora::MethodType::View
+    }
+
+    fn method_name(&self) -> &'static str {
+        "ft_balance_of_eth"
+    }
+
+    fn params(&self) -> Result<Vec<u8>, std::io::Error> {
+        serde_json::to_vec(&json!({ "address": self.address.encode() })).map_err(Into::into)
+    }
+}
+
+pub struct StorageBalanceOf {
+    pub account_id: AccountId,
+}
+
+impl ContractMethod for StorageBalanceOf {
+    type Response = StorageBalance;
+
+    fn method_type() -> crate::aurora::MethodType {
+        crate::aurora::MethodType::View
+    }
+
+    fn method_name(&self) -> &'static str {
+        "storage_balance_of"
+    }
+
+    fn params(&self) -> Result<Vec<u8>, std::io::Error> {
+        serde_json::to_vec(&json!({ "account_id": self.account_id })).map_err(Into::into)
+    }
+}
+
+/// Whether the deposit with the Borsh-serialized proof has been already made.
+pub struct IsUsedProof {
+    pub proof: Vec<u8>,
+}
+
+impl ContractMethod for IsUsedProof {
+    type Response = bool;
+
+    fn method_type() -> crate::aurora::MethodType {
+        crate::aurora::MethodType::View
+    }
+
+    fn method_name(&self) -> &'static str {
+        "is_used_proof"
+    }
+
+    fn params(&self) -> Result<Vec<u8>, std::io::Error> {
+        Ok(self.proof.clone())
+    }
+}
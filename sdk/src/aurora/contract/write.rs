@@ -1,10 +1,18 @@
 use aurora_engine_types::{
     parameters::{
         connector::{
-            MirrorErc20TokenArgs, SetErc20MetadataArgs, SetEthConnectorContractAccountArgs,
+            FtTransferArgs, FtTransferCallArgs, InitCallArgs, MirrorErc20TokenArgs,
+            PauseEthConnectorArgs, SetErc20MetadataArgs, SetEthConnectorContractAccountArgs,
+            StorageDepositArgs, StorageUnregisterArgs, StorageWithdrawArgs, WithdrawCallArgs,
+            WithdrawResult,
+        },
+        engine::{
+            CallArgs, DeployErc20TokenArgs, NewCallArgs, PausePrecompilesCallArgs, RelayerKeyArgs,
+            RelayerKeyManagerArgs, SetOwnerArgs, SetUpgradeDelayBlocksArgs, StartHashchainArgs,
+            StorageBalance, SubmitArgs, SubmitResult,
         },
-        engine::{DeployErc20TokenArgs, SubmitArgs, SubmitResult},
         silo::{FixedGasArgs, SiloParamsArgs, WhitelistArgs, WhitelistStatusArgs},
+        xcc::{AddressVersionUpdateArgs, FundXccArgs, WithdrawWnearToRouterArgs},
     },
     types::Address,
 };
@@ -13,6 +21,210 @@ use std::io;
 use crate::ContractMethod as ContractMethodDerive;
 use crate::aurora::{ContractMethod, error::Error};
 
+/// Deposit required by the NEP-141 methods which move tokens to confirm the full access key.
+const ONE_YOCTO: u128 = 1;
+
+#[derive(ContractMethodDerive)]
+#[contract_method(method = "new", response = ())]
+pub struct New {
+    #[contract_param(serialize_as = "borsh")]
+    pub args: NewCallArgs,
+}
+
+#[derive(ContractMethodDerive)]
+#[contract_method(method = "set_owner", response = ())]
+pub struct SetOwner {
+    #[contract_param(serialize_as = "borsh")]
+    pub args: SetOwnerArgs,
+}
+
+pub struct RegisterRelayer {
+    pub address: Address,
+}
+
+impl ContractMethod for RegisterRelayer {
+    type Response = ();
+
+    fn method_name(&self) -> &'static str {
+        "register_relayer"
+    }
+
+    fn params(&self) -> Result<Vec<u8>, io::Error> {
+        Ok(self.address.as_bytes().to_vec())
+    }
+}
+
+#[derive(ContractMethodDerive)]
+#[contract_method(method = "start_hashchain", response = ())]
+pub struct StartHashchain {
+    #[contract_param(serialize_as = "borsh")]
+    pub args: StartHashchainArgs,
+}
+
+#[derive(ContractMethodDerive)]
+#[contract_method(method = "pause_contract", response = ())]
+pub struct PauseContract;
+
+#[derive(ContractMethodDerive)]
+#[contract_method(method = "resume_contract", response = ())]
+pub struct ResumeContract;
+
+#[derive(ContractMethodDerive)]
+#[contract_method(method = "pause_precompiles", response = ())]
+pub struct PausePrecompiles {
+    #[contract_param(serialize_as = "borsh")]
+    pub args: PausePrecompilesCallArgs,
+}
+
+#[derive(ContractMethodDerive)]
+#[contract_method(method = "resume_precompiles", response = ())]
+pub struct ResumePrecompiles {
+    #[contract_param(serialize_as = "borsh")]
+    pub args: PausePrecompilesCallArgs,
+}
+
+#[derive(ContractMethodDerive)]
+#[contract_method(method = "set_paused_flags", response = ())]
+pub struct SetPausedFlags {
+    #[contract_param(serialize_as = "borsh")]
+    pub args: PauseEthConnectorArgs,
+}
+
+#[derive(ContractMethodDerive)]
+#[contract_method(method = "set_key_manager", response = ())]
+pub struct SetKeyManager {
+    #[contract_param(serialize_as = "json")]
+    pub args: RelayerKeyManagerArgs,
+}
+
+/// Add a function call access key for the relayer. The attached deposit is used as
+/// an allowance of the key.
+pub struct AddRelayerKey {
+    pub args: RelayerKeyArgs,
+    pub allowance: u128,
+}
+
+impl ContractMethod for AddRelayerKey {
+    type Response = ();
+
+    fn method_name(&self) -> &'static str {
+        "add_relayer_key"
+    }
+
+    fn deposit(&self) -> u128 {
+        self.allowance
+    }
+
+    fn params(&self) -> Result<Vec<u8>, io::Error> {
+        serde_json::to_vec(&self.args).map_err(Into::into)
+    }
+}
+
+#[derive(ContractMethodDerive)]
+#[contract_method(method = "remove_relayer_key", response = ())]
+pub struct RemoveRelayerKey {
+    #[contract_param(serialize_as = "json")]
+    pub args: RelayerKeyArgs,
+}
+
+#[derive(ContractMethodDerive)]
+#[contract_method(method = "set_upgrade_delay_blocks", response = ())]
+pub struct SetUpgradeDelayBlocks {
+    #[contract_param(serialize_as = "borsh")]
+    pub args: SetUpgradeDelayBlocksArgs,
+}
+
+#[derive(ContractMethodDerive)]
+#[contract_method(method = "upgrade", response = ())]
+pub struct Upgrade {
+    #[contract_param]
+    pub code: Vec<u8>,
+}
+
+#[derive(ContractMethodDerive)]
+#[contract_method(method = "stage_upgrade", response = ())]
+pub struct StageUpgrade {
+    #[contract_param]
+    pub code: Vec<u8>,
+}
+
+#[derive(ContractMethodDerive)]
+#[contract_method(method = "deploy_upgrade", response = ())]
+pub struct DeployUpgrade;
+
+pub struct FactorySetWnearAddress {
+    pub address: Address,
+}
+
+impl ContractMethod for FactorySetWnearAddress {
+    type Response = ();
+
+    fn method_name(&self) -> &'static str {
+        "factory_set_wnear_address"
+    }
+
+    fn params(&self) -> Result<Vec<u8>, io::Error> {
+        Ok(self.address.as_bytes().to_vec())
+    }
+}
+
+/// Create and/or fund the XCC sub-account with the attached deposit.
+pub struct FundXccSubAccount {
+    pub args: FundXccArgs,
+    pub deposit: u128,
+}
+
+impl ContractMethod for FundXccSubAccount {
+    type Response = ();
+
+    fn method_name(&self) -> &'static str {
+        "fund_xcc_sub_account"
+    }
+
+    fn deposit(&self) -> u128 {
+        self.deposit
+    }
+
+    fn params(&self) -> Result<Vec<u8>, io::Error> {
+        borsh::to_vec(&self.args)
+    }
+}
+
+#[derive(ContractMethodDerive)]
+#[contract_method(method = "withdraw_wnear_to_router", response = ())]
+pub struct WithdrawWnearToRouter {
+    #[contract_param(serialize_as = "borsh")]
+    pub args: WithdrawWnearToRouterArgs,
+}
+
+#[derive(ContractMethodDerive)]
+#[contract_method(method = "call", response = SubmitResult)]
+pub struct Call {
+    #[contract_param(serialize_as = "borsh")]
+    pub args: CallArgs,
+}
+
+#[derive(ContractMethodDerive)]
+#[contract_method(method = "deploy_code", response = SubmitResult)]
+pub struct DeployCode {
+    #[contract_param]
+    pub code: Vec<u8>,
+}
+
+#[derive(ContractMethodDerive)]
+#[contract_method(method = "set_eth_connector_contract_data", response = ())]
+pub struct SetEthConnectorContractData {
+    #[contract_param(serialize_as = "borsh")]
+    pub args: InitCallArgs,
+}
+
+#[derive(ContractMethodDerive)]
+#[contract_method(method = "add_entry_to_whitelist_batch", response = ())]
+pub struct AddEntryToWhitelistBatch {
+    #[contract_param(serialize_as = "borsh")]
+    pub args: Vec<WhitelistArgs>,
+}
+
 #[derive(ContractMethodDerive)]
 #[contract_method(method = "set_eth_connector_contract_account", response = ())]
 pub struct SetEthConnectorContractAccount {
@@ -88,6 +300,13 @@ pub struct SetWhitelistStatus {
     pub args: WhitelistStatusArgs,
 }
 
+#[derive(ContractMethodDerive)]
+#[contract_method(method = "submit", response = SubmitResult)]
+pub struct Submit {
+    #[contract_param]
+    pub transaction: Vec<u8>,
+}
+
 #[derive(ContractMethodDerive)]
 #[contract_method(method = "submit_with_args", response = SubmitResult)]
 pub struct SubmitWithArgs {
@@ -127,6 +346,174 @@ pub struct SetERC20Metadata {
     pub args: SetErc20MetadataArgs,
 }
 
+#[derive(ContractMethodDerive)]
+#[contract_method(method = "factory_update_address_version", response = ())]
+pub struct FactoryUpdateAddressVersion {
+    #[contract_param(serialize_as = "borsh")]
+    pub args: AddressVersionUpdateArgs,
+}
+
+#[derive(ContractMethodDerive)]
+#[contract_method(method = "state_migration", response = ())]
+pub struct StateMigration;
+
+/// Deposit ETH with the Borsh-serialized proof of the locking on Ethereum.
+#[derive(ContractMethodDerive)]
+#[contract_method(method = "deposit", response = ())]
+pub struct Deposit {
+    #[contract_param]
+    pub proof: Vec<u8>,
+}
+
+/// Withdraw ETH to the address on Ethereum.
+pub struct Withdraw {
+    pub args: WithdrawCallArgs,
+}
+
+impl ContractMethod for Withdraw {
+    type Response = WithdrawResult;
+
+    fn method_name(&self) -> &'static str {
+        "withdraw"
+    }
+
+    fn deposit(&self) -> u128 {
+        ONE_YOCTO
+    }
+
+    fn params(&self) -> Result<Vec<u8>, io::Error> {
+        borsh::to_vec(&self.args)
+    }
+}
+
+/// Create the EVM account with the nonce and the balance. It's only available in the engine built
+/// for the integration tests.
+pub struct MintAccount {
+    pub address: Address,
+    pub nonce: u64,
+    pub balance: u64,
+}
+
+impl ContractMethod for MintAccount {
+    type Response = ();
+
+    fn method_name(&self) -> &'static str {
+        "mint_account"
+    }
+
+    fn params(&self) -> Result<Vec<u8>, io::Error> {
+        borsh::to_vec(&(self.address.raw().0, self.nonce, self.balance))
+    }
+}
+
+pub struct FtTransfer {
+    pub args: FtTransferArgs,
+}
+
+impl ContractMethod for FtTransfer {
+    type Response = ();
+
+    fn method_name(&self) -> &'static str {
+        "ft_transfer"
+    }
+
+    fn deposit(&self) -> u128 {
+        ONE_YOCTO
+    }
+
+    fn params(&self) -> Result<Vec<u8>, io::Error> {
+        serde_json::to_vec(&self.args).map_err(Into::into)
+    }
+}
+
+pub struct FtTransferCall {
+    pub args: FtTransferCallArgs,
+}
+
+impl ContractMethod for FtTransferCall {
+    type Response = ();
+
+    fn method_name(&self) -> &'static str {
+        "ft_transfer_call"
+    }
+
+    fn deposit(&self) -> u128 {
+        ONE_YOCTO
+    }
+
+    fn params(&self) -> Result<Vec<u8>, io::Error> {
+        serde_json::to_vec(&self.args).map_err(Into::into)
+    }
+}
+
+/// Register the account for the NEP-141 storage with the attached deposit.
+pub struct StorageDeposit {
+    pub args: StorageDepositArgs,
+    pub deposit: u128,
+}
+
+impl ContractMethod for StorageDeposit {
+    type Response = StorageBalance;
+
+    fn method_name(&self) -> &'static str {
+        "storage_deposit"
+    }
+
+    fn deposit(&self) -> u128 {
+        self.deposit
+    }
+
+    fn params(&self) -> Result<Vec<u8>, io::Error> {
+        serde_json::to_vec(&self.args).map_err(Into::into)
+    }
+}
+
+pub struct StorageWithdraw {
+    pub args: StorageWithdrawArgs,
+}
+
+impl ContractMethod for StorageWithdraw {
+    type Response = StorageBalance;
+
+    fn method_name(&self) -> &'static str {
+        "storage_withdraw"
+    }
+
+    fn deposit(&self) -> u128 {
+        ONE_YOCTO
+    }
+
+    fn params(&self) -> Result<Vec<u8>, io::Error> {
+        serde_json::to_vec(&self.args).map_err(Into::into)
+    }
+}
+
+/// Unregister the account from the NEP-141 storage. The response tells whether it was registered.
+pub struct StorageUnregister {
+    pub args: StorageUnregisterArgs,
+}
+
+impl ContractMethod for StorageUnregister {
+    type Response = bool;
+
+    fn method_name(&self) -> &'static str {
+        "storage_unregister"
+    }
+
+    fn deposit(&self) -> u128 {
+        ONE_YOCTO
+    }
+
+    fn params(&self) -> Result<Vec<u8>, io::Error> {
+        serde_json::to_vec(&self.args).map_err(Into::into)
+    }
+
+    fn parse_response(response: Vec<u8>) -> Result<bool, Error> {
+        serde_json::from_slice(&response)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e).into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::aurora::{ContractMethod, common, contract::write::DeployERC20};
@@ -1,5 +1,6 @@
 use std::io;
 
+use aurora_engine_types::parameters::engine::TransactionStatus;
 use aurora_engine_types::types::address::error::AddressError;
 use serde::Deserialize;
 
@@ -13,6 +14,12 @@ pub enum Error {
     Engine(#[from] EngineError),
     #[error(transparent)]
     Near(#[from] crate::near::error::Error),
+    #[error("ABI error: {0}")]
+    Abi(#[from] ethabi::Error),
+    #[error("EVM transaction failed with status: {0:?}")]
+    TransactionStatus(TransactionStatus),
+    #[error("Signer error: {0}")]
+    Signer(String),
 }
 
 impl From<AddressError> for Error {
@@ -0,0 +1,167 @@
+//! High-level client for interacting with the EVM deployed on the Aurora Engine.
+use aurora_engine_types::parameters::engine::{
+    GetStorageAtArgs, ResultLog, SubmitResult, TransactionStatus,
+};
+use aurora_engine_types::types::{Address, Wei};
+use aurora_engine_types::{H256, U256};
+use near_primitives::types::AccountId;
+
+use super::client::Client;
+use super::contract::read;
+use super::error::Error;
+use super::signer::EvmSigner;
+
+/// Structured result of the EVM transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvmOutcome {
+    /// Status of the execution with the output or the revert reason.
+    pub status: TransactionStatus,
+    /// Amount of EVM gas used by the transaction.
+    pub gas_used: u64,
+    /// Logs emitted during the transaction execution.
+    pub logs: Vec<ResultLog>,
+}
+
+impl EvmOutcome {
+    #[must_use]
+    pub const fn is_success(&self) -> bool {
+        matches!(self.status, TransactionStatus::Succeed(_))
+    }
+
+    /// Return the output of the successful transaction.
+    #[must_use]
+    pub fn output(&self) -> Option<&[u8]> {
+        match &self.status {
+            TransactionStatus::Succeed(output) => Some(output),
+            _ => None,
+        }
+    }
+
+    /// Return the output of the successful transaction or the status as an error otherwise.
+    pub fn into_output(self) -> Result<Vec<u8>, Error> {
+        super::abi::into_output(self.status)
+    }
+
+    /// Return the address of the contract created by the successful deployment.
+    pub fn contract_address(&self) -> Result<Address, Error> {
+        match &self.status {
+            TransactionStatus::Succeed(output) => Address::try_from_slice(output).map_err(|e| {
+                std::io::Error::new(std::io::ErrorKind::InvalidData, e.to_string()).into()
+            }),
+            status => Err(Error::TransactionStatus(status.clone())),
+        }
+    }
+}
+
+impl From<SubmitResult> for EvmOutcome {
+    fn from(result: SubmitResult) -> Self {
+        Self {
+            status: result.status,
+            gas_used: result.gas_used,
+            logs: result.logs,
+        }
+    }
+}
+
+/// Client which signs EVM transactions with its signer and sends them to the engine.
+pub struct EvmClient<'a> {
+    client: &'a Client,
+    engine: AccountId,
+    signer: Box<dyn EvmSigner>,
+}
+
+impl<'a> EvmClient<'a> {
+    #[must_use]
+    pub fn new<S: EvmSigner + 'static>(client: &'a Client, engine: AccountId, signer: S) -> Self {
+        Self {
+            client,
+            engine,
+            signer: Box::new(signer),
+        }
+    }
+
+    /// Return the EVM address of the signer.
+    #[must_use]
+    pub fn address(&self) -> Address {
+        self.signer.address()
+    }
+
+    #[must_use]
+    pub const fn engine(&self) -> &AccountId {
+        &self.engine
+    }
+
+    /// Deploy the EVM code. The output of the successful outcome contains the address of the
+    /// created contract, see [`EvmOutcome::contract_address`].
+    pub async fn deploy(&self, code: Vec<u8>, value: Wei) -> Result<EvmOutcome, Error> {
+        self.submit(None, value, code).await
+    }
+
+    /// Call the EVM contract and commit the state changes.
+    pub async fn call(
+        &self,
+        address: Address,
+        value: Wei,
+        input: Vec<u8>,
+    ) -> Result<EvmOutcome, Error> {
+        self.submit(Some(address), value, input).await
+    }
+
+    /// Execute the EVM call from the signer's address without committing the state changes.
+    pub async fn view(
+        &self,
+        address: Address,
+        value: Wei,
+        input: Vec<u8>,
+    ) -> Result<TransactionStatus, Error> {
+        self.client
+            .view_contract_call(&self.engine, self.address(), address, value, input)
+            .await
+    }
+
+    /// Transfer the base currency to the address.
+    pub async fn transfer(&self, to: Address, value: Wei) -> Result<EvmOutcome, Error> {
+        self.submit(Some(to), value, Vec::new()).await
+    }
+
+    pub async fn nonce(&self, address: Address) -> Result<U256, Error> {
+        self.client
+            .view(&self.engine, read::GetNonce { address })
+            .await
+    }
+
+    pub async fn balance(&self, address: Address) -> Result<Wei, Error> {
+        self.client
+            .view(&self.engine, read::GetBalance { address })
+            .await
+            .map(Wei::new)
+    }
+
+    pub async fn code(&self, address: Address) -> Result<Vec<u8>, Error> {
+        self.client
+            .view(&self.engine, read::GetCode { address })
+            .await
+    }
+
+    pub async fn storage_at(&self, address: Address, key: H256) -> Result<H256, Error> {
+        let args = GetStorageAtArgs {
+            address,
+            key: key.0,
+        };
+        self.client
+            .view(&self.engine, read::GetStorageAt { args })
+            .await
+    }
+
+    async fn submit(
+        &self,
+        to: Option<Address>,
+        value: Wei,
+        data: Vec<u8>,
+    ) -> Result<EvmOutcome, Error> {
+        self.client
+            .submit(&self.engine, self.signer.as_ref(), to, value, data)
+            .await
+            .map(Into::into)
+    }
+}
@@ -5,10 +5,14 @@ use near_jsonrpc_client::methods::query::RpcQueryError;
 use near_primitives::errors::{ActionError, ActionErrorKind, FunctionCallError};
 use regex::Regex;
 
+pub mod abi;
 pub mod client;
 pub mod common;
 pub mod contract;
 pub mod error;
+pub mod evm;
+pub mod signer;
+pub mod transaction;
 
 pub use aurora_engine_types::account_id::AccountId;
 pub use aurora_engine_types::parameters;
@@ -66,10 +70,10 @@ where
     }
 }
 
-pub trait ContractMethodResponse: borsh::BorshDeserialize {
-    fn parse(value: Vec<u8>) -> Result<Self, error::Error> {
-        borsh::from_slice(&value).map_err(Into::into)
-    }
+/// Decoding of the value returned by the contract method. The engine returns most values in the
+/// Borsh format, which is parsed with `borsh::from_slice`, and some as raw bytes.
+pub trait ContractMethodResponse: Sized {
+    fn parse(value: Vec<u8>) -> Result<Self, error::Error>;
 }
 
 pub(crate) fn parse_action_error(action_error: ActionError) -> Result<error::Error, io::Error> {
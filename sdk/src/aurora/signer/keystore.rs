@@ -0,0 +1,147 @@
+//! Decryption of the JSON keystore files according to the Web3 Secret Storage Definition.
+use std::path::Path;
+
+use aes::cipher::{KeyIvInit, StreamCipher};
+use serde::Deserialize;
+
+use super::SecretKey;
+use crate::aurora::error::Error;
+
+type Aes128Ctr = ctr::Ctr128BE<aes::Aes128>;
+
+#[derive(Debug, Deserialize)]
+struct Keystore {
+    #[serde(alias = "Crypto")]
+    crypto: Crypto,
+}
+
+#[derive(Debug, Deserialize)]
+struct Crypto {
+    cipher: String,
+    cipherparams: CipherParams,
+    #[serde(with = "hex::serde")]
+    ciphertext: Vec<u8>,
+    #[serde(flatten)]
+    kdf: Kdf,
+    #[serde(with = "hex::serde")]
+    mac: Vec<u8>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CipherParams {
+    #[serde(with = "hex::serde")]
+    iv: Vec<u8>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "kdf", content = "kdfparams", rename_all = "lowercase")]
+enum Kdf {
+    Scrypt {
+        dklen: usize,
+        n: u64,
+        r: u32,
+        p: u32,
+        #[serde(with = "hex::serde")]
+        salt: Vec<u8>,
+    },
+    Pbkdf2 {
+        dklen: usize,
+        c: u32,
+        prf: String,
+        #[serde(with = "hex::serde")]
+        salt: Vec<u8>,
+    },
+}
+
+/// Read the keystore file and decrypt the secret key with the password.
+pub fn decrypt_file<P: AsRef<Path>>(path: P, password: &str) -> Result<SecretKey, Error> {
+    let json = std::fs::read_to_string(path)?;
+    decrypt(&json, password)
+}
+
+/// Decrypt the secret key from the keystore in JSON format with the password.
+pub fn decrypt(json: &str, password: &str) -> Result<SecretKey, Error> {
+    let keystore: Keystore =
+        serde_json::from_str(json).map_err(|e| Error::Signer(format!("Invalid keystore: {e}")))?;
+    let crypto = keystore.crypto;
+
+    if crypto.cipher != "aes-128-ctr" {
+        return Err(Error::Signer(format!(
+            "Unsupported keystore cipher: {}",
+            crypto.cipher
+        )));
+    }
+
+    let key = derive_key(&crypto.kdf, password.as_bytes())?;
+    let mac = aurora_engine_sdk::keccak(&[&key[16..32], &crypto.ciphertext].concat());
+
+    if mac.as_bytes() != crypto.mac.as_slice() {
+        return Err(Error::Signer(
+            "Wrong keystore password or corrupted keystore".to_string(),
+        ));
+    }
+
+    let mut secret = crypto.ciphertext;
+    Aes128Ctr::new_from_slices(&key[..16], &crypto.cipherparams.iv)
+        .map_err(|e| Error::Signer(format!("Invalid keystore cipher params: {e}")))?
+        .apply_keystream(&mut secret);
+
+    SecretKey::parse_slice(&secret).map_err(|e| Error::Signer(format!("Invalid secret key: {e}")))
+}
+
+fn derive_key(kdf: &Kdf, password: &[u8]) -> Result<Vec<u8>, Error> {
+    match kdf {
+        Kdf::Scrypt {
+            dklen,
+            n,
+            r,
+            p,
+            salt,
+        } => {
+            check_dklen(*dklen)?;
+
+            if !n.is_power_of_two() {
+                return Err(Error::Signer(
+                    "Invalid scrypt parameter n: must be a power of two".to_string(),
+                ));
+            }
+
+            #[allow(clippy::cast_possible_truncation)]
+            let log_n = n.trailing_zeros() as u8;
+            let params = scrypt::Params::new(log_n, *r, *p, *dklen)
+                .map_err(|e| Error::Signer(format!("Invalid scrypt parameters: {e}")))?;
+            let mut key = vec![0; *dklen];
+            scrypt::scrypt(password, salt, &params, &mut key)
+                .map_err(|e| Error::Signer(format!("Invalid scrypt parameters: {e}")))?;
+
+            Ok(key)
+        }
+        Kdf::Pbkdf2 {
+            dklen,
+            c,
+            prf,
+            salt,
+        } => {
+            check_dklen(*dklen)?;
+
+            if prf != "hmac-sha256" {
+                return Err(Error::Signer(format!("Unsupported keystore prf: {prf}")));
+            }
+
+            let mut key = vec![0; *dklen];
+            pbkdf2::pbkdf2_hmac::<sha2::Sha256>(password, salt, *c, &mut key);
+
+            Ok(key)
+        }
+    }
+}
+
+fn check_dklen(dklen: usize) -> Result<(), Error> {
+    if dklen < 32 {
+        return Err(Error::Signer(format!(
+            "Invalid keystore derived key length: {dklen}"
+        )));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+//! Signers of EVM transactions.
+//!
+//! The [`EvmSigner`] trait abstracts the place where the secret key lives, so the key could be
+//! kept in memory, loaded from an encrypted keystore file or never leave a remote signing
+//! service.
+use std::path::Path;
+
+use aurora_engine_transactions::legacy::{LegacyEthSignedTransaction, TransactionLegacy};
+use aurora_engine_types::types::Address;
+
+use super::error::Error;
+use super::transaction::{self, SecretKey};
+
+pub mod keystore;
+pub mod remote;
+
+pub use remote::RemoteSigner;
+
+/// Signer of the EVM transactions.
+#[async_trait::async_trait]
+pub trait EvmSigner: Send + Sync {
+    /// Return the EVM address of the signer.
+    fn address(&self) -> Address;
+
+    /// Sign the legacy transaction according to EIP-155.
+    async fn sign_transaction(
+        &self,
+        tx: TransactionLegacy,
+        chain_id: u64,
+    ) -> Result<LegacyEthSignedTransaction, Error>;
+}
+
+/// Signer keeping the secret key in memory.
+pub struct LocalSigner {
+    secret_key: SecretKey,
+    address: Address,
+}
+
+impl LocalSigner {
+    #[must_use]
+    pub fn new(secret_key: SecretKey) -> Self {
+        let address = transaction::address_from_secret_key(&secret_key);
+
+        Self {
+            secret_key,
+            address,
+        }
+    }
+
+    /// Create the signer from the secret key encoded in hex.
+    pub fn from_hex(key: &str) -> Result<Self, Error> {
+        let bytes = hex::decode(key.trim().trim_start_matches("0x"))
+            .map_err(|e| Error::Signer(format!("Invalid secret key: {e}")))?;
+        let bytes: [u8; 32] = bytes
+            .try_into()
+            .map_err(|_| Error::Signer("Invalid secret key: expected 32 bytes".to_string()))?;
+
+        SecretKey::parse(&bytes)
+            .map(Self::new)
+            .map_err(|e| Error::Signer(format!("Invalid secret key: {e}")))
+    }
+
+    /// Create the signer from the encrypted JSON keystore file (Web3 Secret Storage).
+    pub fn from_keystore<P: AsRef<Path>>(path: P, password: &str) -> Result<Self, Error> {
+        keystore::decrypt_file(path, password).map(Self::new)
+    }
+
+    #[must_use]
+    pub const fn secret_key(&self) -> &SecretKey {
+        &self.secret_key
+    }
+}
+
+#[async_trait::async_trait]
+impl EvmSigner for LocalSigner {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: TransactionLegacy,
+        chain_id: u64,
+    ) -> Result<LegacyEthSignedTransaction, Error> {
+        Ok(transaction::sign_transaction(
+            tx,
+            chain_id,
+            &self.secret_key,
+        ))
+    }
+}
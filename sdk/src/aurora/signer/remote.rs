@@ -0,0 +1,198 @@
+//! Signer delegating signing to a remote service via JSON-RPC.
+//!
+//! The service must implement the `eth_signTransaction` method (e.g. `Web3Signer`) and return
+//! the RLP-encoded signed transaction in hex. The JSON-RPC requests are sent via HTTP(S) or
+//! via a Unix socket (one JSON message per line).
+use std::path::PathBuf;
+
+use aurora_engine_transactions::legacy::{LegacyEthSignedTransaction, TransactionLegacy};
+use aurora_engine_types::U256;
+use aurora_engine_types::types::Address;
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use super::EvmSigner;
+use crate::aurora::error::Error;
+
+const UNIX_SCHEME: &str = "unix://";
+
+#[derive(Debug, Clone)]
+enum Transport {
+    Http(String),
+    Unix(PathBuf),
+}
+
+/// Signer which never holds the secret key and asks the remote service to sign transactions.
+#[derive(Debug, Clone)]
+pub struct RemoteSigner {
+    transport: Transport,
+    address: Address,
+}
+
+impl RemoteSigner {
+    /// Create the signer for the account with `address` managed by the service at `url`.
+    /// The url should start with `http://`, `https://` or `unix://` followed by a path to
+    /// the Unix socket.
+    pub fn new(url: &str, address: Address) -> Result<Self, Error> {
+        let transport = if let Some(path) = url.strip_prefix(UNIX_SCHEME) {
+            Transport::Unix(PathBuf::from(path))
+        } else if url.starts_with("http://") || url.starts_with("https://") {
+            Transport::Http(url.to_string())
+        } else {
+            return Err(Error::Signer(format!(
+                "Unsupported remote signer url: {url}, expected http(s):// or unix://"
+            )));
+        };
+
+        Ok(Self { transport, address })
+    }
+
+    async fn request(&self, body: Value) -> Result<Value, Error> {
+        let response = match &self.transport {
+            Transport::Http(url) => reqwest::Client::new()
+                .post(url)
+                .json(&body)
+                .send()
+                .await
+                .and_then(reqwest::Response::error_for_status)
+                .map_err(|e| Error::Signer(format!("Remote signer request failed: {e}")))?
+                .json::<RpcResponse>()
+                .await
+                .map_err(|e| Error::Signer(format!("Invalid remote signer response: {e}")))?,
+            Transport::Unix(path) => unix_request(path, &body).await?,
+        };
+
+        match response {
+            RpcResponse {
+                result: Some(result),
+                ..
+            } => Ok(result),
+            RpcResponse {
+                error: Some(error), ..
+            } => Err(Error::Signer(format!(
+                "Remote signer error {}: {}",
+                error.code, error.message
+            ))),
+            _ => Err(Error::Signer(
+                "Invalid remote signer response: no result".to_string(),
+            )),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl EvmSigner for RemoteSigner {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: TransactionLegacy,
+        chain_id: u64,
+    ) -> Result<LegacyEthSignedTransaction, Error> {
+        let body = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "eth_signTransaction",
+            "params": [transaction_to_json(&tx, self.address, chain_id)],
+        });
+        let result = self.request(body).await?;
+        let raw = result
+            .as_str()
+            .and_then(|raw| hex::decode(raw.trim_start_matches("0x")).ok())
+            .ok_or_else(|| {
+                Error::Signer("Invalid remote signer response: expected hex".to_string())
+            })?;
+        let signed: LegacyEthSignedTransaction = rlp::decode(&raw).map_err(|e| {
+            Error::Signer(format!(
+                "Invalid signed transaction from remote signer: {e}"
+            ))
+        })?;
+
+        // Don't trust the service blindly: the transaction must be the one we asked to sign.
+        if signed.transaction != tx {
+            return Err(Error::Signer(
+                "Remote signer returned a different transaction".to_string(),
+            ));
+        }
+        if signed.chain_id() != Some(chain_id) {
+            return Err(Error::Signer(format!(
+                "Remote signer used unexpected chain id: {:?}",
+                signed.chain_id()
+            )));
+        }
+        if signed.sender().ok() != Some(self.address) {
+            return Err(Error::Signer(format!(
+                "Remote signer signed the transaction not by 0x{}",
+                self.address.encode()
+            )));
+        }
+
+        Ok(signed)
+    }
+}
+
+/// Convert the transaction to the object expected by `eth_signTransaction`.
+#[must_use]
+pub fn transaction_to_json(tx: &TransactionLegacy, from: Address, chain_id: u64) -> Value {
+    let mut json = json!({
+        "from": format!("0x{}", from.encode()),
+        "nonce": to_quantity(tx.nonce),
+        "gasPrice": to_quantity(tx.gas_price),
+        "gas": to_quantity(tx.gas_limit),
+        "value": to_quantity(tx.value.raw()),
+        "data": format!("0x{}", hex::encode(&tx.data)),
+        "chainId": to_quantity(chain_id.into()),
+    });
+
+    if let Some(to) = tx.to {
+        json["to"] = format!("0x{}", to.encode()).into();
+    }
+
+    json
+}
+
+fn to_quantity(value: U256) -> String {
+    format!("{value:#x}")
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcResponse {
+    result: Option<Value>,
+    error: Option<RpcError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+#[cfg(unix)]
+async fn unix_request(path: &std::path::Path, body: &Value) -> Result<RpcResponse, Error> {
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+    let mut stream = tokio::net::UnixStream::connect(path).await.map_err(|e| {
+        Error::Signer(format!(
+            "Couldn't connect to the remote signer at {}: {e}",
+            path.display()
+        ))
+    })?;
+    let mut request = serde_json::to_vec(body).map_err(std::io::Error::from)?;
+    request.push(b'\n');
+    stream.write_all(&request).await?;
+
+    let mut line = String::new();
+    BufReader::new(stream).read_line(&mut line).await?;
+
+    serde_json::from_str(&line)
+        .map_err(|e| Error::Signer(format!("Invalid remote signer response: {e}")))
+}
+
+#[cfg(not(unix))]
+async fn unix_request(_: &std::path::Path, _: &Value) -> Result<RpcResponse, Error> {
+    Err(Error::Signer(
+        "Unix sockets are not supported on this platform".to_string(),
+    ))
+}
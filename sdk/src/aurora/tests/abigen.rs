@@ -0,0 +1,174 @@
+use aurora_engine_types::parameters::engine::ResultLog;
+use aurora_engine_types::types::Address;
+
+use crate::abigen;
+use crate::aurora::abi::ethabi::{self, Int, Token, Uint};
+use crate::aurora::abi::{from_abi_address, to_abi_address};
+
+abigen!(TestToken, "src/aurora/tests/abi/token.json");
+
+use test_token::{events, functions};
+
+fn address(byte: u8) -> Address {
+    Address::from_array([byte; 20])
+}
+
+fn topic(address: Address) -> [u8; 32] {
+    let mut topic = [0; 32];
+    topic[12..].copy_from_slice(address.as_bytes());
+    topic
+}
+
+#[test]
+fn test_function_selectors() {
+    assert_eq!(hex::encode(functions::BalanceOf::SELECTOR), "70a08231");
+    assert_eq!(hex::encode(functions::Transfer::SELECTOR), "a9059cbb");
+    assert_eq!(hex::encode(functions::Deposit::SELECTOR), "d0e30db0");
+    // Overloaded functions are sorted by their order in the ABI.
+    assert_eq!(
+        hex::encode(functions::SafeTransferFrom::SELECTOR),
+        "42842e0e"
+    );
+    assert_eq!(
+        hex::encode(functions::SafeTransferFrom1::SELECTOR),
+        "b88d4fde"
+    );
+}
+
+#[test]
+fn test_encode_input() {
+    let input = functions::Transfer::encode_input(to_abi_address(address(1)), Uint::from(1000));
+
+    assert_eq!(
+        hex::encode(input),
+        "a9059cbb\
+         0000000000000000000000000101010101010101010101010101010101010101\
+         00000000000000000000000000000000000000000000000000000000000003e8"
+    );
+}
+
+#[test]
+fn test_encode_constructor() {
+    let code = [0x60, 0x80];
+    let input = test_token::encode_constructor(&code, "Token".to_string(), "TKN".to_string());
+    let args = ethabi::encode(&[
+        Token::String("Token".to_string()),
+        Token::String("TKN".to_string()),
+    ]);
+
+    assert_eq!(input[..2], code);
+    assert_eq!(input[2..], args);
+}
+
+#[test]
+fn test_decode_output() {
+    let output = ethabi::encode(&[Token::Uint(Uint::from(42))]);
+    assert_eq!(
+        functions::BalanceOf::decode_output(&output).unwrap(),
+        Uint::from(42)
+    );
+
+    let output = ethabi::encode(&[
+        Token::Tuple(vec![
+            Token::Address(to_abi_address(address(2))),
+            Token::Array(vec![Token::Int(Int::from(1)), Token::Int(Int::MAX)]),
+            Token::String("memo".to_string()),
+        ]),
+        Token::Bool(true),
+    ]);
+    let ((maker, amounts, memo), active) = functions::GetOrder::decode_output(&output).unwrap();
+
+    assert_eq!(from_abi_address(maker), address(2));
+    assert_eq!(amounts, vec![Int::from(1), Int::MAX]);
+    assert_eq!(memo, "memo");
+    assert!(active);
+
+    assert!(functions::GetOrder::decode_output(&output[..64]).is_err());
+}
+
+#[test]
+fn test_encode_complex_input() {
+    let input =
+        functions::GetOrder::encode_input(vec![Uint::from(1), Uint::from(2)], [[1; 32], [2; 32]]);
+    let args = ethabi::encode(&[
+        Token::Array(vec![Token::Uint(Uint::from(1)), Token::Uint(Uint::from(2))]),
+        Token::FixedArray(vec![
+            Token::FixedBytes(vec![1; 32]),
+            Token::FixedBytes(vec![2; 32]),
+        ]),
+    ]);
+
+    assert_eq!(input[..4], functions::GetOrder::SELECTOR);
+    assert_eq!(input[4..], args);
+}
+
+#[test]
+fn test_decode_events() {
+    let transfer = ResultLog {
+        address: address(0),
+        topics: vec![
+            events::Transfer::SIGNATURE,
+            topic(address(1)),
+            topic(address(2)),
+        ],
+        data: ethabi::encode(&[Token::Uint(Uint::from(1000))]),
+    };
+    let memo = ResultLog {
+        address: address(0),
+        topics: vec![
+            events::Memo::SIGNATURE,
+            aurora_engine_sdk::keccak(b"topic").0,
+        ],
+        data: ethabi::encode(&[Token::String("text".to_string())]),
+    };
+
+    assert_eq!(
+        hex::encode(events::Transfer::SIGNATURE),
+        "ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+    );
+    assert_eq!(
+        events::Transfer::decode_log(&transfer).unwrap(),
+        events::Transfer {
+            from: to_abi_address(address(1)),
+            to: to_abi_address(address(2)),
+            value: Uint::from(1000),
+        }
+    );
+    // Indexed parameters of dynamic types are decoded as hashes.
+    assert_eq!(
+        events::Memo::decode_log(&memo).unwrap(),
+        events::Memo {
+            topic: aurora_engine_sdk::keccak(b"topic").0,
+            text: "text".to_string(),
+        }
+    );
+    assert!(events::Transfer::decode_log(&memo).is_err());
+    assert_eq!(
+        events::Transfer::filter(&[memo, transfer.clone(), transfer]).len(),
+        2
+    );
+}
+
+#[test]
+fn test_overloads_and_reserved_names() {
+    let memo = ResultLog {
+        address: address(0),
+        topics: vec![events::Memo1::SIGNATURE],
+        data: ethabi::encode(&[Token::String("text".to_string())]),
+    };
+
+    // Overloaded events get a numeric suffix like the functions.
+    assert_ne!(events::Memo::SIGNATURE, events::Memo1::SIGNATURE);
+    assert_eq!(
+        events::Memo1::decode_log(&memo).unwrap(),
+        events::Memo1 {
+            text: "text".to_string(),
+        }
+    );
+    // The function `address` doesn't clash with the method returning the contract address.
+    let _ = TestToken::address_;
+    assert_eq!(
+        functions::Address::SELECTOR,
+        aurora_engine_sdk::keccak(b"address()").0[..4]
+    );
+}
@@ -0,0 +1,333 @@
+use aurora_engine_types::parameters::connector::{
+    Erc20Identifier, Erc20Metadata, FtTransferArgs, StorageDepositArgs,
+};
+use aurora_engine_types::parameters::engine::{
+    CallArgs, FunctionCallArgsV2, GetStorageAtArgs, PausePrecompilesCallArgs, RelayerKeyArgs,
+    SetOwnerArgs, SubmitResult, TransactionStatus,
+};
+use aurora_engine_types::parameters::silo::{
+    SiloParamsArgs, WhitelistKind, WhitelistKindArgs, WhitelistStatusArgs,
+};
+use aurora_engine_types::parameters::xcc::FundXccArgs;
+use aurora_engine_types::types::{Address, EthGas, NEP141Wei};
+use aurora_engine_types::{H256, U256};
+use near_primitives::types::AccountId;
+use near_sdk::json_types::U128;
+
+use crate::aurora::contract::{read, write};
+use crate::aurora::{self, ContractMethod, MethodType};
+
+fn address(byte: u8) -> Address {
+    Address::from_array([byte; 20])
+}
+
+#[test]
+fn test_getters_are_view_methods() {
+    assert!(matches!(read::GetVersion::method_type(), MethodType::View));
+    assert!(matches!(read::GetBalance::method_type(), MethodType::View));
+    assert!(matches!(
+        read::GetStorageAt::method_type(),
+        MethodType::View
+    ));
+    assert!(matches!(
+        read::GetErc20Metadata::method_type(),
+        MethodType::View
+    ));
+    assert!(matches!(write::SetOwner::method_type(), MethodType::Call));
+    assert!(matches!(write::Call::method_type(), MethodType::Call));
+}
+
+#[test]
+fn test_method_names() {
+    assert_eq!(read::GetChainId.method_name(), "get_chain_id");
+    assert_eq!(read::GetUpgradeIndex.method_name(), "get_upgrade_index");
+    assert_eq!(read::GetPausedFlags.method_name(), "get_paused_flags");
+    assert_eq!(
+        read::FactoryGetWnearAddress.method_name(),
+        "factory_get_wnear_address"
+    );
+    assert_eq!(write::PauseContract.method_name(), "pause_contract");
+    assert_eq!(write::DeployUpgrade.method_name(), "deploy_upgrade");
+    assert_eq!(
+        write::DeployCode { code: vec![] }.method_name(),
+        "deploy_code"
+    );
+}
+
+#[test]
+fn test_raw_params() -> anyhow::Result<()> {
+    let addr = address(0x11);
+
+    assert_eq!(
+        read::GetNonce { address: addr }.params()?,
+        addr.as_bytes().to_vec()
+    );
+    assert_eq!(
+        read::GetCode { address: addr }.params()?,
+        addr.as_bytes().to_vec()
+    );
+    assert_eq!(
+        write::RegisterRelayer { address: addr }.params()?,
+        addr.as_bytes().to_vec()
+    );
+    assert_eq!(
+        read::GetBlockHash { height: 0x0102 }.params()?,
+        vec![2, 1, 0, 0, 0, 0, 0, 0]
+    );
+    assert_eq!(
+        write::StageUpgrade { code: vec![0, 97] }.params()?,
+        vec![0, 97]
+    );
+    assert!(write::ResumeContract.params()?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_borsh_params() -> anyhow::Result<()> {
+    let args = SetOwnerArgs {
+        new_owner: aurora::AccountId::new("owner.near").unwrap(),
+    };
+    assert_eq!(
+        write::SetOwner { args: args.clone() }.params()?,
+        borsh::to_vec(&args)?
+    );
+
+    let args = PausePrecompilesCallArgs { paused_mask: 3 };
+    assert_eq!(write::PausePrecompiles { args }.params()?, vec![3, 0, 0, 0]);
+
+    let args = GetStorageAtArgs {
+        address: address(1),
+        key: [2; 32],
+    };
+    let params = read::GetStorageAt { args }.params()?;
+    assert_eq!(params.len(), 52);
+    assert_eq!(&params[..20], address(1).as_bytes());
+
+    let args = CallArgs::V2(FunctionCallArgsV2 {
+        contract: address(2),
+        value: [0; 32],
+        input: vec![1, 2, 3],
+    });
+    let params = write::Call { args: args.clone() }.params()?;
+    assert_eq!(CallArgs::deserialize(&params), Some(args));
+
+    let params = read::GetErc20FromNep141 {
+        nep141: "token.near".parse()?,
+    }
+    .params()?;
+    assert_eq!(params, borsh::to_vec("token.near")?);
+
+    let params = read::GetWhitelistStatus {
+        args: WhitelistKindArgs {
+            kind: WhitelistKind::EvmAdmin,
+        },
+    }
+    .params()?;
+    assert_eq!(params, vec![1]);
+
+    Ok(())
+}
+
+#[test]
+fn test_json_params() -> anyhow::Result<()> {
+    let public_key = "ed25519:DcA2MzgpJbrUATQLLceocVckhhAqrkingax4oJ9kZ847".parse()?;
+    let params = write::RemoveRelayerKey {
+        args: RelayerKeyArgs { public_key },
+    }
+    .params()?;
+    assert_eq!(
+        String::from_utf8(params)?,
+        r#"{"public_key":"ed25519:DcA2MzgpJbrUATQLLceocVckhhAqrkingax4oJ9kZ847"}"#
+    );
+
+    let params = read::GetErc20Metadata {
+        erc20_identifier: Erc20Identifier::Nep141 {
+            account_id: aurora::AccountId::new("token.near").unwrap(),
+        },
+    }
+    .params()?;
+    assert_eq!(String::from_utf8(params)?, r#"{"account_id":"token.near"}"#);
+
+    Ok(())
+}
+
+#[test]
+fn test_deposits() -> anyhow::Result<()> {
+    let public_key = "ed25519:DcA2MzgpJbrUATQLLceocVckhhAqrkingax4oJ9kZ847".parse()?;
+    let method = write::AddRelayerKey {
+        args: RelayerKeyArgs { public_key },
+        allowance: 100,
+    };
+    assert_eq!(method.deposit(), 100);
+
+    let method = write::FundXccSubAccount {
+        args: FundXccArgs {
+            target: address(3),
+            wnear_account_id: None,
+        },
+        deposit: 5,
+    };
+    assert_eq!(method.deposit(), 5);
+    assert_eq!(write::PauseContract.deposit(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_parse_numeric_responses() -> anyhow::Result<()> {
+    let mut bytes = [0; 32];
+    bytes[31] = 0x4e;
+    bytes[30] = 0x45;
+    assert_eq!(
+        read::GetChainId::parse_response(bytes.to_vec())?,
+        U256::from(0x454e)
+    );
+    assert!(read::GetBalance::parse_response(vec![1; 31]).is_err());
+
+    assert_eq!(
+        read::GetUpgradeIndex::parse_response(10u64.to_le_bytes().to_vec())?,
+        10
+    );
+    assert_eq!(
+        read::GetPausedPrecompiles::parse_response(7u32.to_le_bytes().to_vec())?,
+        7
+    );
+    assert_eq!(read::GetPausedFlags::parse_response(vec![1])?, 1);
+
+    Ok(())
+}
+
+#[test]
+fn test_parse_responses() -> anyhow::Result<()> {
+    assert_eq!(
+        read::GetStorageAt::parse_response(vec![5; 32])?,
+        H256::repeat_byte(5)
+    );
+    assert_eq!(
+        read::GetErc20FromNep141::parse_response(address(7).as_bytes().to_vec())?,
+        address(7)
+    );
+    assert_eq!(
+        read::GetNep141FromErc20::parse_response(b"token.near".to_vec())?,
+        "token.near".parse::<AccountId>()?
+    );
+    assert_eq!(
+        read::GetOwner::parse_response(b"aurora".to_vec())?,
+        "aurora".parse::<AccountId>()?
+    );
+    assert_eq!(
+        read::GetVersion::parse_response(b"3.9.0\n".to_vec())?,
+        "3.9.0\n"
+    );
+
+    let metadata = read::GetErc20Metadata::parse_response(
+        br#"{"name":"Token","symbol":"TKN","decimals":18}"#.to_vec(),
+    )?;
+    assert_eq!(
+        metadata,
+        Erc20Metadata {
+            name: "Token".to_string(),
+            symbol: "TKN".to_string(),
+            decimals: 18,
+        }
+    );
+
+    let status = WhitelistStatusArgs {
+        kind: WhitelistKind::Admin,
+        active: true,
+    };
+    assert_eq!(
+        read::GetWhitelistStatus::parse_response(borsh::to_vec(&status)?)?,
+        status
+    );
+
+    let params = Some(SiloParamsArgs {
+        fixed_gas: EthGas::new(1000),
+        erc20_fallback_address: address(9),
+    });
+    assert_eq!(
+        read::GetSiloParams::parse_response(borsh::to_vec(&params)?)?,
+        params
+    );
+
+    let result = SubmitResult::new(TransactionStatus::Succeed(vec![1]), 21000, vec![]);
+    let parsed = write::DeployCode::parse_response(borsh::to_vec(&result)?)?;
+    assert_eq!(parsed.gas_used, 21000);
+    assert_eq!(parsed.status, TransactionStatus::Succeed(vec![1]));
+
+    Ok(())
+}
+
+#[test]
+fn test_nep141_methods() -> anyhow::Result<()> {
+    let params = read::FtBalanceOf {
+        account_id: "alice.near".parse()?,
+    }
+    .params()?;
+    assert_eq!(String::from_utf8(params)?, r#"{"account_id":"alice.near"}"#);
+
+    let params = read::FtBalanceOfEth {
+        address: address(0xab),
+    }
+    .params()?;
+    assert_eq!(
+        String::from_utf8(params)?,
+        format!(r#"{{"address":"{}"}}"#, "ab".repeat(20))
+    );
+
+    let method = write::FtTransfer {
+        args: FtTransferArgs {
+            receiver_id: aurora::AccountId::new("bob.near").unwrap(),
+            amount: NEP141Wei::new(10),
+            memo: None,
+        },
+    };
+    assert_eq!(method.deposit(), 1);
+    assert_eq!(
+        String::from_utf8(method.params()?)?,
+        r#"{"receiver_id":"bob.near","amount":"10","memo":null}"#
+    );
+
+    let method = write::StorageDeposit {
+        args: StorageDepositArgs {
+            account_id: None,
+            registration_only: Some(true),
+        },
+        deposit: 1250,
+    };
+    assert_eq!(method.deposit(), 1250);
+    assert_eq!(write::StateMigration.deposit(), 0);
+
+    assert_eq!(
+        read::FtTotalSupply::parse_response(br#""1000""#.to_vec())?,
+        U128(1000)
+    );
+    let balance =
+        read::StorageBalanceOf::parse_response(br#"{"total":"1250","available":"0"}"#.to_vec())?;
+    assert_eq!(balance.total.as_u128(), 1250);
+    assert!(write::StorageUnregister::parse_response(b"true".to_vec())?);
+    assert!(read::IsUsedProof::parse_response(vec![1])?);
+    assert_eq!(
+        read::GetAccountsCounter::parse_response(3u64.to_le_bytes().to_vec())?,
+        3
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_mint_account_params() -> anyhow::Result<()> {
+    let params = write::MintAccount {
+        address: address(2),
+        nonce: 1,
+        balance: 5,
+    }
+    .params()?;
+
+    assert_eq!(&params[..20], address(2).as_bytes());
+    assert_eq!(&params[20..28], 1u64.to_le_bytes());
+    assert_eq!(&params[28..], 5u64.to_le_bytes());
+
+    Ok(())
+}
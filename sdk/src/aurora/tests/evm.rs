@@ -0,0 +1,222 @@
+use aurora_engine_transactions::{EthTransactionKind, NormalizedEthTransaction};
+use aurora_engine_types::parameters::engine::{
+    GetStorageAtArgs, ResultLog, SubmitResult, TransactionStatus, ViewCallArgs,
+};
+use aurora_engine_types::types::{Address, Wei};
+use aurora_engine_types::{H256, U256};
+use aurora_mock_rpc::MockRpc;
+use near_crypto::{InMemorySigner, KeyType};
+use near_primitives::views::FinalExecutionStatus;
+
+use crate::aurora::client::Client;
+use crate::aurora::error::Error;
+use crate::aurora::evm::{EvmClient, EvmOutcome};
+use crate::aurora::signer::LocalSigner;
+
+const CHAIN_ID: u64 = 1_313_161_556;
+
+fn client(rpc: &MockRpc) -> Client {
+    let signer =
+        InMemorySigner::from_seed("owner.near".parse().unwrap(), KeyType::ED25519, "owner");
+    Client::new(crate::near::client::Client::new(rpc.url(), None, signer).unwrap())
+}
+
+fn evm(client: &Client) -> EvmClient<'_> {
+    let signer = LocalSigner::from_hex(&"01".repeat(32)).unwrap();
+    EvmClient::new(client, "aurora".parse().unwrap(), signer)
+}
+
+/// Mock the reads made before signing and the outcome of the `submit` transaction.
+fn on_submit(rpc: &MockRpc, nonce: u64, result: &SubmitResult) {
+    rpc.on_call_function("get_nonce", U256::from(nonce).to_big_endian().to_vec());
+    rpc.on_call_function(
+        "get_chain_id",
+        U256::from(CHAIN_ID).to_big_endian().to_vec(),
+    );
+    rpc.on_transaction(
+        "submit",
+        FinalExecutionStatus::SuccessValue(borsh::to_vec(result).unwrap()),
+    );
+}
+
+/// Decode the EVM transaction sent to the `submit` method.
+fn submitted_transaction(rpc: &MockRpc) -> NormalizedEthTransaction {
+    let calls = rpc.function_calls();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].method_name, "submit");
+
+    EthTransactionKind::try_from(calls[0].args.as_slice())
+        .and_then(NormalizedEthTransaction::try_from)
+        .unwrap()
+}
+
+#[test]
+fn test_outcome_from_submit_result() {
+    let log = ResultLog {
+        address: Address::from_array([1; 20]),
+        topics: vec![[2; 32]],
+        data: vec![3],
+    };
+    let result = SubmitResult::new(
+        TransactionStatus::Succeed(vec![4]),
+        21000,
+        vec![log.clone()],
+    );
+    let outcome = EvmOutcome::from(result);
+
+    assert!(outcome.is_success());
+    assert_eq!(outcome.gas_used, 21000);
+    assert_eq!(outcome.logs, vec![log]);
+    assert_eq!(outcome.output(), Some([4].as_slice()));
+    assert_eq!(outcome.into_output().unwrap(), vec![4]);
+}
+
+#[test]
+fn test_outcome_contract_address() {
+    let address = Address::from_array([5; 20]);
+    let outcome = EvmOutcome::from(SubmitResult::new(
+        TransactionStatus::Succeed(address.as_bytes().to_vec()),
+        100_000,
+        vec![],
+    ));
+
+    assert_eq!(outcome.contract_address().unwrap(), address);
+}
+
+#[test]
+fn test_failed_outcome() {
+    let outcome = EvmOutcome::from(SubmitResult::new(
+        TransactionStatus::Revert(vec![1, 2]),
+        30000,
+        vec![],
+    ));
+
+    assert!(!outcome.is_success());
+    assert_eq!(outcome.output(), None);
+    assert!(matches!(
+        outcome.contract_address(),
+        Err(Error::TransactionStatus(TransactionStatus::Revert(_)))
+    ));
+    assert!(matches!(
+        outcome.into_output(),
+        Err(Error::TransactionStatus(TransactionStatus::Revert(_)))
+    ));
+}
+
+#[tokio::test]
+async fn test_deploy() {
+    let rpc = MockRpc::start().await;
+    let contract = Address::from_array([7; 20]);
+    on_submit(
+        &rpc,
+        3,
+        &SubmitResult::new(
+            TransactionStatus::Succeed(contract.as_bytes().to_vec()),
+            50_000,
+            vec![],
+        ),
+    );
+    let client = client(&rpc);
+    let evm = evm(&client);
+
+    let outcome = evm.deploy(vec![0x60, 0x01], Wei::zero()).await.unwrap();
+
+    assert_eq!(outcome.contract_address().unwrap(), contract);
+    assert_eq!(outcome.gas_used, 50_000);
+    assert_eq!(rpc.view_calls()[0].args, evm.address().as_bytes());
+
+    let tx = submitted_transaction(&rpc);
+    assert_eq!(tx.address, evm.address());
+    assert_eq!(tx.chain_id, Some(CHAIN_ID));
+    assert_eq!(tx.nonce, U256::from(3));
+    assert_eq!(tx.to, None);
+    assert_eq!(tx.data, [0x60, 0x01]);
+}
+
+#[tokio::test]
+async fn test_call() {
+    let rpc = MockRpc::start().await;
+    on_submit(
+        &rpc,
+        0,
+        &SubmitResult::new(TransactionStatus::Revert(vec![1]), 30_000, vec![]),
+    );
+    let client = client(&rpc);
+    let evm = evm(&client);
+    let target = Address::from_array([8; 20]);
+
+    let outcome = evm
+        .call(target, Wei::new_u64(5), vec![0xaa, 0xbb])
+        .await
+        .unwrap();
+
+    assert_eq!(outcome.status, TransactionStatus::Revert(vec![1]));
+    assert_eq!(outcome.gas_used, 30_000);
+
+    let tx = submitted_transaction(&rpc);
+    assert_eq!(tx.to, Some(target));
+    assert_eq!(tx.value, Wei::new_u64(5));
+    assert_eq!(tx.data, [0xaa, 0xbb]);
+}
+
+#[tokio::test]
+async fn test_view() {
+    let rpc = MockRpc::start().await;
+    let status = TransactionStatus::Succeed(vec![0x2a]);
+    rpc.on_call_function("view", borsh::to_vec(&status).unwrap());
+    let client = client(&rpc);
+    let evm = evm(&client);
+    let target = Address::from_array([9; 20]);
+
+    let result = evm.view(target, Wei::new_u64(1), vec![0xcc]).await.unwrap();
+
+    assert_eq!(result, status);
+    assert_eq!(
+        rpc.view_calls()[0].args_borsh::<ViewCallArgs>().unwrap(),
+        ViewCallArgs {
+            sender: evm.address(),
+            address: target,
+            amount: Wei::new_u64(1).to_bytes(),
+            input: vec![0xcc],
+        }
+    );
+    assert!(rpc.transactions().is_empty());
+}
+
+#[tokio::test]
+async fn test_account_state() {
+    let rpc = MockRpc::start().await;
+    rpc.on_call_function("get_nonce", U256::from(4).to_big_endian().to_vec());
+    rpc.on_call_function("get_balance", U256::from(1000).to_big_endian().to_vec());
+    rpc.on_call_function("get_code", vec![0x60, 0x80]);
+    rpc.on_call_function("get_storage_at", vec![3; 32]);
+    let client = client(&rpc);
+    let evm = evm(&client);
+    let address = Address::from_array([10; 20]);
+    let key = H256::repeat_byte(1);
+
+    assert_eq!(evm.nonce(address).await.unwrap(), U256::from(4));
+    assert_eq!(evm.balance(address).await.unwrap(), Wei::new_u64(1000));
+    assert_eq!(evm.code(address).await.unwrap(), [0x60, 0x80]);
+    assert_eq!(
+        evm.storage_at(address, key).await.unwrap(),
+        H256::repeat_byte(3)
+    );
+
+    let calls = rpc.view_calls();
+    assert_eq!(
+        calls
+            .iter()
+            .map(|call| call.method_name.as_str())
+            .collect::<Vec<_>>(),
+        ["get_nonce", "get_balance", "get_code", "get_storage_at"]
+    );
+    assert!(
+        calls[..3]
+            .iter()
+            .all(|call| call.args == address.as_bytes())
+    );
+    let args = calls[3].args_borsh::<GetStorageAtArgs>().unwrap();
+    assert_eq!(args.address, address);
+    assert_eq!(args.key, key.0);
+}
@@ -1 +1,5 @@
+mod abigen;
+mod contract_methods;
+mod evm;
 mod parse_errors;
+mod signer;
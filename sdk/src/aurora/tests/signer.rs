@@ -0,0 +1,268 @@
+use aes::cipher::{KeyIvInit, StreamCipher};
+use aurora_engine_transactions::legacy::TransactionLegacy;
+use aurora_engine_types::U256;
+use aurora_engine_types::types::{Address, Wei};
+use serde_json::{Value, json};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+
+use crate::aurora::error::Error;
+use crate::aurora::signer::{EvmSigner, LocalSigner, RemoteSigner, keystore};
+use crate::aurora::transaction::{self, SecretKey};
+
+// The test vectors from the Web3 Secret Storage Definition.
+const TEST_PASSWORD: &str = "testpassword";
+const TEST_SECRET_KEY: &str = "7a28b5ba57c53603b0b07b56bba752f7784bf506fa95edc395f5cf6c7514fe9d";
+const PBKDF2_KEYSTORE: &str = r#"{
+    "crypto" : {
+        "cipher" : "aes-128-ctr",
+        "cipherparams" : {
+            "iv" : "6087dab2f9fdbbfaddc31a909735c1e6"
+        },
+        "ciphertext" : "5318b4d5bcd28de64ee5559e671353e16f075ecae9f99c7a79a38af5f869aa46",
+        "kdf" : "pbkdf2",
+        "kdfparams" : {
+            "c" : 262144,
+            "dklen" : 32,
+            "prf" : "hmac-sha256",
+            "salt" : "ae3cd4e7013836a3df6bd7241b12db061dbe2c6785853cce422d148a624ce0bd"
+        },
+        "mac" : "517ead924a9d0dc3124507e3393d175ce3ff7c1e96529c6c555ce9e51205e9b2"
+    },
+    "id" : "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+    "version" : 3
+}"#;
+const CHAIN_ID: u64 = 1_313_161_556;
+
+fn secret_key(byte: u8) -> SecretKey {
+    SecretKey::parse(&[byte; 32]).unwrap()
+}
+
+fn transaction() -> TransactionLegacy {
+    TransactionLegacy {
+        nonce: U256::from(7),
+        gas_price: U256::zero(),
+        gas_limit: U256::from(u64::MAX),
+        to: Some(Address::from_array([0x11; 20])),
+        value: Wei::new_u64(1000),
+        data: vec![1, 2, 3],
+    }
+}
+
+/// Create a keystore with cheap scrypt parameters.
+fn scrypt_keystore(sk: &SecretKey, password: &str) -> String {
+    let salt = [3; 32];
+    let iv = [4; 16];
+    let params = scrypt::Params::new(4, 8, 1, 32).unwrap();
+    let mut key = [0; 32];
+    scrypt::scrypt(password.as_bytes(), &salt, &params, &mut key).unwrap();
+
+    let mut ciphertext = sk.serialize().to_vec();
+    ctr::Ctr128BE::<aes::Aes128>::new_from_slices(&key[..16], &iv)
+        .unwrap()
+        .apply_keystream(&mut ciphertext);
+    let mac = aurora_engine_sdk::keccak(&[&key[16..], &ciphertext].concat());
+
+    json!({
+        "crypto": {
+            "cipher": "aes-128-ctr",
+            "cipherparams": { "iv": hex::encode(iv) },
+            "ciphertext": hex::encode(ciphertext),
+            "kdf": "scrypt",
+            "kdfparams": { "dklen": 32, "n": 16, "r": 8, "p": 1, "salt": hex::encode(salt) },
+            "mac": hex::encode(mac),
+        },
+        "version": 3
+    })
+    .to_string()
+}
+
+#[tokio::test]
+async fn test_local_signer() {
+    let signer = LocalSigner::from_hex(&format!("0x{TEST_SECRET_KEY}")).unwrap();
+    let signed_tx = signer
+        .sign_transaction(transaction(), CHAIN_ID)
+        .await
+        .unwrap();
+
+    assert_eq!(signed_tx.sender().unwrap(), signer.address());
+    assert_eq!(signed_tx.chain_id(), Some(CHAIN_ID));
+    assert!(matches!(
+        LocalSigner::from_hex("0x1234"),
+        Err(Error::Signer(_))
+    ));
+}
+
+#[test]
+fn test_decrypt_pbkdf2_keystore() {
+    let sk = keystore::decrypt(PBKDF2_KEYSTORE, TEST_PASSWORD).unwrap();
+    assert_eq!(hex::encode(sk.serialize()), TEST_SECRET_KEY);
+}
+
+#[test]
+fn test_decrypt_scrypt_keystore() {
+    let expected = secret_key(5);
+    let json = scrypt_keystore(&expected, TEST_PASSWORD);
+
+    let sk = keystore::decrypt(&json, TEST_PASSWORD).unwrap();
+    assert_eq!(sk.serialize(), expected.serialize());
+
+    let error = keystore::decrypt(&json, "wrong password").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Signer error: Wrong keystore password or corrupted keystore"
+    );
+}
+
+#[test]
+fn test_local_signer_from_keystore_file() {
+    let sk = secret_key(6);
+    let path = std::env::temp_dir().join(format!("keystore-{}.json", std::process::id()));
+    std::fs::write(&path, scrypt_keystore(&sk, TEST_PASSWORD)).unwrap();
+
+    let signer = LocalSigner::from_keystore(&path, TEST_PASSWORD);
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(
+        signer.unwrap().address(),
+        transaction::address_from_secret_key(&sk)
+    );
+}
+
+fn quantity(value: &Value) -> U256 {
+    U256::from_str_radix(value.as_str().unwrap().trim_start_matches("0x"), 16).unwrap()
+}
+
+/// Handle the `eth_signTransaction` request the same way as `Web3Signer` does.
+fn sign_request(request: &[u8], sk: &SecretKey) -> Vec<u8> {
+    let request: Value = serde_json::from_slice(request).unwrap();
+    assert_eq!(request["method"], "eth_signTransaction");
+    let params = &request["params"][0];
+    let to = params["to"]
+        .as_str()
+        .map(|to| Address::decode(to.trim_start_matches("0x")).unwrap());
+    let tx = TransactionLegacy {
+        nonce: quantity(&params["nonce"]),
+        gas_price: quantity(&params["gasPrice"]),
+        gas_limit: quantity(&params["gas"]),
+        to,
+        value: Wei::new(quantity(&params["value"])),
+        data: hex::decode(params["data"].as_str().unwrap().trim_start_matches("0x")).unwrap(),
+    };
+    let chain_id = quantity(&params["chainId"]).low_u64();
+    let signed = transaction::sign_transaction(tx, chain_id, sk);
+
+    serde_json::to_vec(&json!({
+        "jsonrpc": "2.0",
+        "id": request["id"],
+        "result": format!("0x{}", hex::encode(rlp::encode(&signed))),
+    }))
+    .unwrap()
+}
+
+/// Stand-in for the signing service serving a single HTTP request.
+async fn serve_http(listener: tokio::net::TcpListener, sk: SecretKey) {
+    let (stream, _) = listener.accept().await.unwrap();
+    let mut reader = BufReader::new(stream);
+    let mut content_length = 0;
+
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).await.unwrap();
+        let line = line.trim_end();
+
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':')
+            && name.eq_ignore_ascii_case("content-length")
+        {
+            content_length = value.trim().parse().unwrap();
+        }
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).await.unwrap();
+    let response = sign_request(&body, &sk);
+    let head = format!(
+        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n",
+        response.len()
+    );
+    let stream = reader.get_mut();
+    stream.write_all(head.as_bytes()).await.unwrap();
+    stream.write_all(&response).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_remote_signer_http() {
+    let sk = secret_key(7);
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let server = tokio::spawn(serve_http(listener, sk));
+
+    let signer = RemoteSigner::new(&url, transaction::address_from_secret_key(&sk)).unwrap();
+    let signed_tx = signer
+        .sign_transaction(transaction(), CHAIN_ID)
+        .await
+        .unwrap();
+    server.await.unwrap();
+
+    assert_eq!(signed_tx.transaction, transaction());
+    assert_eq!(signed_tx.sender().unwrap(), signer.address());
+}
+
+#[tokio::test]
+async fn test_remote_signer_rejects_foreign_signature() {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let server = tokio::spawn(serve_http(listener, secret_key(8)));
+
+    let expected = transaction::address_from_secret_key(&secret_key(9));
+    let signer = RemoteSigner::new(&url, expected).unwrap();
+    let result = signer.sign_transaction(transaction(), CHAIN_ID).await;
+    server.await.unwrap();
+
+    assert!(matches!(result, Err(Error::Signer(_))));
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn test_remote_signer_unix_socket() {
+    let sk = secret_key(10);
+    let path = std::env::temp_dir().join(format!("signer-{}.sock", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let listener = tokio::net::UnixListener::bind(&path).unwrap();
+    let server = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut reader = BufReader::new(stream);
+        let mut request = String::new();
+        reader.read_line(&mut request).await.unwrap();
+        let mut response = sign_request(request.as_bytes(), &sk);
+        response.push(b'\n');
+        reader.get_mut().write_all(&response).await.unwrap();
+    });
+
+    let url = format!("unix://{}", path.display());
+    let signer = RemoteSigner::new(&url, transaction::address_from_secret_key(&sk)).unwrap();
+    let tx = TransactionLegacy {
+        to: None,
+        ..transaction()
+    };
+    let signed_tx = signer.sign_transaction(tx.clone(), CHAIN_ID).await.unwrap();
+    server.await.unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(signed_tx.transaction, tx);
+    assert_eq!(signed_tx.sender().unwrap(), signer.address());
+}
+
+#[test]
+fn test_remote_signer_url() {
+    let address = Address::zero();
+
+    assert!(RemoteSigner::new("https://signer.example", address).is_ok());
+    assert!(RemoteSigner::new("unix:///run/signer.sock", address).is_ok());
+    assert!(matches!(
+        RemoteSigner::new("ftp://signer.example", address),
+        Err(Error::Signer(_))
+    ));
+}
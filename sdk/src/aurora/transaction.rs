@@ -0,0 +1,39 @@
+use aurora_engine_transactions::legacy::{LegacyEthSignedTransaction, TransactionLegacy};
+use aurora_engine_types::U256;
+use aurora_engine_types::types::Address;
+pub use libsecp256k1::SecretKey;
+use libsecp256k1::{Message, PublicKey};
+use rlp::RlpStream;
+
+/// Return the EVM address corresponding to the secret key.
+#[must_use]
+pub fn address_from_secret_key(sk: &SecretKey) -> Address {
+    let pk = PublicKey::from_secret_key(sk);
+    let hash = aurora_engine_sdk::keccak(&pk.serialize()[1..]);
+    Address::try_from_slice(&hash[12..]).expect("the slice is 20 bytes long")
+}
+
+/// Sign the legacy transaction according to EIP-155.
+#[must_use]
+pub fn sign_transaction(
+    tx: TransactionLegacy,
+    chain_id: u64,
+    secret_key: &SecretKey,
+) -> LegacyEthSignedTransaction {
+    let mut rlp_stream = RlpStream::new();
+    tx.rlp_append_unsigned(&mut rlp_stream, Some(chain_id));
+    let message_hash = aurora_engine_sdk::keccak(rlp_stream.as_raw());
+    let message = Message::parse(&message_hash.0);
+
+    let (signature, recovery_id) = libsecp256k1::sign(&message, secret_key);
+    let v = u64::from(recovery_id.serialize()) + 2 * chain_id + 35;
+    let r = U256::from_big_endian(&signature.r.b32());
+    let s = U256::from_big_endian(&signature.s.b32());
+
+    LegacyEthSignedTransaction {
+        transaction: tx,
+        v,
+        r,
+        s,
+    }
+}
@@ -2,3 +2,4 @@ pub mod aurora;
 pub mod near;
 
 use aurora_sdk_macros::ContractMethod;
+pub use aurora_sdk_macros::abigen;
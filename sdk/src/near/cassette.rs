@@ -0,0 +1,285 @@
+//! Record and replay of the NEAR JSON-RPC traffic.
+//!
+//! A cassette is a directory with a JSON file per interaction holding the RPC method, its params
+//! and the raw response of the server. In the record mode the requests are sent to the RPC
+//! endpoint and every response is written to the directory. In the replay mode the responses are
+//! read from the directory, matched by the method and params, and no network access happens.
+//!
+//! Identical requests are answered in the order they were recorded, and the last response is
+//! repeated once all of them are consumed, e.g. when polling for a transaction status.
+use std::collections::HashMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use near_jsonrpc_client::errors::{
+    JsonRpcError, JsonRpcServerError, JsonRpcServerResponseStatusError,
+    JsonRpcTransportHandlerResponseError, JsonRpcTransportRecvError, JsonRpcTransportSendError,
+    RpcTransportError,
+};
+use near_jsonrpc_client::{JsonRpcClient, MethodCallResult, methods};
+use near_jsonrpc_primitives::message::{self, Message};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The way a [`Cassette`] handles the requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Send the requests to the RPC endpoint and persist the responses.
+    Record,
+    /// Answer the requests with the persisted responses.
+    Replay,
+}
+
+/// A single request with the response to it, stored in its own file.
+#[derive(Debug, Serialize, Deserialize)]
+struct Interaction {
+    method: String,
+    params: Value,
+    response: Value,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// The number of interactions in the directory, used to name the next recorded one.
+    recorded: usize,
+    /// The responses by the method and params with the index of the next one to replay.
+    responses: HashMap<(String, String), (Vec<Value>, usize)>,
+}
+
+/// Records the JSON-RPC interactions into a directory or replays them from it.
+#[derive(Debug)]
+pub struct Cassette {
+    dir: PathBuf,
+    mode: Mode,
+    http: reqwest::Client,
+    state: Mutex<State>,
+}
+
+impl Cassette {
+    /// Creates a cassette recording the interactions into the directory. The directory is
+    /// created if it doesn't exist, and new interactions are appended to the existing ones.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the directory can't be created or read.
+    pub fn record<P: AsRef<Path>>(dir: P) -> io::Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        std::fs::create_dir_all(&dir)?;
+        let state = State {
+            recorded: interaction_files(&dir)?.len(),
+            ..State::default()
+        };
+
+        Ok(Self::new(dir, Mode::Record, state))
+    }
+
+    /// Creates a cassette replaying the interactions recorded in the directory.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the directory can't be read or contains a malformed interaction.
+    pub fn replay<P: AsRef<Path>>(dir: P) -> io::Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        let mut state = State::default();
+
+        for path in interaction_files(&dir)? {
+            let interaction: Interaction =
+                serde_json::from_slice(&std::fs::read(&path)?).map_err(|e| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("{}: {e}", path.display()),
+                    )
+                })?;
+            state
+                .responses
+                .entry((interaction.method, interaction.params.to_string()))
+                .or_default()
+                .0
+                .push(interaction.response);
+        }
+
+        Ok(Self::new(dir, Mode::Replay, state))
+    }
+
+    fn new(dir: PathBuf, mode: Mode, state: State) -> Self {
+        Self {
+            dir,
+            mode,
+            http: reqwest::Client::new(),
+            state: Mutex::new(state),
+        }
+    }
+
+    /// Returns the mode of the cassette.
+    #[must_use]
+    pub const fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Returns the directory of the cassette.
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Calls the RPC method in place of `client.call(method)`, recording or replaying
+    /// the response depending on the mode.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `JsonRpcClient::call`, and a transport error if the cassette
+    /// can't be written or has no response to the request.
+    pub async fn call<M: methods::RpcMethod>(
+        &self,
+        client: &JsonRpcClient,
+        method: M,
+    ) -> MethodCallResult<M::Response, M::Error> {
+        let request = methods::to_json(&method).map_err(send_error)?;
+        let method_name = method.method_name().to_string();
+        let params = request["params"].clone();
+
+        let response = match self.mode {
+            Mode::Record => {
+                let response = self.send(client, &request).await?;
+                self.write(&Interaction {
+                    method: method_name,
+                    params,
+                    response: response.clone(),
+                })
+                .map_err(send_error)?;
+                response
+            }
+            Mode::Replay => self.next_response(&method_name, &params)?,
+        };
+
+        parse_response::<M>(response)
+    }
+
+    async fn send<E>(
+        &self,
+        client: &JsonRpcClient,
+        request: &Value,
+    ) -> Result<Value, JsonRpcError<E>> {
+        let response = self
+            .http
+            .post(client.server_addr())
+            .headers(client.headers().clone())
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| {
+                JsonRpcError::TransportError(RpcTransportError::SendError(
+                    JsonRpcTransportSendError::PayloadSendError(e),
+                ))
+            })?;
+
+        if !response.status().is_success() {
+            return Err(JsonRpcError::ServerError(
+                JsonRpcServerError::ResponseStatusError(
+                    JsonRpcServerResponseStatusError::Unexpected {
+                        status: response.status(),
+                    },
+                ),
+            ));
+        }
+
+        let payload = response.bytes().await.map_err(|e| {
+            JsonRpcError::TransportError(RpcTransportError::RecvError(
+                JsonRpcTransportRecvError::PayloadRecvError(e),
+            ))
+        })?;
+
+        serde_json::from_slice(&payload).map_err(|e| {
+            JsonRpcError::TransportError(RpcTransportError::RecvError(
+                JsonRpcTransportRecvError::PayloadParseError(message::Broken::SyntaxError(
+                    e.to_string(),
+                )),
+            ))
+        })
+    }
+
+    fn write(&self, interaction: &Interaction) -> io::Result<()> {
+        let mut state = self.state.lock().map_err(|_| poisoned())?;
+        let path = self
+            .dir
+            .join(format!("{:06}-{}.json", state.recorded, interaction.method));
+
+        std::fs::write(path, serde_json::to_vec_pretty(interaction)?)?;
+        state.recorded += 1;
+        drop(state);
+
+        Ok(())
+    }
+
+    fn next_response<E>(&self, method: &str, params: &Value) -> Result<Value, JsonRpcError<E>> {
+        let mut state = self.state.lock().map_err(|_| send_error(poisoned()))?;
+        let (responses, next) = state
+            .responses
+            .get_mut(&(method.to_string(), params.to_string()))
+            .ok_or_else(|| {
+                send_error(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!(
+                        "no response to `{method}` with params {params} in {}",
+                        self.dir.display()
+                    ),
+                ))
+            })?;
+        let response = responses[(*next).min(responses.len() - 1)].clone();
+        *next += 1;
+        drop(state);
+
+        Ok(response)
+    }
+}
+
+/// Parses the raw JSON-RPC response the same way `JsonRpcClient::call` does.
+fn parse_response<M: methods::RpcMethod>(
+    response: Value,
+) -> MethodCallResult<M::Response, M::Error> {
+    let message = message::decoded_to_parsed(serde_json::from_value(response)).map_err(|e| {
+        JsonRpcError::TransportError(RpcTransportError::RecvError(
+            JsonRpcTransportRecvError::PayloadParseError(e),
+        ))
+    })?;
+    let Message::Response(response) = message else {
+        return Err(JsonRpcError::TransportError(RpcTransportError::RecvError(
+            JsonRpcTransportRecvError::UnexpectedServerResponse(message),
+        )));
+    };
+
+    M::parse_handler_response(response.result?)
+        .map_err(|e| {
+            JsonRpcError::TransportError(RpcTransportError::RecvError(
+                JsonRpcTransportRecvError::ResponseParseError(
+                    JsonRpcTransportHandlerResponseError::ResultParseError(e),
+                ),
+            ))
+        })?
+        .map_err(|e| JsonRpcError::ServerError(JsonRpcServerError::HandlerError(e)))
+}
+
+/// Returns the interaction files of the directory in the order they were recorded.
+fn interaction_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = std::fs::read_dir(dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .filter(|path| {
+            path.as_ref()
+                .map_or(true, |p| p.extension().is_some_and(|ext| ext == "json"))
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+    files.sort();
+
+    Ok(files)
+}
+
+const fn send_error<E>(error: io::Error) -> JsonRpcError<E> {
+    JsonRpcError::TransportError(RpcTransportError::SendError(
+        JsonRpcTransportSendError::PayloadSerializeError(error),
+    ))
+}
+
+fn poisoned() -> io::Error {
+    io::Error::other("cassette lock is poisoned")
+}
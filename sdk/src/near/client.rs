@@ -1,11 +1,15 @@
+use std::sync::Arc;
+
 use near_crypto::{PublicKey, Signer};
 use near_jsonrpc_client::AsUrl;
 use near_jsonrpc_client::methods::tx::RpcTransactionResponse;
+use near_primitives::action::delegate::SignedDelegateAction;
 use near_primitives::hash::CryptoHash;
 use near_primitives::types::AccountId;
-use near_primitives::views::TxExecutionStatus;
+use near_primitives::views::{FinalExecutionOutcomeView, TxExecutionStatus};
 
 use super::Result;
+use super::cassette::Cassette;
 use super::operations::{CallTransaction, Function, Transaction};
 use super::query::{Query, ViewAccessKey, ViewAccessKeyList, ViewAccount, ViewFunction};
 use super::rpc_client::RpcClient;
@@ -77,6 +81,23 @@ impl Client {
         Transaction::new(&self.client, self.signer.clone(), contract_id.to_owned())
     }
 
+    /// Submits the delegate action signed by another account, paying for the gas as a relayer.
+    ///
+    /// # Arguments
+    ///
+    /// * `signed` - The signed delegate action, e.g. created with `Transaction::sign_delegate`.
+    ///
+    /// # Returns
+    ///
+    /// The final outcome of the relayer's transaction.
+    pub async fn submit_delegate(
+        &self,
+        signed: SignedDelegateAction,
+    ) -> Result<FinalExecutionOutcomeView> {
+        let sender_id = signed.delegate_action.sender_id.clone();
+        self.batch(&sender_id).delegate(signed).transact().await
+    }
+
     /// Creates a query builder to view the list of access keys for a given account.
     ///
     /// # Arguments
@@ -175,6 +196,29 @@ impl Client {
             .await
     }
 
+    /// Returns a copy of the client which signs transactions with another `signer`.
+    ///
+    /// Useful to sign delegate actions with a cold key and relay them with a hot one.
+    #[must_use]
+    pub fn with_signer(&self, signer: Signer) -> Self {
+        Self {
+            client: self.client.clone(),
+            signer,
+        }
+    }
+
+    /// Returns the client which records the RPC traffic into the cassette or replays it from
+    /// the cassette without network access, depending on the cassette mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `cassette` - The cassette, which could be shared between several clients.
+    #[must_use]
+    pub fn with_cassette(mut self, cassette: Arc<Cassette>) -> Self {
+        self.client.set_cassette(cassette);
+        self
+    }
+
     pub const fn signer(&self) -> &Signer {
         &self.signer
     }
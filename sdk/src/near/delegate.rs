@@ -0,0 +1,155 @@
+//! Delegate (meta) transactions according to NEP-366.
+//!
+//! The sender signs a [`DelegateAction`] off-chain and hands it over to a relayer, which wraps
+//! it into a `Delegate` action of its own transaction and pays for the gas.
+use near_crypto::Signer;
+use near_primitives::action::Action;
+use near_primitives::action::delegate::{DelegateAction, NonDelegateAction, SignedDelegateAction};
+use near_primitives::types::{AccountId, BlockHeight, Nonce};
+
+use super::Result;
+use super::error::Error;
+
+/// Default number of blocks during which the signed delegate action stays valid.
+pub const DEFAULT_BLOCK_HEIGHT_TTL: BlockHeight = 1000;
+
+/// Build the delegate action of the `signer` to execute `actions` on the `receiver_id`.
+///
+/// # Errors
+///
+/// An error is returned if `actions` contains a nested delegate action.
+pub fn delegate_action(
+    signer: &Signer,
+    receiver_id: AccountId,
+    actions: Vec<Action>,
+    nonce: Nonce,
+    max_block_height: BlockHeight,
+) -> Result<DelegateAction> {
+    let actions = actions
+        .into_iter()
+        .map(NonDelegateAction::try_from)
+        .collect::<std::result::Result<_, _>>()
+        .map_err(|e| Error::DelegateAction(e.to_string()))?;
+
+    Ok(DelegateAction {
+        sender_id: signer.get_account_id(),
+        receiver_id,
+        actions,
+        nonce,
+        max_block_height,
+        public_key: signer.public_key(),
+    })
+}
+
+/// Sign the delegate action according to NEP-461.
+#[must_use]
+pub fn sign(signer: &Signer, delegate_action: DelegateAction) -> SignedDelegateAction {
+    SignedDelegateAction::sign(signer, delegate_action)
+}
+
+/// Serialize the signed delegate action to the base64-encoded borsh to pass it to a relayer.
+#[must_use]
+pub fn encode(signed: &SignedDelegateAction) -> String {
+    let bytes = borsh::to_vec(signed).expect("signed delegate action is always serializable");
+    aurora_engine_sdk::base64::encode(bytes)
+}
+
+/// Deserialize the signed delegate action from the base64-encoded borsh and verify its signature.
+///
+/// # Errors
+///
+/// An error is returned if the input is malformed or the signature is invalid.
+pub fn decode(input: &str) -> Result<SignedDelegateAction> {
+    let bytes = aurora_engine_sdk::base64::decode(input.trim())
+        .map_err(|e| Error::DelegateAction(format!("invalid base64: {e}")))?;
+    let signed: SignedDelegateAction = borsh::from_slice(&bytes)?;
+
+    if !signed.verify() {
+        return Err(Error::DelegateAction("invalid signature".to_string()));
+    }
+
+    Ok(signed)
+}
+
+#[cfg(test)]
+mod tests {
+    use near_crypto::{InMemorySigner, KeyType};
+    use near_primitives::action::{FunctionCallAction, TransferAction};
+    use near_primitives::gas::Gas;
+    use near_token::NearToken;
+
+    use super::*;
+
+    fn signer() -> Signer {
+        InMemorySigner::from_seed("cold.near".parse().unwrap(), KeyType::ED25519, "cold")
+    }
+
+    fn call_action() -> Action {
+        FunctionCallAction {
+            method_name: "pause_contract".to_string(),
+            args: vec![],
+            gas: Gas::from_teragas(10),
+            deposit: NearToken::from_yoctonear(0),
+        }
+        .into()
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let cold_key = signer();
+        let action = delegate_action(
+            &cold_key,
+            "aurora".parse().unwrap(),
+            vec![call_action()],
+            5,
+            100,
+        )
+        .unwrap();
+        let signed = sign(&cold_key, action);
+        let decoded = decode(&encode(&signed)).unwrap();
+
+        assert_eq!(decoded, signed);
+        assert_eq!(decoded.delegate_action.sender_id.as_str(), "cold.near");
+        assert_eq!(decoded.delegate_action.public_key, cold_key.public_key());
+        assert_eq!(decoded.delegate_action.get_actions(), vec![call_action()]);
+    }
+
+    #[test]
+    fn test_decode_rejects_tampered_action() {
+        let cold_key = signer();
+        let action = delegate_action(&cold_key, "aurora".parse().unwrap(), vec![], 5, 100).unwrap();
+        let mut signed = sign(&cold_key, action);
+        signed.delegate_action.max_block_height += 1;
+
+        assert!(matches!(
+            decode(&encode(&signed)),
+            Err(Error::DelegateAction(_))
+        ));
+        assert!(matches!(
+            decode("not base64!"),
+            Err(Error::DelegateAction(_))
+        ));
+    }
+
+    #[test]
+    fn test_nested_delegate_action() {
+        let cold_key = signer();
+        let inner = delegate_action(&cold_key, "aurora".parse().unwrap(), vec![], 1, 100).unwrap();
+        let nested = Action::from(sign(&cold_key, inner));
+        let outer = delegate_action(
+            &cold_key,
+            "aurora".parse().unwrap(),
+            vec![
+                TransferAction {
+                    deposit: NearToken::from_near(1),
+                }
+                .into(),
+                nested,
+            ],
+            2,
+            100,
+        );
+
+        assert!(matches!(outer, Err(Error::DelegateAction(_))));
+    }
+}
@@ -28,6 +28,9 @@ pub enum Error {
     #[error("Data conversion error: {0}")]
     DataConversionError(#[from] DataConversionError),
 
+    #[error("Delegate action error: {0}")]
+    DelegateAction(String),
+
     #[error("Unexpected query response kind: {0:?}")]
     UnexpectedQueryResponseKind(Box<QueryResponseKind>),
 }
@@ -1,8 +1,11 @@
+pub mod cassette;
 pub mod client;
+pub mod delegate;
 pub mod error;
 pub mod operations;
 pub mod query;
 pub(crate) mod rpc_client;
+pub mod trace;
 pub mod types;
 pub use near_jsonrpc_client as jsonrpc;
 pub use near_primitives as primitives;
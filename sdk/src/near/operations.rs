@@ -4,6 +4,7 @@ use near_primitives::gas::Gas;
 use near_primitives::types::Balance;
 use near_primitives::{
     account::AccessKey,
+    action::delegate::SignedDelegateAction,
     action::{
         AddKeyAction, CreateAccountAction, DeleteAccountAction, DeleteKeyAction,
         DeployContractAction, DeployGlobalContractAction, FunctionCallAction, StakeAction,
@@ -11,11 +12,12 @@ use near_primitives::{
     },
     hash::CryptoHash,
     transaction::Action,
-    types::AccountId,
+    types::{AccountId, BlockHeight},
     views::FinalExecutionOutcomeView,
 };
 use near_token::NearToken;
 
+use crate::near::delegate;
 use crate::near::types::{GlobalContractDeployMode, GlobalContractIdentifier};
 
 pub const MAX_GAS: NearGas = NearGas::from_tgas(300);
@@ -213,6 +215,14 @@ impl<'a> Transaction<'a> {
         self
     }
 
+    /// Execute the actions of the signed delegate action on behalf of its sender. The
+    /// `receiver_id` of the transaction must be the sender of the delegate action.
+    #[must_use]
+    pub fn delegate(mut self, signed: SignedDelegateAction) -> Self {
+        self.actions.push(signed.into());
+        self
+    }
+
     /// An action which stakes the signer's tokens and sets a validator public key.
     #[must_use]
     pub fn stake(mut self, stake: NearToken, public_key: PublicKey) -> Self {
@@ -243,6 +253,27 @@ impl<'a> Transaction<'a> {
         self
     }
 
+    /// Signs the queued actions as a delegate action (NEP-366) instead of sending them.
+    ///
+    /// The result could be passed to a relayer, which submits it with
+    /// [`Client::submit_delegate`](super::client::Client::submit_delegate) and pays for the gas.
+    /// The delegate action is valid during `block_height_ttl` blocks.
+    pub async fn sign_delegate(
+        self,
+        block_height_ttl: BlockHeight,
+    ) -> Result<SignedDelegateAction> {
+        let (nonce, block_height) = self.client.delegate_nonce(&self.signer).await?;
+        let action = delegate::delegate_action(
+            &self.signer,
+            self.receiver_id,
+            self.actions,
+            nonce,
+            block_height + block_height_ttl,
+        )?;
+
+        Ok(delegate::sign(&self.signer, action))
+    }
+
     /// Executes the transaction, sending all queued actions to the network.
     ///
     /// Waits for the transaction to be finalized and returns the final outcome.
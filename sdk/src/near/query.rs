@@ -7,7 +7,7 @@ use near_jsonrpc_primitives::types::query::QueryResponseKind;
 use near_primitives::{
     account::AccessKey,
     hash::CryptoHash,
-    types::{AccountId, BlockHeight, BlockId, BlockReference},
+    types::{AccountId, BlockHeight, BlockId, BlockReference, Finality},
     views::{BlockView, QueryRequest},
 };
 use serde::de::DeserializeOwned;
@@ -44,6 +44,20 @@ impl<'a, M> Query<'a, M> {
         self.block_ref = BlockId::Hash(CryptoHash(hash.0)).into();
         self
     }
+
+    /// Query the latest block with the given finality instead of the optimistic one.
+    #[must_use]
+    pub fn finality(mut self, finality: Finality) -> Self {
+        self.block_ref = finality.into();
+        self
+    }
+
+    /// Query the block by its height, hash or finality.
+    #[must_use]
+    pub const fn block_reference(mut self, block_ref: BlockReference) -> Self {
+        self.block_ref = block_ref;
+        self
+    }
 }
 
 impl<'a, T, R> IntoFuture for Query<'a, T>
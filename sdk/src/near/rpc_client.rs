@@ -1,4 +1,4 @@
-use std::{collections::HashMap, fmt::Debug, vec};
+use std::{collections::HashMap, fmt::Debug, sync::Arc, vec};
 
 use near_crypto::Signer;
 use near_jsonrpc_client::{
@@ -20,17 +20,20 @@ use near_primitives::{
     errors::InvalidTxError,
     hash::CryptoHash,
     transaction::SignedTransaction,
-    types::{AccountId, BlockReference, Finality, Gas, Nonce},
+    types::{AccountId, BlockHeight, BlockReference, Finality, Gas, Nonce},
     views::{AccessKeyView, BlockView, FinalExecutionOutcomeView, QueryRequest, TxExecutionStatus},
 };
 use near_token::NearToken;
 use tokio::sync::Mutex;
 
 use super::Result;
+use super::cassette::Cassette;
 use super::error::Error;
+use super::trace;
 
 pub struct RpcClient {
     client: JsonRpcClient,
+    cassette: Option<Arc<Cassette>>,
     access_key_nonces: Mutex<HashMap<(AccountId, near_crypto::PublicKey), u64>>,
 }
 
@@ -45,17 +48,28 @@ impl RpcClient {
 
         Ok(Self {
             client,
+            cassette: None,
             access_key_nonces: Mutex::new(HashMap::new()),
         })
     }
 
+    pub(crate) fn set_cassette(&mut self, cassette: Arc<Cassette>) {
+        self.cassette = Some(cassette);
+    }
+
     pub(crate) async fn query<M>(&self, method: M) -> MethodCallResult<M::Response, M::Error>
     where
         M: methods::RpcMethod + Debug + Send + Sync,
         M::Response: Debug + Send,
         M::Error: Debug + Send,
     {
-        self.client.call(&method).await
+        trace::call(self.client.server_addr(), &method, async {
+            match &self.cassette {
+                Some(cassette) => cassette.call(&self.client, &method).await,
+                None => self.client.call(&method).await,
+            }
+        })
+        .await
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -135,6 +149,16 @@ impl RpcClient {
         .map_err(Into::into)
     }
 
+    /// Reserve the nonce of the signer's access key for a delegate action and return it with
+    /// the height of the latest final block.
+    pub(crate) async fn delegate_nonce(&self, signer: &Signer) -> Result<(Nonce, BlockHeight)> {
+        let cache_key = (signer.get_account_id(), signer.public_key());
+        let (_, nonce) = self.fetch_tx_nonce(&cache_key).await?;
+        let block = self.view_block(Some(Finality::Final.into())).await?;
+
+        Ok((nonce, block.header.height))
+    }
+
     pub(crate) async fn status(
         &self,
         hash: &CryptoHash,
@@ -142,8 +166,7 @@ impl RpcClient {
         wait_until: Option<TxExecutionStatus>,
     ) -> Result<RpcTransactionResponse> {
         let status = self
-            .client
-            .call(RpcTransactionStatusRequest {
+            .query(RpcTransactionStatusRequest {
                 transaction_info: TransactionInfo::TransactionId {
                     tx_hash: *hash,
                     sender_account_id: sender.clone(),
@@ -159,7 +182,7 @@ impl RpcClient {
         &self,
         method: &RpcBroadcastTxCommitRequest,
     ) -> MethodCallResult<FinalExecutionOutcomeView, RpcTransactionError> {
-        self.client.call(method).await
+        self.query(method).await
     }
 
     async fn send_tx(
@@ -267,6 +290,7 @@ impl Clone for RpcClient {
     fn clone(&self) -> Self {
         Self {
             client: self.client.clone(),
+            cassette: self.cassette.clone(),
             access_key_nonces: Mutex::new(HashMap::new()),
         }
     }
@@ -0,0 +1,255 @@
+//! Tracing of the NEAR JSON-RPC traffic.
+//!
+//! Every call is emitted as a `tracing` event with the [`TARGET`] target: at the `debug` level
+//! with the method, the endpoint and the latency, and at the `trace` level with the params and
+//! the response. Signed transactions in the params are decoded, and the arguments of function
+//! calls are hex-dumped and decoded if they are JSON or borsh arguments of a known Aurora Engine
+//! method.
+//!
+//! Only the origin of the endpoint is logged, so an API key in the URL doesn't leak into logs.
+use std::fmt::Debug;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Instant;
+
+use aurora_engine_transactions::EthTransactionKind;
+use aurora_engine_types::parameters::connector::PauseEthConnectorArgs;
+use aurora_engine_types::parameters::engine::{
+    CallArgs, DeployErc20TokenArgs, NewCallArgs, PausePrecompilesCallArgs, SetOwnerArgs,
+    SubmitArgs, ViewCallArgs,
+};
+use aurora_engine_types::types::Address;
+use borsh::BorshDeserialize;
+use near_jsonrpc_client::{MethodCallResult, methods::RpcMethod};
+use near_primitives::action::Action;
+use near_primitives::serialize::from_base64;
+use near_primitives::transaction::SignedTransaction;
+use serde_json::{Value, json};
+
+/// Target of the events with the NEAR JSON-RPC traffic.
+pub const TARGET: &str = "near_rpc";
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Traces the `call` of the RPC method sent to the endpoint.
+///
+/// # Arguments
+///
+/// * `endpoint` - URL of the RPC endpoint.
+/// * `method` - The RPC method, whose params are logged.
+/// * `call` - The future sending the method to the endpoint.
+pub async fn call<M, F>(
+    endpoint: &str,
+    method: &M,
+    call: F,
+) -> MethodCallResult<M::Response, M::Error>
+where
+    M: RpcMethod + Sync,
+    M::Response: Debug + Send,
+    M::Error: Debug + Send,
+    F: Future<Output = MethodCallResult<M::Response, M::Error>> + Send,
+{
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    let method_name = method.method_name();
+    let endpoint = origin(endpoint);
+
+    if tracing::enabled!(target: TARGET, tracing::Level::TRACE) {
+        let params = method.params().map_or_else(
+            |e| Value::String(e.to_string()),
+            |params| decode_params(method_name, params),
+        );
+        tracing::trace!(target: TARGET, id, method = method_name, endpoint, %params, "request");
+    }
+
+    let started = Instant::now();
+    let result = call.await;
+    let latency = started.elapsed();
+
+    match &result {
+        Ok(response) => {
+            tracing::debug!(target: TARGET, id, method = method_name, endpoint, ?latency, "ok");
+            tracing::trace!(target: TARGET, id, ?response, "response");
+        }
+        Err(error) => {
+            tracing::debug!(target: TARGET, id, method = method_name, endpoint, ?latency, ?error, "error");
+        }
+    }
+
+    result
+}
+
+/// Returns the scheme, the host and the port of the URL.
+#[must_use]
+pub fn origin(url: &str) -> String {
+    reqwest::Url::parse(url).map_or_else(
+        |_| "<invalid url>".to_string(),
+        |url| url.origin().ascii_serialization(),
+    )
+}
+
+/// Decodes the signed transaction and the arguments of the function call in the params.
+fn decode_params(method_name: &str, mut params: Value) -> Value {
+    match method_name {
+        "broadcast_tx_commit" | "broadcast_tx_async" => {
+            if let Some(tx) = params.get_mut(0) {
+                decode_transaction(tx);
+            }
+        }
+        "send_tx" => {
+            if let Some(tx) = params.get_mut("signed_tx_base64") {
+                decode_transaction(tx);
+            }
+        }
+        "query" if params["request_type"] == "call_function" => {
+            let args = params["args_base64"]
+                .as_str()
+                .and_then(|a| from_base64(a).ok());
+
+            if let (Some(method_name), Some(args)) = (params["method_name"].as_str(), args) {
+                params["args"] = decode_args(method_name, &args);
+            }
+        }
+        _ => {}
+    }
+
+    params
+}
+
+fn decode_transaction(value: &mut Value) {
+    let Some(signed) = value
+        .as_str()
+        .and_then(|tx| from_base64(tx).ok())
+        .and_then(|bytes| SignedTransaction::try_from_slice(&bytes).ok())
+    else {
+        return;
+    };
+    let tx = &signed.transaction;
+
+    *value = json!({
+        "hash": signed.get_hash().to_string(),
+        "signer_id": tx.signer_id(),
+        "receiver_id": tx.receiver_id(),
+        "nonce": tx.nonce(),
+        "actions": tx.actions().iter().map(decode_action).collect::<Vec<_>>(),
+    });
+}
+
+fn decode_action(action: &Action) -> Value {
+    match action {
+        Action::FunctionCall(call) => json!({
+            "FunctionCall": {
+                "method_name": call.method_name,
+                "args": decode_args(&call.method_name, &call.args),
+                "gas": call.gas,
+                "deposit": call.deposit,
+            }
+        }),
+        Action::DeployContract(deploy) => json!({
+            "DeployContract": { "code_size": deploy.code.len() }
+        }),
+        action => Value::String(format!("{action:?}")),
+    }
+}
+
+/// Hex dump of the arguments with the decoded ones if they are JSON or borsh arguments of a known
+/// Aurora Engine method.
+fn decode_args(method_name: &str, args: &[u8]) -> Value {
+    let decoded = serde_json::from_slice(args)
+        .ok()
+        .or_else(|| decode_engine_args(method_name, args).map(Value::String));
+
+    json!({
+        "hex": hex::encode(args),
+        "decoded": decoded,
+    })
+}
+
+fn decode_engine_args(method_name: &str, args: &[u8]) -> Option<String> {
+    fn borsh<T: BorshDeserialize + Debug>(args: &[u8]) -> Option<String> {
+        T::try_from_slice(args).ok().map(|args| format!("{args:?}"))
+    }
+
+    match method_name {
+        "new" => borsh::<NewCallArgs>(args),
+        "submit" => EthTransactionKind::try_from(args)
+            .ok()
+            .map(|tx| format!("{tx:?}")),
+        "submit_with_args" => borsh::<SubmitArgs>(args),
+        "call" => borsh::<CallArgs>(args),
+        "view" => borsh::<ViewCallArgs>(args),
+        "set_owner" => borsh::<SetOwnerArgs>(args),
+        "pause_precompiles" | "resume_precompiles" => borsh::<PausePrecompilesCallArgs>(args),
+        "set_paused_flags" => borsh::<PauseEthConnectorArgs>(args),
+        "deploy_erc20_token" => borsh::<DeployErc20TokenArgs>(args),
+        "register_relayer" | "get_nonce" | "get_balance" | "get_code" => {
+            Address::try_from_slice(args).ok().map(|a| a.encode())
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use near_jsonrpc_client::methods;
+    use near_primitives::hash::CryptoHash;
+
+    use super::*;
+
+    #[test]
+    fn test_decode_args() {
+        let args = borsh::to_vec(&SetOwnerArgs {
+            new_owner: "owner.near".parse().unwrap(),
+        })
+        .unwrap();
+        let decoded = decode_args("set_owner", &args);
+
+        assert_eq!(decoded["hex"], hex::encode(&args));
+        assert!(decoded["decoded"].as_str().unwrap().contains("owner.near"));
+
+        assert_eq!(
+            decode_args("ft_transfer", br#"{"amount":"1"}"#)["decoded"],
+            json!({"amount": "1"})
+        );
+        assert_eq!(decode_args("unknown", &[1, 2])["decoded"], Value::Null);
+    }
+
+    #[test]
+    fn test_decode_broadcast_tx() {
+        let signer = near_crypto::InMemorySigner::from_seed(
+            "owner.near".parse().unwrap(),
+            near_crypto::KeyType::ED25519,
+            "seed",
+        );
+        let tx = SignedTransaction::call(
+            1,
+            "owner.near".parse().unwrap(),
+            "aurora".parse().unwrap(),
+            &signer,
+            near_token::NearToken::from_yoctonear(0),
+            "register_relayer".to_string(),
+            vec![0x22; 20],
+            near_primitives::gas::Gas::from_teragas(300),
+            CryptoHash::default(),
+        );
+        let method = methods::broadcast_tx_commit::RpcBroadcastTxCommitRequest {
+            signed_transaction: tx,
+        };
+        let params = decode_params("broadcast_tx_commit", method.params().unwrap());
+        let call = &params[0]["actions"][0]["FunctionCall"];
+
+        assert_eq!(params[0]["signer_id"], "owner.near");
+        assert_eq!(params[0]["receiver_id"], "aurora");
+        assert_eq!(call["method_name"], "register_relayer");
+        assert_eq!(call["args"]["hex"], "22".repeat(20));
+        assert_eq!(call["args"]["decoded"], "22".repeat(20));
+    }
+
+    #[test]
+    fn test_origin() {
+        assert_eq!(
+            origin("https://mainnet.aurora.dev/secret-api-key"),
+            "https://mainnet.aurora.dev"
+        );
+        assert_eq!(origin("http://127.0.0.1:3030/"), "http://127.0.0.1:3030");
+    }
+}
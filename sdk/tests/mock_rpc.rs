@@ -0,0 +1,213 @@
+use aurora_engine_types::U256;
+use aurora_engine_types::parameters::engine::{PausePrecompilesCallArgs, SetOwnerArgs};
+use std::sync::Arc;
+
+use aurora_mock_rpc::MockRpc;
+use aurora_sdk_rs::aurora::contract::{read, write};
+use aurora_sdk_rs::near::cassette::Cassette;
+use aurora_sdk_rs::near::client::Client;
+use aurora_sdk_rs::near::delegate;
+use aurora_sdk_rs::near::operations::Function;
+use near_crypto::{InMemorySigner, KeyType, Signer};
+use near_primitives::types::{AccountId, BlockId, BlockReference, Finality};
+
+fn signer(account_id: &str) -> Signer {
+    InMemorySigner::from_seed(account_id.parse().unwrap(), KeyType::ED25519, account_id)
+}
+
+fn engine() -> AccountId {
+    "aurora".parse().unwrap()
+}
+
+fn cassette_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("aurora-sdk-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+#[tokio::test]
+async fn test_view_method() -> anyhow::Result<()> {
+    let rpc = MockRpc::start().await;
+    rpc.on_call_function(
+        "get_chain_id",
+        U256::from(1_313_161_556).to_big_endian().to_vec(),
+    );
+    let client = aurora_sdk_rs::aurora::client::Client::new(Client::new(
+        rpc.url(),
+        None,
+        signer("owner.near"),
+    )?);
+
+    let chain_id = client.view(&engine(), read::GetChainId).await?;
+
+    assert_eq!(chain_id, U256::from(1_313_161_556));
+    assert_eq!(rpc.view_calls()[0].method_name, "get_chain_id");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_view_method_at_block() -> anyhow::Result<()> {
+    let rpc = MockRpc::start().await;
+    rpc.on_call_function("get_version", b"3.9.0\n".to_vec());
+    let client = aurora_sdk_rs::aurora::client::Client::new(Client::new(
+        rpc.url(),
+        None,
+        signer("owner.near"),
+    )?);
+
+    client
+        .view_at(&engine(), read::GetVersion, BlockId::Height(100).into())
+        .await?;
+    client
+        .view_at(&engine(), read::GetVersion, Finality::Final.into())
+        .await?;
+
+    let blocks = rpc
+        .requests_of("query")
+        .iter()
+        .map(aurora_mock_rpc::Request::block_reference)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        blocks,
+        [
+            Some(BlockReference::BlockId(BlockId::Height(100))),
+            Some(BlockReference::Finality(Finality::Final))
+        ]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_call_method() -> anyhow::Result<()> {
+    let rpc = MockRpc::start().await;
+    let client = aurora_sdk_rs::aurora::client::Client::new(Client::new(
+        rpc.url(),
+        None,
+        signer("owner.near"),
+    )?);
+    let args = SetOwnerArgs {
+        new_owner: "new-owner.near".parse().unwrap(),
+    };
+
+    client
+        .call(&engine(), write::SetOwner { args: args.clone() })
+        .await?;
+    client
+        .call(
+            &engine(),
+            write::PausePrecompiles {
+                args: PausePrecompilesCallArgs { paused_mask: 1 },
+            },
+        )
+        .await?;
+
+    let calls = rpc.function_calls();
+    assert_eq!(calls.len(), 2);
+    assert_eq!(calls[0].method_name, "set_owner");
+    assert_eq!(calls[0].args_borsh::<SetOwnerArgs>()?, args);
+    assert_eq!(
+        calls[1].args_borsh::<PausePrecompilesCallArgs>()?,
+        PausePrecompilesCallArgs { paused_mask: 1 }
+    );
+
+    // The nonce is fetched once and cached for subsequent transactions.
+    let nonces: Vec<_> = rpc
+        .transactions()
+        .iter()
+        .map(|tx| tx.transaction.nonce())
+        .collect();
+    assert_eq!(nonces, [1, 2]);
+    assert_eq!(
+        rpc.requests_of("query")
+            .iter()
+            .filter(|request| request.params["request_type"] == "view_access_key")
+            .count(),
+        1
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_call_method_failure() -> anyhow::Result<()> {
+    let rpc = MockRpc::start().await;
+    rpc.on_transaction_failure("set_owner", "ERR_NOT_ALLOWED");
+    let client = aurora_sdk_rs::aurora::client::Client::new(Client::new(
+        rpc.url(),
+        None,
+        signer("owner.near"),
+    )?);
+    let method = write::SetOwner {
+        args: SetOwnerArgs {
+            new_owner: "new-owner.near".parse().unwrap(),
+        },
+    };
+
+    assert!(client.call(&engine(), method).await.is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_delegate_action() -> anyhow::Result<()> {
+    let rpc = MockRpc::start().await;
+    rpc.set_block_height(1000);
+    let cold = Client::new(rpc.url(), None, signer("cold.near"))?;
+    let relayer = cold.with_signer(signer("relayer.near"));
+
+    let signed = cold
+        .batch(&engine())
+        .call(Function::new("pause_contract").max_gas())
+        .sign_delegate(delegate::DEFAULT_BLOCK_HEIGHT_TTL)
+        .await?;
+    assert_eq!(signed.delegate_action.max_block_height, 2000);
+    assert!(rpc.transactions().is_empty());
+
+    let outcome = relayer
+        .submit_delegate(delegate::decode(&delegate::encode(&signed))?)
+        .await?;
+    assert_eq!(outcome.transaction.signer_id.as_str(), "relayer.near");
+    assert_eq!(outcome.transaction.receiver_id.as_str(), "cold.near");
+
+    let calls = rpc.function_calls();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].signer_id.as_str(), "cold.near");
+    assert_eq!(calls[0].receiver_id, engine());
+    assert_eq!(calls[0].method_name, "pause_contract");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_record_and_replay() -> anyhow::Result<()> {
+    let dir = cassette_dir("record-and-replay");
+    let rpc = MockRpc::start().await;
+    rpc.on_call_function(
+        "get_chain_id",
+        U256::from(1_313_161_556).to_big_endian().to_vec(),
+    );
+    let pause = || write::PausePrecompiles {
+        args: PausePrecompilesCallArgs { paused_mask: 1 },
+    };
+
+    let client = aurora_sdk_rs::aurora::client::Client::new(
+        Client::new(rpc.url(), None, signer("owner.near"))?
+            .with_cassette(Arc::new(Cassette::record(&dir)?)),
+    );
+    let chain_id = client.view(&engine(), read::GetChainId).await?;
+    client.call(&engine(), pause()).await?;
+    let requests = rpc.requests().len();
+    drop(rpc);
+
+    // The responses come from the cassette since the server is gone.
+    let client = aurora_sdk_rs::aurora::client::Client::new(
+        Client::new("http://127.0.0.1:1", None, signer("owner.near"))?
+            .with_cassette(Arc::new(Cassette::replay(&dir)?)),
+    );
+    assert_eq!(std::fs::read_dir(&dir)?.count(), requests);
+    assert_eq!(client.view(&engine(), read::GetChainId).await?, chain_id);
+    client.call(&engine(), pause()).await?;
+    // Identical requests get the last recorded response.
+    assert_eq!(client.view(&engine(), read::GetChainId).await?, chain_id);
+
+    let error = client.view(&engine(), read::GetVersion).await.unwrap_err();
+    assert!(error.to_string().contains("no response to `query`"));
+    Ok(())
+}